@@ -132,6 +132,9 @@ fn testnet_genesis(
 				"metadata": vec![(1, "XUSD".as_bytes(), "XUSD".as_bytes(), 0)], // Genesis metadata: id, name, symbol, decimals
 				"accounts": endowed_accounts.iter().cloned().map(|x| (1, x.0.clone(), 1_000_000)).collect::<Vec<_>>(),
 			},   
+			"nftMarketplace": {
+				"paymentAssets": vec![1],
+			},
 			"sudo": {
 				// Assign network admin rights.
 				"key": Some(root_key),
@@ -0,0 +1,257 @@
+//! RPC methods for the marketplace, property management and property governance pallets.
+//! The methods call the runtime apis of the pallets, so that dapps get JSON results instead
+//! of having to decode the SCALE encoded storage.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use node_template_runtime::{AccountId, Balance, BlockNumber, Hash};
+use pallet_nft_marketplace::{
+	HoldingInfo, LegalDocumentsInfo, ListingInfo, ListingPage, NftMarketplaceApi, OfferInfo,
+};
+use pallet_property_governance::{PropertyGovernanceApi, ProposalInfo};
+use pallet_property_management::PropertyManagementApi;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code for failed runtime api calls.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Error code for runtimes that do not implement the queries of the runtime apis yet.
+const API_VERSION_ERROR: i32 = 2;
+
+/// The version of the runtime apis that added the queries.
+const QUERY_API_VERSION: u32 = 2;
+
+/// The funds of a property that are managed by the letting agent.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(serialize = "Balance: std::fmt::Display", deserialize = "Balance: std::str::FromStr"))]
+pub struct PropertyFunds<Balance> {
+	/// The funds that are held in reserve for the property.
+	#[serde(with = "pallet_nft_marketplace::serde_balance")]
+	pub reserve: Balance,
+	/// The debts of the property.
+	#[serde(with = "pallet_nft_marketplace::serde_balance")]
+	pub debts: Balance,
+}
+
+/// Xcavate RPC methods.
+#[rpc(server)]
+pub trait XcavateApi<BlockHash> {
+	/// Returns up to `limit` active listings of a region, starting at the listing id `start`.
+	/// If `location` is set, only the listings of this location are returned. The next page
+	/// starts at the returned `next` listing id.
+	#[method(name = "xcavate_listings")]
+	fn listings(
+		&self,
+		region: u32,
+		location: Option<Vec<u8>>,
+		start: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<ListingPage<AccountId, Balance, BlockNumber>>;
+
+	/// Returns an active listing with its remaining token.
+	#[method(name = "xcavate_listing")]
+	fn listing(
+		&self,
+		listing_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ListingInfo<AccountId, Balance, BlockNumber>>>;
+
+	/// Returns all offers on a listing.
+	#[method(name = "xcavate_offers")]
+	fn offers(
+		&self,
+		listing_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OfferInfo<AccountId, Balance, BlockNumber>>>;
+
+	/// Returns the pending and settled token of an account.
+	#[method(name = "xcavate_holdings")]
+	fn holdings(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<HoldingInfo<Balance>>>;
+
+	/// Returns the documents that the lawyers reviewed for a property.
+	#[method(name = "xcavate_legalDocuments")]
+	fn legal_documents(
+		&self,
+		listing_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<LegalDocumentsInfo<AccountId, Hash, BlockNumber>>>;
+
+	/// Returns the reserve and the debts of a property.
+	#[method(name = "xcavate_propertyFunds")]
+	fn property_funds(
+		&self,
+		asset_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<PropertyFunds<Balance>>;
+
+	/// Returns the proposals of a property that are still open for voting.
+	#[method(name = "xcavate_openProposals")]
+	fn open_proposals(
+		&self,
+		asset_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ProposalInfo<AccountId, Balance, BlockNumber>>>;
+}
+
+/// Provides the xcavate RPC methods.
+pub struct Xcavate<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Xcavate<C, Block> {
+	/// Creates a new instance of the xcavate RPC methods.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Converts a failed runtime api call into a RPC error.
+fn runtime_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime api call failed",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+/// Fails if the runtime api at a block is older than the version that added the queries.
+fn ensure_query_api(version: Option<u32>) -> RpcResult<()> {
+	match version {
+		Some(version) if version >= QUERY_API_VERSION => Ok(()),
+		_ => Err(CallError::Custom(ErrorObject::owned(
+			API_VERSION_ERROR,
+			"Runtime api version not supported",
+			Some(format!("the runtime api has to be at least version {}", QUERY_API_VERSION)),
+		))
+		.into()),
+	}
+}
+
+impl<C, Block> Xcavate<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: NftMarketplaceApi<Block, AccountId, Balance, BlockNumber, Hash>,
+{
+	/// Fails if the marketplace runtime api at `at` does not implement the queries yet.
+	fn ensure_marketplace_api(&self, at: <Block as BlockT>::Hash) -> RpcResult<()> {
+		ensure_query_api(
+			self.client
+				.runtime_api()
+				.api_version::<dyn NftMarketplaceApi<Block, AccountId, Balance, BlockNumber, Hash>>(at)
+				.map_err(runtime_error)?,
+		)
+	}
+}
+
+impl<C, Block> XcavateApiServer<<Block as BlockT>::Hash> for Xcavate<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: NftMarketplaceApi<Block, AccountId, Balance, BlockNumber, Hash>,
+	C::Api: PropertyManagementApi<Block, AccountId, Balance>,
+	C::Api: PropertyGovernanceApi<Block, AccountId, Balance, BlockNumber>,
+{
+	fn listings(
+		&self,
+		region: u32,
+		location: Option<Vec<u8>>,
+		start: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ListingPage<AccountId, Balance, BlockNumber>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_marketplace_api(at)?;
+		self.client
+			.runtime_api()
+			.get_listings(at, region, location, start, limit)
+			.map_err(runtime_error)
+	}
+
+	fn listing(
+		&self,
+		listing_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ListingInfo<AccountId, Balance, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_marketplace_api(at)?;
+		self.client.runtime_api().get_listing(at, listing_id).map_err(runtime_error)
+	}
+
+	fn offers(
+		&self,
+		listing_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<OfferInfo<AccountId, Balance, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_marketplace_api(at)?;
+		self.client.runtime_api().get_offers(at, listing_id).map_err(runtime_error)
+	}
+
+	fn holdings(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<HoldingInfo<Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_marketplace_api(at)?;
+		self.client.runtime_api().get_holdings(at, account).map_err(runtime_error)
+	}
+
+	fn legal_documents(
+		&self,
+		listing_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<LegalDocumentsInfo<AccountId, Hash, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_marketplace_api(at)?;
+		self.client.runtime_api().get_legal_documents(at, listing_id).map_err(runtime_error)
+	}
+
+	fn property_funds(
+		&self,
+		asset_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<PropertyFunds<Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		ensure_query_api(
+			api.api_version::<dyn PropertyManagementApi<Block, AccountId, Balance>>(at)
+				.map_err(runtime_error)?,
+		)?;
+		Ok(PropertyFunds {
+			reserve: api.get_property_reserve(at, asset_id).map_err(runtime_error)?,
+			debts: api.get_property_debts(at, asset_id).map_err(runtime_error)?,
+		})
+	}
+
+	fn open_proposals(
+		&self,
+		asset_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProposalInfo<AccountId, Balance, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		ensure_query_api(
+			api.api_version::<dyn PropertyGovernanceApi<Block, AccountId, Balance, BlockNumber>>(at)
+				.map_err(runtime_error)?,
+		)?;
+		api.get_open_proposals(at, asset_id).map_err(runtime_error)
+	}
+}
@@ -10,7 +10,7 @@ use frame_support::sp_runtime::traits::Bounded;
 use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use pallet_assets::Pallet as Assets;
-use pallet_xcavate_whitelist::Pallet as Whitelist;
+use pallet_xcavate_whitelist::{KycTier, Pallet as Whitelist};
 type DepositBalanceOf<T> = <<T as pallet_nfts::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
@@ -47,6 +47,177 @@ fn setup_object_listing<T: Config>() -> (T::AccountId, BalanceOf<T>) {
 	(caller, value)
 }
 
+fn setup_offer<T: Config>() -> (T::AccountId, T::AccountId) {
+	let seller = setup_token_listing::<T>(2u32.into());
+	let caller: T::AccountId = whitelisted_caller();
+	let token_buyer: T::AccountId = account("offeror", 0, 0);
+	<T as pallet_nfts::Config>::Currency::make_free_balance_be(
+		&token_buyer,
+		DepositBalanceOf::<T>::max_value(),
+	);
+	assert_ok!(Whitelist::<T>::add_to_whitelist(
+		RawOrigin::Root.into(),
+		token_buyer.clone(),
+		KycTier::Retail,
+		*b"GB",
+		Bounded::max_value()
+	));
+	let amount: BalanceOf<T> = 1_000_000_000u32.into();
+	let user_lookup = <T::Lookup as StaticLookup>::unlookup(token_buyer.clone());
+	let asset_id = <T as pallet::Config>::Helper::to_asset(1);
+	assert_ok!(Assets::<T, Instance1>::mint(
+		RawOrigin::Signed(caller).into(),
+		asset_id.clone().into(),
+		user_lookup,
+		amount.into(),
+	));
+	let offer_value: BalanceOf<T> = 100u32.into();
+	assert_ok!(NftMarketplace::<T>::make_offer(
+		RawOrigin::Signed(token_buyer.clone()).into(),
+		1,
+		offer_value,
+		10,
+		None
+	));
+	(seller, token_buyer)
+}
+
+/// Lists an object and sells `sold_token` of its 100 token to at least `buyers` whitelisted
+/// investors. More investors buy if a single investor would exceed the concentration cap.
+fn setup_listing_with_buyers<T: Config>(
+	buyers: u32,
+	sold_token: u32,
+	soft_cap: Option<SoftCap<BlockNumberFor<T>>>,
+) -> T::AccountId {
+	let (caller, _) = setup_object_listing::<T>();
+	assert_ok!(Whitelist::<T>::add_to_whitelist(
+		RawOrigin::Root.into(),
+		caller.clone(),
+		KycTier::Retail,
+		*b"GB",
+		Bounded::max_value()
+	));
+	let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
+		.try_into()
+		.unwrap();
+	let token_price: AssetBalanceOf<T> = 1u32.into();
+	assert_ok!(NftMarketplace::<T>::list_object(
+		RawOrigin::Signed(caller.clone()).into(),
+		0,
+		location,
+		token_price,
+		100,
+		vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
+			.try_into()
+			.unwrap(),
+		1,
+		soft_cap,
+	));
+	let max_per_buyer = <T as pallet::Config>::MaxOwnershipShare::get().mul_ceil(100u32).max(1);
+	let buyers = buyers.max((sold_token + max_per_buyer - 1) / max_per_buyer);
+	let asset_id = <T as pallet::Config>::Helper::to_asset(1);
+	let mut remaining_token = sold_token;
+	for i in 0..buyers {
+		let buyer: T::AccountId = account("buyer", i, 0);
+		assert_ok!(Whitelist::<T>::add_to_whitelist(
+			RawOrigin::Root.into(),
+			buyer.clone(),
+			KycTier::Retail,
+			*b"GB",
+			Bounded::max_value()
+		));
+		let funds: AssetBalanceOf<T> = 1_000u32.into();
+		assert_ok!(Assets::<T, Instance1>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			asset_id.clone().into(),
+			<T::Lookup as StaticLookup>::unlookup(buyer.clone()),
+			funds,
+		));
+		let amount = (remaining_token + buyers - i - 1) / (buyers - i);
+		if amount > 0 {
+			assert_ok!(NftMarketplace::<T>::buy_token(
+				RawOrigin::Signed(buyer).into(),
+				0,
+				amount,
+				Bounded::max_value()
+			));
+			remaining_token -= amount;
+		}
+	}
+	caller
+}
+
+/// Sells all token of a listed object to `buyers` investors and lets a lawyer claim the real
+/// estate developer side of the legal process.
+fn setup_legal_case<T: Config>(buyers: u32) -> T::AccountId {
+	setup_listing_with_buyers::<T>(buyers, 100, None);
+	let lawyer = setup_lawyer::<T>(0);
+	assert_ok!(NftMarketplace::<T>::lawyer_claim_property(
+		RawOrigin::Signed(lawyer.clone()).into(),
+		0,
+		LegalProperty::RealEstateDeveloperSide,
+		Zero::zero(),
+	));
+	lawyer
+}
+
+/// Registers a lawyer that is licensed for the region of the benchmark listings.
+fn setup_lawyer<T: Config>(index: u32) -> T::AccountId {
+	let lawyer: T::AccountId = account("lawyer", index, 0);
+	<T as pallet::Config>::Currency::make_free_balance_be(&lawyer, BalanceOf::<T>::max_value());
+	assert_ok!(NftMarketplace::<T>::apply_as_lawyer(
+		RawOrigin::Signed(lawyer.clone()).into(),
+		Default::default(),
+		vec![0].try_into().unwrap(),
+	));
+	assert_ok!(NftMarketplace::<T>::register_lawyer(RawOrigin::Root.into(), lawyer.clone()));
+	lawyer
+}
+
+/// Sells all token of a listed object and lets both lawyers approve the documents, so that the
+/// investors receive their token. Returns the first investor.
+fn setup_sold_property<T: Config>() -> T::AccountId {
+	let developer_lawyer = setup_legal_case::<T>(1);
+	let spv_lawyer = setup_lawyer::<T>(1);
+	assert_ok!(NftMarketplace::<T>::lawyer_claim_property(
+		RawOrigin::Signed(spv_lawyer.clone()).into(),
+		0,
+		LegalProperty::SpvSide,
+		Zero::zero(),
+	));
+	let document_hashes: BoundedVec<T::Hash, T::MaxDocumentHashes> =
+		vec![Default::default()].try_into().unwrap();
+	for lawyer in [developer_lawyer, spv_lawyer] {
+		assert_ok!(NftMarketplace::<T>::lawyer_confirm_documents(
+			RawOrigin::Signed(lawyer).into(),
+			0,
+			true,
+			document_hashes.clone(),
+			None,
+		));
+	}
+	let investor: T::AccountId = account("buyer", 0, 0);
+	<T as pallet_nfts::Config>::Currency::make_free_balance_be(
+		&investor,
+		DepositBalanceOf::<T>::max_value(),
+	);
+	investor
+}
+
+/// Relists token of a sold property with listing id 1 and returns the seller.
+fn setup_token_listing<T: Config>(token_price: BalanceOf<T>) -> T::AccountId {
+	let seller = setup_sold_property::<T>();
+	assert_ok!(NftMarketplace::<T>::relist_token(
+		RawOrigin::Signed(seller.clone()).into(),
+		0,
+		0.into(),
+		token_price,
+		20,
+		1,
+	));
+	seller
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -56,7 +227,13 @@ mod benchmarks {
 		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
 			.try_into()
 			.unwrap();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
+		assert_ok!(Whitelist::<T>::add_to_whitelist(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			KycTier::Retail,
+			*b"GB",
+			Bounded::max_value()
+		));
 		#[extrinsic_call]
 		list_object(
 			RawOrigin::Signed(caller),
@@ -67,6 +244,8 @@ mod benchmarks {
 			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
 				.try_into()
 				.unwrap(),
+				1,
+				None,
 		);
 		assert_eq!(
 			NftMarketplace::<T>::registered_nft_details::<
@@ -81,269 +260,78 @@ mod benchmarks {
 
 	#[benchmark]
 	fn buy_token() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
+		let max_per_buyer =
+			<T as pallet::Config>::MaxOwnershipShare::get().mul_ceil(100u32).max(1).min(100);
+		let caller = setup_listing_with_buyers::<T>(1, 100 - max_per_buyer, None);
+		let buyer: T::AccountId = account("last_buyer", 0, 0);
+		assert_ok!(Whitelist::<T>::add_to_whitelist(
+			RawOrigin::Root.into(),
+			buyer.clone(),
+			KycTier::Retail,
+			*b"GB",
+			Bounded::max_value()
+		));
+		let funds: AssetBalanceOf<T> = 1_000u32.into();
+		assert_ok!(Assets::<T, Instance1>::mint(
+			RawOrigin::Signed(caller).into(),
+			<T as pallet::Config>::Helper::to_asset(1).into(),
+			<T::Lookup as StaticLookup>::unlookup(buyer.clone()),
+			funds,
 		));
 		#[extrinsic_call]
-		buy_token(RawOrigin::Signed(caller), 0, 100);
+		buy_token(RawOrigin::Signed(buyer), 0, max_per_buyer, Bounded::max_value());
 
-		assert_eq!(
-			NftMarketplace::<T>::registered_nft_details::<
-				<T as pallet::Config>::CollectionId,
-				<T as pallet::Config>::ItemId,
-			>(0.into(), 0.into())
-			.unwrap()
-			.spv_created,
-			true
-		);
+		assert!(PropertyLawyer::<T>::get(0).is_some());
 	}
 
 	#[benchmark]
 	fn relist_token() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
+		let seller = setup_sold_property::<T>();
 		let listing_value: BalanceOf<T> = 2_000u32.into();
 		#[extrinsic_call]
-		relist_token(RawOrigin::Signed(caller), 0, 0.into(), listing_value, 80);
+		relist_token(RawOrigin::Signed(seller), 0, 0.into(), listing_value, 20, 1);
 		//assert_eq!(NftMarketplace::<T>::listed_nfts().len(), 1);
 	}
 
 	#[benchmark]
 	fn buy_relisted_token() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
-		let listing_value: BalanceOf<T> = 2u32.into();
-		assert_ok!(NftMarketplace::<T>::relist_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			0.into(),
-			listing_value,
-			80,
-		));
+		setup_token_listing::<T>(2u32.into());
 		let nft_buyer: T::AccountId = whitelisted_caller();
-		<T as pallet_nfts::Config>::Currency::make_free_balance_be(
-			&nft_buyer,
-			DepositBalanceOf::<T>::max_value(),
-		);
-		let amount: BalanceOf<T> = 1_000_000u32.into();
-		let user_lookup = <T::Lookup as StaticLookup>::unlookup(nft_buyer.clone());
-		let asset_id = <T as pallet::Config>::Helper::to_asset(1);
-		assert_ok!(Assets::<T, Instance1>::mint(
-			RawOrigin::Signed(caller.clone()).into(),
-			asset_id.clone().into(),
-			user_lookup,
-			amount.into(),
-		));
 		#[extrinsic_call]
-		buy_relisted_token(RawOrigin::Signed(nft_buyer), 1, 1);
+		buy_relisted_token(RawOrigin::Signed(nft_buyer), 1, 1, Bounded::max_value());
 		//assert_eq!(NftMarketplace::<T>::listed_nfts().len(), 0);
 	}
 
 	#[benchmark]
 	fn make_offer() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
-		let listing_value: BalanceOf<T> = 2u32.into();
-		assert_ok!(NftMarketplace::<T>::relist_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			0.into(),
-			listing_value,
-			80,
-		));
+		setup_token_listing::<T>(2u32.into());
 		let token_buyer: T::AccountId = whitelisted_caller();
-		<T as pallet_nfts::Config>::Currency::make_free_balance_be(
-			&token_buyer,
-			DepositBalanceOf::<T>::max_value(),
-		);
-		let amount: BalanceOf<T> = 1_000_000_000u32.into();
-		let user_lookup = <T::Lookup as StaticLookup>::unlookup(token_buyer.clone());
-		let asset_id = <T as pallet::Config>::Helper::to_asset(1);
-		assert_ok!(Assets::<T, Instance1>::mint(
-			RawOrigin::Signed(caller.clone()).into(),
-			asset_id.clone().into(),
-			user_lookup,
-			amount.into(),
-		));
 		let offer_value: BalanceOf<T> = 100u32.into();
 		#[extrinsic_call]
-		make_offer(RawOrigin::Signed(token_buyer), 1, offer_value, 10);
+		make_offer(RawOrigin::Signed(token_buyer), 1, offer_value, 10, Some(Bounded::max_value()));
 		//assert_eq!(NftMarketplace::<T>::listed_nfts().len(), 0);
 	}
 
 	#[benchmark]
 	fn handle_offer() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
-		let listing_value: BalanceOf<T> = 2u32.into();
-		assert_ok!(NftMarketplace::<T>::relist_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			0.into(),
-			listing_value,
-			80,
-		));
+		let seller = setup_token_listing::<T>(2u32.into());
 		let token_buyer: T::AccountId = whitelisted_caller();
-		<T as pallet_nfts::Config>::Currency::make_free_balance_be(
-			&token_buyer,
-			DepositBalanceOf::<T>::max_value(),
-		);
-		let amount: BalanceOf<T> = 1_000_000_000u32.into();
-		let user_lookup = <T::Lookup as StaticLookup>::unlookup(token_buyer.clone());
-		let asset_id = <T as pallet::Config>::Helper::to_asset(1);
-		assert_ok!(Assets::<T, Instance1>::mint(
-			RawOrigin::Signed(caller.clone()).into(),
-			asset_id.clone().into(),
-			user_lookup,
-			amount.into(),
-		));
 		let offer_value: BalanceOf<T> = 10u32.into();
 		assert_ok!(NftMarketplace::<T>::make_offer(
 			RawOrigin::Signed(token_buyer).into(),
 			1,
 			offer_value,
-			10
+			10,
+			None
 		));
 		#[extrinsic_call]
-		handle_offer(RawOrigin::Signed(caller), 1, 0, crate::Offer::Accept);
+		handle_offer(RawOrigin::Signed(seller), 1, 0, crate::Offer::Accept);
 		//assert_eq!(NftMarketplace::<T>::listed_nfts().len(), 0);
 	}
 
 	#[benchmark]
 	fn cancel_offer() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
-		let listing_value: BalanceOf<T> = 2u32.into();
-		assert_ok!(NftMarketplace::<T>::relist_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			0.into(),
-			listing_value,
-			80,
-		));
-		let token_buyer: T::AccountId = whitelisted_caller();
-		<T as pallet_nfts::Config>::Currency::make_free_balance_be(
-			&token_buyer,
-			DepositBalanceOf::<T>::max_value(),
-		);
-		let amount: BalanceOf<T> = 1_000_000_000u32.into();
-		let user_lookup = <T::Lookup as StaticLookup>::unlookup(token_buyer.clone());
-		let asset_id = <T as pallet::Config>::Helper::to_asset(1);
-		assert_ok!(Assets::<T, Instance1>::mint(
-			RawOrigin::Signed(caller.clone()).into(),
-			asset_id.clone().into(),
-			user_lookup,
-			amount.into(),
-		));
-		let offer_value: BalanceOf<T> = 100u32.into();
-		assert_ok!(NftMarketplace::<T>::make_offer(
-			RawOrigin::Signed(token_buyer.clone()).into(),
-			1,
-			offer_value,
-			10
-		));
+		let (_, token_buyer) = setup_offer::<T>();
 		#[extrinsic_call]
 		cancel_offer(RawOrigin::Signed(token_buyer), 1, 0);
 		//assert_eq!(NftMarketplace::<T>::listed_nfts().len(), 0);
@@ -351,52 +339,22 @@ mod benchmarks {
 
 	#[benchmark]
 	fn upgrade_listing() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
-		let listing_value: BalanceOf<T> = 2_000u32.into();
-		assert_ok!(NftMarketplace::<T>::relist_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			0.into(),
-			listing_value,
-			80,
-		));
+		let seller = setup_token_listing::<T>(2_000u32.into());
 		let new_price: BalanceOf<T> = 5_000u32.into();
 		#[extrinsic_call]
-		upgrade_listing(RawOrigin::Signed(caller), 1, new_price);
-		/* 		assert_eq!(
-			NftMarketplace::<T>::ongoing_nft_details::<
-				<T as pallet::Config>::CollectionId,
-				<T as pallet::Config>::ItemId,
-			>(0.into(), 22.into())
-			.unwrap()
-			.price,
-			new_price
-		); */
+		upgrade_listing(RawOrigin::Signed(seller), 1, new_price);
 	}
 
 	#[benchmark]
 	fn upgrade_object() {
 		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
+		assert_ok!(Whitelist::<T>::add_to_whitelist(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			KycTier::Retail,
+			*b"GB",
+			Bounded::max_value()
+		));
 		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
 			.try_into()
 			.unwrap();
@@ -409,6 +367,8 @@ mod benchmarks {
 			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
 				.try_into()
 				.unwrap(),
+				1,
+				None,
 		));
 		let new_price: BalanceOf<T> = 300_000u32.into();
 		#[extrinsic_call]
@@ -418,36 +378,9 @@ mod benchmarks {
 
 	#[benchmark]
 	fn delist_token() {
-		let (caller, value) = setup_object_listing::<T>();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
-		let location = vec![0; <T as pallet::Config>::PostcodeLimit::get() as usize]
-			.try_into()
-			.unwrap();
-		assert_ok!(NftMarketplace::<T>::list_object(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			location,
-			value,
-			100,
-			vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
-				.try_into()
-				.unwrap(),
-		));
-		assert_ok!(NftMarketplace::<T>::buy_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			100
-		));
-		let listing_value: BalanceOf<T> = 2_000u32.into();
-		assert_ok!(NftMarketplace::<T>::relist_token(
-			RawOrigin::Signed(caller.clone()).into(),
-			0,
-			0.into(),
-			listing_value,
-			80,
-		));
+		let seller = setup_token_listing::<T>(2_000u32.into());
 		#[extrinsic_call]
-		delist_token(RawOrigin::Signed(caller), 1);
+		delist_token(RawOrigin::Signed(seller), 1);
 		//assert_eq!(NftMarketplace::<T>::listed_nfts().len(), 0);
 	}
 
@@ -467,5 +400,159 @@ mod benchmarks {
 		create_new_region(RawOrigin::Root);
 	}
 
+	#[benchmark]
+	fn expire_offer() {
+		let (_, token_buyer) = setup_offer::<T>();
+		let expiry = OngoingOffers::<T>::get(1, token_buyer.clone()).unwrap().expiry;
+		#[block]
+		{
+			NftMarketplace::<T>::expire_offer(1, token_buyer.clone(), expiry);
+		}
+		assert_eq!(OngoingOffers::<T>::get(1, token_buyer), None);
+	}
+
+	#[benchmark]
+	fn refund_offer() {
+		let (caller, token_buyer) = setup_offer::<T>();
+		assert_ok!(NftMarketplace::<T>::delist_token(RawOrigin::Signed(caller).into(), 1));
+		#[block]
+		{
+			NftMarketplace::<T>::refund_offers(1, 1);
+		}
+		assert_eq!(OngoingOffers::<T>::get(1, token_buyer), None);
+	}
+
+	#[benchmark]
+	fn claim_bid_refund() {
+		let (caller, _) = setup_object_listing::<T>();
+		let refund: AssetBalanceOf<T> = 1_000u32.into();
+		let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(NftMarketplace::<T>::account_id());
+		assert_ok!(Assets::<T, Instance1>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			<T as pallet::Config>::Helper::to_asset(1).into(),
+			pallet_lookup,
+			refund,
+		));
+		FailedBidRefunds::<T>::insert(caller.clone(), 1, refund);
+		#[extrinsic_call]
+		claim_bid_refund(RawOrigin::Signed(caller.clone()), 1);
+
+		assert_eq!(FailedBidRefunds::<T>::get(caller, 1), Default::default());
+	}
+
+	#[benchmark]
+	fn set_transfer_fee() {
+		setup_object_listing::<T>();
+		let fee: AssetBalanceOf<T> = 1u32.into();
+		#[extrinsic_call]
+		set_transfer_fee(RawOrigin::Root, 1, fee);
+
+		assert_eq!(TransferFee::<T>::get(1), fee);
+	}
+
+	#[benchmark]
+	fn transfer_property_token() {
+		let investor = setup_sold_property::<T>();
+		let receiver: T::AccountId = whitelisted_caller();
+		let fee: AssetBalanceOf<T> = 1u32.into();
+		assert_ok!(NftMarketplace::<T>::set_transfer_fee(RawOrigin::Root.into(), 1, fee));
+		let token = PropertyOwnerToken::<T>::get(0, investor.clone());
+		#[extrinsic_call]
+		transfer_property_token(RawOrigin::Signed(investor.clone()), 0, receiver.clone(), 1, 1);
+
+		assert_eq!(PropertyOwnerToken::<T>::get(0, investor), token - 1);
+		assert_eq!(PropertyOwnerToken::<T>::get(0, receiver), 1);
+	}
+
+	#[benchmark]
+	fn release_quarantined_funds() {
+		let (caller, _) = setup_object_listing::<T>();
+		let amount: AssetBalanceOf<T> = 1_000u32.into();
+		let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(NftMarketplace::<T>::account_id());
+		assert_ok!(Assets::<T, Instance1>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			<T as pallet::Config>::Helper::to_asset(1).into(),
+			pallet_lookup,
+			amount,
+		));
+		QuarantinedFunds::<T>::insert(caller.clone(), 1, amount);
+		#[extrinsic_call]
+		release_quarantined_funds(RawOrigin::Root, caller.clone(), 1);
+
+		assert_eq!(QuarantinedFunds::<T>::get(caller, 1), Default::default());
+	}
+
+	#[benchmark]
+	fn review_timeout(b: Linear<1, 100>) {
+		setup_legal_case::<T>(b);
+		let deadline =
+			PropertyLawyer::<T>::get(0).unwrap().real_estate_developer_deadline.unwrap();
+		// the last allowed timeout refunds the investors.
+		PropertyLawyer::<T>::mutate(0, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.review_timeouts =
+					<T as pallet::Config>::MaxReviewTimeouts::get().saturating_sub(1);
+			}
+		});
+		#[block]
+		{
+			assert_ok!(NftMarketplace::<T>::review_timeout(
+				0,
+				LegalProperty::RealEstateDeveloperSide,
+				deadline
+			));
+		}
+		assert!(PropertyLawyer::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn expire_arbitration(b: Linear<1, 100>) {
+		setup_legal_case::<T>(b);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		Arbitrations::<T>::insert(0, deadline);
+		#[block]
+		{
+			assert_ok!(NftMarketplace::<T>::expire_arbitration(0, deadline));
+		}
+		assert!(PropertyLawyer::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn finish_soft_cap(b: Linear<1, 99>) {
+		let deadline = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		let soft_cap = SoftCap { minimum_sold: Percent::from_percent(100), deadline };
+		setup_listing_with_buyers::<T>(b, 99, Some(soft_cap));
+		#[block]
+		{
+			assert_ok!(NftMarketplace::<T>::finish_soft_cap(0));
+		}
+		assert!(OngoingObjectListing::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn lawyer_confirm_documents(b: Linear<1, 100>) {
+		let developer_lawyer = setup_legal_case::<T>(b);
+		let spv_lawyer = setup_lawyer::<T>(1);
+		assert_ok!(NftMarketplace::<T>::lawyer_claim_property(
+			RawOrigin::Signed(spv_lawyer.clone()).into(),
+			0,
+			LegalProperty::SpvSide,
+			Zero::zero(),
+		));
+		let document_hashes: BoundedVec<T::Hash, T::MaxDocumentHashes> =
+			vec![Default::default(); T::MaxDocumentHashes::get() as usize].try_into().unwrap();
+		assert_ok!(NftMarketplace::<T>::lawyer_confirm_documents(
+			RawOrigin::Signed(developer_lawyer).into(),
+			0,
+			true,
+			document_hashes.clone(),
+			None,
+		));
+		#[extrinsic_call]
+		lawyer_confirm_documents(RawOrigin::Signed(spv_lawyer), 0, true, document_hashes, None);
+
+		assert!(PropertyLawyer::<T>::get(0).is_none());
+	}
+
 	impl_benchmark_test_suite!(NftMarketplace, crate::mock::new_test_ext(), crate::mock::Test);
 }
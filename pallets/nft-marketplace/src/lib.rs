@@ -11,18 +11,23 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 use pallet_assets::Instance1;
 
 use frame_support::{
-	traits::{Currency, Incrementable, ReservableCurrency},
+	traits::{fungibles, BalanceStatus, Currency, Incrementable, ReservableCurrency},
 	PalletId,
 };
 
-use frame_support::sp_runtime::traits::{
-	AccountIdConversion, CheckedAdd, CheckedSub, CheckedDiv, CheckedMul, StaticLookup,
+use frame_support::sp_runtime::{
+	traits::{
+		AccountIdConversion, CheckedAdd, CheckedSub, CheckedDiv, CheckedMul, One, Saturating,
+		StaticLookup, Zero,
+	},
+	PerThing, Percent, SaturatedConversion,
 };
 
 use enumflags2::BitFlags;
@@ -33,7 +38,7 @@ use pallet_nfts::{
 
 use frame_system::RawOrigin;
 
-use codec::Codec;
+use codec::{Codec, FullCodec};
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
@@ -45,13 +50,24 @@ type CurrencyBalanceOf<T> = <<T as pallet_nfts::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 
+pub type BalanceOf<T> = <<T as pallet::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// The maximum amount of blocks that a deadline gets moved to a later block if the blocks are full.
+	const MAX_ROLLOVER_BLOCKS: u32 = 100;
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -96,6 +112,8 @@ pub mod pallet {
 		pub item_id: ItemId,
 		pub collection_id: CollectionId,
 		pub token_amount: u32,
+		pub payment_asset: u32,
+		pub listing_expiry: BlockNumberFor<T>,
 	}
 
 	/// Infos regarding the listing of a token.
@@ -109,6 +127,26 @@ pub mod pallet {
 		pub item_id: ItemId,
 		pub collection_id: CollectionId,
 		pub amount: u32,
+		pub payment_asset: u32,
+		pub dutch_auction: Option<DutchAuctionDetails<Balance, BlockNumberFor<T>>>,
+	}
+
+	/// Infos regarding a token listing whose price falls over time.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct DutchAuctionDetails<Balance, BlockNumber> {
+		pub start_price: Balance,
+		pub floor_price: Balance,
+		pub start_block: BlockNumber,
+		pub end_block: BlockNumber,
+	}
+
+	/// The minimum share of token that has to be sold until the deadline of a listing.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct SoftCap<BlockNumber> {
+		pub minimum_sold: Percent,
+		pub deadline: BlockNumber,
 	}
 
 	/// Infos regarding the asset id.
@@ -132,6 +170,21 @@ pub mod pallet {
 		pub buyer: AccountIdOf<T>,
 		pub token_price: Balance,
 		pub amount: u32,
+		pub fees: Balance,
+		pub payment_asset: u32,
+		pub expiry: BlockNumberFor<T>,
+		pub state: OfferState<Balance>,
+	}
+
+	/// Infos regarding a registered lawyer.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct LawyerDetails<T: Config> {
+		pub firm_name_hash: T::Hash,
+		pub regions: BoundedVec<RegionId, T::MaxLawyerRegions>,
+		pub bond: BalanceOf<T>,
+		pub active_cases: u32,
+		pub unbonding_until: Option<BlockNumberFor<T>>,
 	}
 
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -145,6 +198,10 @@ pub mod pallet {
 		pub real_estate_developer_lawyer_costs: AssetBalanceOf<T>,
 		pub spv_lawyer_costs: AssetBalanceOf<T>,
 		pub second_attempt: bool,
+		pub real_estate_developer_deadline: Option<BlockNumberFor<T>>,
+		pub spv_deadline: Option<BlockNumberFor<T>>,
+		pub review_timeouts: u32,
+		pub review_round: u8,
 	}
 
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -154,11 +211,24 @@ pub mod pallet {
 		pub token_amount: u32,
 		pub paid_funds: Balance,
 		pub paid_tax: Balance,
+		pub paid_fee: Balance,
+	}
+
+	/// Infos regarding a bid in the auction of a listed object.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct BidDetails<Balance, T: Config> {
+		pub bidder: AccountIdOf<T>,
+		pub token_price: Balance,
+		pub amount: u32,
+		pub fee: Balance,
+		pub tax: Balance,
 	}
 
 	impl<Balance, T: Config> OfferDetails<Balance, T>
 	where
-		Balance: CheckedMul + TryFrom<u64>,
+		Balance: CheckedMul + CheckedAdd + TryFrom<u64>,
 	{
 		pub fn get_total_amount(&self) -> Result<Balance, Error<T>> {
 			let amount_in_balance: Balance = (self.amount as u64)
@@ -169,14 +239,214 @@ pub mod pallet {
 				.checked_mul(&amount_in_balance)
 				.ok_or(Error::<T>::MultiplyError)
 		}
+
+		/// Returns the offered price plus the fees that are held in escrow for the offer.
+		pub fn get_escrowed_amount(&self) -> Result<Balance, Error<T>> {
+			self.get_total_amount()?
+				.checked_add(&self.fees)
+				.ok_or(Error::<T>::ArithmeticOverflow)
+		}
+	}
+
+	/// Fees and tax that apply to a sale on the marketplace.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct FeeDetails {
+		/// Fee paid by the buyer on top of the price.
+		pub buyer_fee: Percent,
+		/// Tax paid by the buyer on top of the price.
+		pub tax: Percent,
+		/// Part of the price that goes to the treasury instead of the seller.
+		pub treasury_fee: Percent,
+	}
+
+	/// The fee schedule for primary and secondary sales.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct FeeScheduleDetails {
+		pub primary: FeeDetails,
+		pub secondary: FeeDetails,
+	}
+
+	impl Default for FeeScheduleDetails {
+		fn default() -> Self {
+			FeeScheduleDetails {
+				primary: FeeDetails {
+					buyer_fee: Percent::from_percent(1),
+					tax: Percent::from_percent(3),
+					treasury_fee: Percent::from_percent(1),
+				},
+				secondary: FeeDetails {
+					buyer_fee: Percent::from_percent(0),
+					tax: Percent::from_percent(0),
+					treasury_fee: Percent::from_percent(1),
+				},
+			}
+		}
+	}
+
+	/// The kind of a listing returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum ListingType {
+		/// Token of a newly listed real estate object.
+		Object,
+		/// Token that have been relisted by a token owner.
+		Relisted,
+	}
+
+	/// Infos regarding an active listing returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(
+			serialize = "AccountId: serde::Serialize, Balance: std::fmt::Display, \
+				BlockNumber: serde::Serialize",
+			deserialize = "AccountId: serde::Deserialize<'de>, Balance: std::str::FromStr, \
+				BlockNumber: serde::Deserialize<'de>"
+		))
+	)]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ListingInfo<AccountId, Balance, BlockNumber> {
+		pub listing_id: ListingId,
+		pub listing_type: ListingType,
+		pub seller: AccountId,
+		pub asset_id: u32,
+		pub region: RegionId,
+		pub location: sp_std::vec::Vec<u8>,
+		#[cfg_attr(feature = "std", serde(with = "crate::serde_balance"))]
+		pub token_price: Balance,
+		pub remaining_token: u32,
+		pub payment_asset: u32,
+		pub expiry: Option<BlockNumber>,
+	}
+
+	/// A page of active listings returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(
+			serialize = "AccountId: serde::Serialize, Balance: std::fmt::Display, \
+				BlockNumber: serde::Serialize",
+			deserialize = "AccountId: serde::Deserialize<'de>, Balance: std::str::FromStr, \
+				BlockNumber: serde::Deserialize<'de>"
+		))
+	)]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ListingPage<AccountId, Balance, BlockNumber> {
+		pub listings: sp_std::vec::Vec<ListingInfo<AccountId, Balance, BlockNumber>>,
+		/// The listing id to continue the search at, `None` if all listings have been searched.
+		pub next: Option<ListingId>,
+	}
+
+	/// Infos regarding an offer returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(
+			serialize = "AccountId: serde::Serialize, Balance: std::fmt::Display, \
+				BlockNumber: serde::Serialize",
+			deserialize = "AccountId: serde::Deserialize<'de>, Balance: std::str::FromStr, \
+				BlockNumber: serde::Deserialize<'de>"
+		))
+	)]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct OfferInfo<AccountId, Balance, BlockNumber> {
+		pub offeror: AccountId,
+		#[cfg_attr(feature = "std", serde(with = "crate::serde_balance"))]
+		pub token_price: Balance,
+		pub amount: u32,
+		#[cfg_attr(feature = "std", serde(with = "crate::serde_balance"))]
+		pub fees: Balance,
+		pub payment_asset: u32,
+		pub expiry: BlockNumber,
+		#[cfg_attr(feature = "std", serde(with = "crate::serde_balance::option"))]
+		pub counter_price: Option<Balance>,
+	}
+
+	/// The state of the token of a holding.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(serialize = "Balance: std::fmt::Display", deserialize = "Balance: std::str::FromStr"))
+	)]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum HoldingStatus<Balance> {
+		/// The token have been bought from a listed object that has not been settled yet.
+		Pending {
+			listing_id: ListingId,
+			#[cfg_attr(feature = "std", serde(with = "crate::serde_balance"))]
+			paid_funds: Balance,
+			#[cfg_attr(feature = "std", serde(with = "crate::serde_balance"))]
+			paid_tax: Balance,
+		},
+		/// The token are owned by the account.
+		Settled,
+	}
+
+	/// Infos regarding the token of an account returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(serialize = "Balance: std::fmt::Display", deserialize = "Balance: std::str::FromStr"))
+	)]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct HoldingInfo<Balance> {
+		pub asset_id: u32,
+		pub token_amount: u32,
+		pub status: HoldingStatus<Balance>,
+	}
+
+	/// The documents that a lawyer reviewed for a legal side of a property.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct DocumentReview<T: Config> {
+		pub lawyer: AccountIdOf<T>,
+		pub document_hashes: BoundedVec<T::Hash, T::MaxDocumentHashes>,
+		pub approved: bool,
+		pub reason: Option<RejectionReason>,
+		pub reviewed_at: BlockNumberFor<T>,
+	}
+
+	/// Infos regarding the reviewed documents of a legal side returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct LegalDocumentsInfo<AccountId, Hash, BlockNumber> {
+		pub legal_side: LegalProperty,
+		pub round: u8,
+		pub lawyer: AccountId,
+		pub document_hashes: sp_std::vec::Vec<Hash>,
+		pub approved: bool,
+		pub reason: Option<RejectionReason>,
+		pub reviewed_at: BlockNumber,
+	}
+
+	/// Sale type enum.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub enum SaleType {
+		Primary,
+		Secondary,
 	}
 
 	/// Offer enum.
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub enum Offer {
+	pub enum Offer<Balance> {
 		Accept,
 		Reject,
+		/// Counter the offer with a different price per token.
+		Counter(Balance),
+		/// Accept the offer for only some of the token.
+		PartialAccept(u32),
+	}
+
+	/// Negotiation state of an offer.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub enum OfferState<Balance> {
+		Pending,
+		Countered { token_price: Balance },
 	}
 
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -194,6 +464,26 @@ pub mod pallet {
 		Rejected,
 	}
 
+	/// The decision of the arbiter on a disputed legal case.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub enum ArbitrationDecision {
+		Execute,
+		Refund,
+		Review,
+	}
+
+	/// The reason why a lawyer rejected the documents of a property.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub enum RejectionReason {
+		MissingDocuments,
+		InvalidDocuments,
+		TitleDefect,
+		RegulatoryIssue,
+		Other,
+	}
+
 	/// AccountId storage.
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -298,6 +588,87 @@ pub mod pallet {
 		/// The maximum length of data stored in for post codes.
 		#[pallet::constant]
 		type PostcodeLimit: Get<u32>;
+
+		/// Origin who can update the fee schedule.
+		type FeeScheduleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin who can resolve a disputed legal case.
+		type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin who can add and remove accepted payment assets.
+		type PaymentAssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The amount of blocks that a listed object can be bought before it expires.
+		#[pallet::constant]
+		type ListingDuration: Get<BlockNumberFor<Self>>;
+
+		/// The amount of blocks that an offer stays valid.
+		#[pallet::constant]
+		type OfferDuration: Get<BlockNumberFor<Self>>;
+
+		/// The maximum amount of offers that can expire or get refunded in a block.
+		#[pallet::constant]
+		type MaxOffersForBlock: Get<u32>;
+
+		/// The amount of blocks that bids can be placed on an object listed in an auction.
+		#[pallet::constant]
+		type AuctionDuration: Get<BlockNumberFor<Self>>;
+
+		/// The maximum amount of auctions that can end in a block.
+		#[pallet::constant]
+		type MaxAuctionsForBlock: Get<u32>;
+
+		/// The maximum amount of purchases in a batch.
+		#[pallet::constant]
+		type MaxBatchPurchases: Get<u32>;
+
+		/// The amount that is reserved from a lawyer on registration.
+		#[pallet::constant]
+		type LawyerBond: Get<BalanceOf<Self>>;
+
+		/// The amount of blocks after deregistration until a lawyer can withdraw the bond.
+		#[pallet::constant]
+		type LawyerUnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum amount of regions that a lawyer can be licensed for.
+		#[pallet::constant]
+		type MaxLawyerRegions: Get<u32>;
+
+		/// The amount of blocks that a lawyer has to review the documents after claiming a case.
+		#[pallet::constant]
+		type LawyerReviewPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The amount of review timeouts after which the investors of a property get refunded.
+		#[pallet::constant]
+		type MaxReviewTimeouts: Get<u32>;
+
+		/// The maximum amount of review deadlines that can end in a block.
+		#[pallet::constant]
+		type MaxReviewDeadlinesForBlock: Get<u32>;
+
+		/// The maximum amount of document hashes that a lawyer can submit for a review.
+		#[pallet::constant]
+		type MaxDocumentHashes: Get<u32>;
+
+		/// The amount of blocks that the arbiter has to resolve a disputed legal case.
+		#[pallet::constant]
+		type ArbitrationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum amount of arbitrations that can end in a block.
+		#[pallet::constant]
+		type MaxArbitrationsForBlock: Get<u32>;
+
+		/// The maximum amount of soft cap deadlines that can end in a block.
+		#[pallet::constant]
+		type MaxSoftCapsForBlock: Get<u32>;
+
+		/// The maximum share of the token of a property that a single investor can hold.
+		#[pallet::constant]
+		type MaxOwnershipShare: Get<Percent>;
+
+		/// The maximum amount of listings that can be checked in a compliance enforcement.
+		#[pallet::constant]
+		type MaxComplianceListings: Get<u32>;
 	}
 
 	pub type FractionalizedAssetId<T> = <T as Config>::AssetId;
@@ -317,6 +688,15 @@ pub mod pallet {
 		T,
 	>;
 
+	pub type ListingInfoOf<T> = ListingInfo<AccountIdOf<T>, AssetBalanceOf<T>, BlockNumberFor<T>>;
+
+	pub type ListingPageOf<T> = ListingPage<AccountIdOf<T>, AssetBalanceOf<T>, BlockNumberFor<T>>;
+
+	pub type OfferInfoOf<T> = OfferInfo<AccountIdOf<T>, AssetBalanceOf<T>, BlockNumberFor<T>>;
+
+	pub type LegalDocumentsInfoOf<T> =
+		LegalDocumentsInfo<AccountIdOf<T>, <T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
 	pub(super) type ListingDetailsType<T> = TokenListingDetails<
 		AssetBalanceOf<T>,
 		<T as pallet::Config>::ItemId,
@@ -430,6 +810,12 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Mapping from the asset id of a property to the maximum amount of token that a single
+	/// investor can hold. Set by the real estate developer.
+	#[pallet::storage]
+	pub(super) type InvestorLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, u32, OptionQuery>;
+
 	/// Mapping of the assetid to the collectionid and nftid.
 	#[pallet::storage]
 	pub type AssetIdDetails<T: Config> = StorageMap<
@@ -453,13 +839,25 @@ pub mod pallet {
 	>;
 
 	/// Stores the lawyer info.
+	/// Mapping from the account of a registered lawyer to the lawyer details.
 	#[pallet::storage]
 	pub(super) type RealEstateLawyer<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
 		AccountIdOf<T>,
-		bool,
-		ValueQuery,
+		LawyerDetails<T>,
+		OptionQuery,
+	>;
+
+	/// Mapping from the account of a lawyer that applied for registration to the lawyer details
+	/// that get registered once the application is approved.
+	#[pallet::storage]
+	pub(super) type LawyerApplications<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		LawyerDetails<T>,
+		OptionQuery,
 	>;
 
 	#[pallet::storage]
@@ -471,6 +869,171 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The fees and tax charged for primary and secondary sales.
+	#[pallet::storage]
+	pub type FeeSchedule<T: Config> = StorageValue<_, FeeScheduleDetails, ValueQuery>;
+
+	/// The flat fee charged for a transfer of property token between two investors, per payment
+	/// asset.
+	#[pallet::storage]
+	pub type TransferFee<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, AssetBalanceOf<T>, ValueQuery>;
+
+	/// Stores the offers that expire in a block.
+	#[pallet::storage]
+	pub(super) type OffersExpiring<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(ListingId, AccountIdOf<T>), T::MaxOffersForBlock>,
+		ValueQuery,
+	>;
+
+	/// Offers whose escrowed funds could not be refunded when they expired or their listing
+	/// closed. The offeror can claim the refund with `cancel_offer`.
+	#[pallet::storage]
+	pub(super) type FailedOfferRefunds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ListingId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		OfferDetails<AssetBalanceOf<T>, T>,
+		OptionQuery,
+	>;
+
+	/// True if a token listing has been closed and still has offers to refund.
+	#[pallet::storage]
+	pub(super) type ClosedListings<T: Config> =
+		StorageMap<_, Blake2_128Concat, ListingId, bool, ValueQuery>;
+
+	/// Mapping from the listing id of an object in an auction to the block the auction ends.
+	#[pallet::storage]
+	pub type ObjectAuctions<T: Config> =
+		StorageMap<_, Blake2_128Concat, ListingId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The bids that have been placed in the auction of a listed object.
+	#[pallet::storage]
+	pub type AuctionBids<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ListingId,
+		BoundedVec<BidDetails<AssetBalanceOf<T>, T>, T::MaxNftToken>,
+		ValueQuery,
+	>;
+
+	/// The escrowed funds of bids that could not be refunded, per bidder and payment asset. The
+	/// bidder can claim them with `claim_bid_refund`.
+	#[pallet::storage]
+	pub(super) type FailedBidRefunds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		u32,
+		AssetBalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The funds owed to blocked accounts, per account and payment asset. The funds are held by
+	/// the pallet account until the account gets unblocked and the funds released.
+	#[pallet::storage]
+	pub type QuarantinedFunds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		u32,
+		AssetBalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Stores the auctions that end in a block.
+	#[pallet::storage]
+	pub(super) type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ListingId, T::MaxAuctionsForBlock>,
+		ValueQuery,
+	>;
+
+	/// Stores the legal sides of the cases whose review deadline ends in a block.
+	#[pallet::storage]
+	pub(super) type ReviewDeadlines<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(ListingId, LegalProperty), T::MaxReviewDeadlinesForBlock>,
+		ValueQuery,
+	>;
+
+	/// Stores the documents that the lawyers reviewed for each legal side and review round of
+	/// a property. The first review is round 1, every further review increments the round.
+	#[pallet::storage]
+	pub(super) type LegalDocuments<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ListingId>,
+			NMapKey<Blake2_128Concat, LegalProperty>,
+			NMapKey<Blake2_128Concat, u8>,
+		),
+		DocumentReview<T>,
+		OptionQuery,
+	>;
+
+	/// Mapping from the listing id of a disputed legal case to the deadline of the arbitration.
+	#[pallet::storage]
+	pub(super) type Arbitrations<T: Config> =
+		StorageMap<_, Blake2_128Concat, ListingId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Stores the arbitrations that end in a block.
+	#[pallet::storage]
+	pub(super) type ArbitrationsExpiring<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ListingId, T::MaxArbitrationsForBlock>,
+		ValueQuery,
+	>;
+
+	/// Mapping from the listing id of a listed object to its soft cap.
+	#[pallet::storage]
+	pub(super) type SoftCaps<T: Config> =
+		StorageMap<_, Blake2_128Concat, ListingId, SoftCap<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Stores the listings whose soft cap deadline ends in a block.
+	#[pallet::storage]
+	pub(super) type SoftCapsEnding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ListingId, T::MaxSoftCapsForBlock>,
+		ValueQuery,
+	>;
+
+	/// True if an asset is accepted as payment for listings.
+	#[pallet::storage]
+	pub type PaymentAssets<T: Config> = StorageMap<_, Blake2_128Concat, u32, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The assets that are accepted as payment at genesis.
+		pub payment_assets: sp_std::vec::Vec<u32>,
+		#[serde(skip)]
+		pub _config: sp_std::marker::PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for payment_asset in &self.payment_assets {
+				PaymentAssets::<T>::insert(payment_asset, true);
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -481,7 +1044,7 @@ pub mod pallet {
 			price: AssetBalanceOf<T>,
 			seller: AccountIdOf<T>,
 		},
-		/// A token has been bought.
+		/// A token has been bought. `price` is the price per token that the buyer paid.
 		TokenBought { asset_id: u32, buyer: AccountIdOf<T>, price: AssetBalanceOf<T> },
 		/// Token from listed object have been bought.
 		TokenBoughtObject { asset_id: u32, buyer: AccountIdOf<T>, amount: u32, price: AssetBalanceOf<T> },
@@ -502,7 +1065,53 @@ pub mod pallet {
 		/// An offer has been cancelled.
 		OfferCancelled { listing_id: ListingId, account_id: AccountIdOf<T> },
 		/// A lawyer has been registered.
-		LawyerRegistered { lawyer: AccountIdOf<T> },
+		LawyerRegistered { lawyer: AccountIdOf<T>, regions: BoundedVec<RegionId, T::MaxLawyerRegions> },
+		/// A lawyer applied for registration and the bond has been reserved.
+		LawyerApplied { lawyer: AccountIdOf<T>, regions: BoundedVec<RegionId, T::MaxLawyerRegions> },
+		/// The application of a lawyer has been rejected and the bond has been released.
+		LawyerApplicationRejected { lawyer: AccountIdOf<T> },
+		/// A lawyer has been deregistered and the bond is unbonding.
+		LawyerUnbonding { lawyer: AccountIdOf<T>, unbonding_until: BlockNumberFor<T> },
+		/// A lawyer withdrew the bond and has been removed from the registry.
+		LawyerUnregistered { lawyer: AccountIdOf<T> },
+		/// The bond of a lawyer has been slashed.
+		LawyerSlashed { lawyer: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// A lawyer topped up the bond.
+		LawyerBondToppedUp { lawyer: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// A lawyer did not review the documents in time and has been removed from the case.
+		LawyerReviewTimedOut {
+			lawyer: AccountIdOf<T>,
+			listing_id: ListingId,
+			legal_side: LegalProperty,
+		},
+		/// The investors of a property have been refunded after repeated review timeouts.
+		LegalProcessTimedOut { listing_id: ListingId },
+		/// The lawyers disagreed twice and the case has been handed to the arbiter.
+		ArbitrationStarted { listing_id: ListingId, deadline: BlockNumberFor<T> },
+		/// The arbiter resolved a disputed legal case.
+		ArbitrationResolved { listing_id: ListingId, decision: ArbitrationDecision },
+		/// The arbiter did not resolve a disputed legal case in time and the investors have been refunded.
+		ArbitrationExpired { listing_id: ListingId },
+		/// The soft cap of a listing has been reached and the sold token get settled.
+		SoftCapReached { listing_id: ListingId, sold_token: u32 },
+		/// The soft cap of a listing has been missed and the investors have been refunded.
+		SoftCapMissed { listing_id: ListingId, sold_token: u32 },
+		/// The real estate developer updated the maximum amount of token per investor.
+		InvestorLimitSet { listing_id: ListingId, asset_id: u32, max_token: Option<u32> },
+		/// An account has been removed from the whitelist or blocked, its listings, offers, bids
+		/// and pending purchases have to be closed.
+		ComplianceCheckRequired { account: AccountIdOf<T> },
+		/// The listings of a removed account have been returned and its offers, bids and pending
+		/// purchases refunded. Bids whose refund failed are counted in `failed_refunds` and can be
+		/// claimed with `claim_bid_refund`.
+		ComplianceEnforced {
+			account: AccountIdOf<T>,
+			returned_listings: u32,
+			refunded_offers: u32,
+			refunded_bids: u32,
+			failed_refunds: u32,
+			refunded_purchases: u32,
+		},
 		/// A lawyer claimed a property.
 		LawyerClaimedProperty { lawyer: AccountIdOf<T>, listing_id: ListingId, legal_side: LegalProperty},
 		/// A lawyer stepped back from a legal case.
@@ -511,6 +1120,73 @@ pub mod pallet {
 		DocumentsConfirmed { signer: AccountIdOf<T>, listing_id: ListingId, approve: bool },
 		/// The property nft got burned.
 		PropertyNftBurned { collection_id: CollectionId<T>, item_id: ItemId<T>, asset_id: u32 },
+		/// The fee schedule of a sale type has been updated.
+		FeeScheduleUpdated { sale_type: SaleType, fee_details: FeeDetails },
+		/// The fee for transfers of property token has been updated.
+		TransferFeeSet { payment_asset: u32, fee: AssetBalanceOf<T> },
+		/// Property token have been transferred between two investors.
+		PropertyTokenTransferred {
+			asset_id: u32,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+			amount: u32,
+			payment_asset: u32,
+			fee: AssetBalanceOf<T>,
+		},
+		/// An asset has been added to the accepted payment assets.
+		PaymentAssetAdded { asset_id: u32 },
+		/// An asset has been removed from the accepted payment assets.
+		PaymentAssetRemoved { asset_id: u32 },
+		/// A listed object expired before all token were sold and the investors got refunded.
+		ListingExpired { listing_id: ListingId },
+		/// A listed object has been cancelled and the investors got refunded.
+		ObjectListingCancelled { listing_id: ListingId },
+		/// An offer has expired and the escrowed funds got refunded.
+		OfferExpired { listing_id: ListingId, account_id: AccountIdOf<T> },
+		/// An offer on a closed listing has been refunded.
+		OfferRefunded { listing_id: ListingId, account_id: AccountIdOf<T> },
+		/// The escrowed funds of an offer could not be refunded and can be claimed with
+		/// `cancel_offer`.
+		OfferRefundFailed { listing_id: ListingId, account_id: AccountIdOf<T> },
+		/// The seller countered an offer with a different price.
+		OfferCountered { listing_id: ListingId, offeror: AccountIdOf<T>, token_price: AssetBalanceOf<T> },
+		/// Token have been listed in a dutch auction.
+		DutchAuctionListed {
+			listing_id: ListingId,
+			asset_id: u32,
+			start_price: AssetBalanceOf<T>,
+			floor_price: AssetBalanceOf<T>,
+			end_block: BlockNumberFor<T>,
+			seller: AccountIdOf<T>,
+		},
+		/// A new object has been listed in an auction.
+		AuctionStarted {
+			listing_id: ListingId,
+			reserve_price: AssetBalanceOf<T>,
+			end_block: BlockNumberFor<T>,
+			seller: AccountIdOf<T>,
+		},
+		/// A bid has been placed in an auction.
+		BidPlaced { listing_id: ListingId, bidder: AccountIdOf<T>, token_price: AssetBalanceOf<T>, amount: u32 },
+		/// A bid has been removed from a full auction by a higher bid and got refunded.
+		BidOutbid { listing_id: ListingId, bidder: AccountIdOf<T> },
+		/// The escrowed funds of a bid could not be refunded and can be claimed with
+		/// `claim_bid_refund`.
+		BidRefundFailed { listing_id: ListingId, bidder: AccountIdOf<T> },
+		/// A bidder claimed the refund of bids that could not be refunded before.
+		BidRefundClaimed { bidder: AccountIdOf<T>, payment_asset: u32, amount: AssetBalanceOf<T> },
+		/// An auction has ended and the token have been allocated to the highest bids.
+		AuctionFinished { listing_id: ListingId, sold_token: u32 },
+		/// A purchase of a batch failed, the whole batch gets reverted.
+		BatchItemFailed { index: u32, listing_id: ListingId, error: DispatchError },
+		/// Funds owed to a blocked account have been put into quarantine.
+		FundsQuarantined { account: AccountIdOf<T>, payment_asset: u32, amount: AssetBalanceOf<T> },
+		/// Quarantined funds have been released to an account.
+		QuarantinedFundsReleased {
+			account: AccountIdOf<T>,
+			payment_asset: u32,
+			amount: AssetBalanceOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -562,6 +1238,184 @@ pub mod pallet {
 		AlreadyConfirmed,
 		/// The costs of the lawyer can't be that high.
 		CostsTooHigh,
+		/// The asset is not accepted as payment.
+		PaymentAssetNotSupported,
+		/// The asset is already accepted as payment.
+		PaymentAssetAlreadySupported,
+		/// The asset does not exist.
+		AssetNotFound,
+		/// The token of a property can not be used as payment.
+		PropertyTokenNotAllowed,
+		/// The listing has expired.
+		ListingExpired,
+		/// The listing has not expired yet.
+		ListingNotExpired,
+		/// There are already too many offers expiring in this block.
+		TooManyOffers,
+		/// The amount has to be more than zero and less than the offered amount.
+		InvalidPartialAmount,
+		/// The seller has not countered the offer.
+		NoCounterOffer,
+		/// The floor price has to be below the start price and the duration above zero.
+		InvalidAuctionParameters,
+		/// The price of a dutch auction can not be updated.
+		PriceSetByAuction,
+		/// The listing is in an ongoing auction.
+		ListingInAuction,
+		/// The listing is not in an auction.
+		AuctionNotFound,
+		/// The auction has already ended.
+		AuctionEnded,
+		/// The bid is below the reserve price.
+		BidTooLow,
+		/// The amount has to be more than zero and not more than the token of the object.
+		InvalidBidAmount,
+		/// The auction has the maximum amount of bids and the bid is not higher than the lowest bid.
+		TooManyBids,
+		/// A new bid of a bidder has to raise the price or the amount of the previous bid.
+		BidNotRaised,
+		/// There is no refund of a bid to claim.
+		NoBidRefund,
+		/// The account has no funds in quarantine.
+		NoQuarantinedFunds,
+		/// There are already too many auctions ending in this block.
+		TooManyAuctions,
+		/// The total price including fees and tax exceeds the maximum price.
+		PriceLimitExceeded,
+		/// A purchase of the batch failed.
+		BatchItemFailed,
+		/// The lawyer is not licensed for the region of the property.
+		RegionNotLicensed,
+		/// The lawyer has been deregistered.
+		LawyerUnbonding,
+		/// The lawyer has not been deregistered.
+		LawyerNotUnbonding,
+		/// The unbonding period of the lawyer is not over yet.
+		UnbondingPeriodNotOver,
+		/// The lawyer is still handling cases.
+		LawyerHasActiveCases,
+		/// The bond of the lawyer is below the required bond and has to be topped up.
+		LawyerBondTooLow,
+		/// The bond of the lawyer is already complete.
+		LawyerBondComplete,
+		/// Too many review deadlines end in the blocks after the deadline.
+		TooManyReviewDeadlines,
+		/// The lawyer did not submit any document hashes.
+		NoDocumentHashes,
+		/// A reason must be given if and only if the documents are rejected.
+		InvalidRejectionReason,
+		/// The legal case is not in arbitration.
+		ArbitrationNotFound,
+		/// Too many arbitrations end in the blocks after the deadline.
+		TooManyArbitrations,
+		/// The minimum share of the soft cap must be above zero and the deadline must be
+		/// before the listing expires.
+		InvalidSoftCap,
+		/// Too many soft cap deadlines end in the blocks after the deadline.
+		TooManySoftCaps,
+		/// The investor would hold more token than the real estate developer allows.
+		InvestorLimitExceeded,
+		/// The investor would hold a bigger share of the property than allowed.
+		ConcentrationCapExceeded,
+		/// The maximum amount of token per investor must be above zero.
+		InvalidInvestorLimit,
+		/// The account is still whitelisted and not blocked.
+		AccountWhitelisted,
+		/// The account has no listings, offers, bids or pending purchases on the given listings.
+		NothingToEnforce,
+		/// The receiver of the token is not whitelisted.
+		ReceiverNotWhitelisted,
+		/// The seller of the token is no longer whitelisted.
+		SellerNotWhitelisted,
+		/// Token can not be transferred to the sender or with an amount of zero.
+		InvalidTransfer,
+		/// The lawyer already applied for registration.
+		LawyerAlreadyApplied,
+		/// The lawyer has not applied for registration.
+		LawyerApplicationNotFound,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			let expiring_offers = OffersExpiring::<T>::take(n);
+			// refunds the offers that expire in this block.
+			expiring_offers.iter().for_each(|(listing_id, offeror)| {
+				weight = weight.saturating_add(<T as pallet::Config>::WeightInfo::expire_offer());
+				Self::expire_offer(*listing_id, offeror.clone(), n);
+			});
+
+			// refunds the offers of closed listings.
+			let mut refunds_left =
+				T::MaxOffersForBlock::get().saturating_sub(expiring_offers.len() as u32);
+			while refunds_left > 0 {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+				let listing_id = match ClosedListings::<T>::iter_keys().next() {
+					Some(listing_id) => listing_id,
+					None => break,
+				};
+				let refunded_offers = Self::refund_offers(listing_id, refunds_left);
+				weight = weight.saturating_add(
+					<T as pallet::Config>::WeightInfo::refund_offer()
+						.saturating_mul(refunded_offers.into()),
+				);
+				refunds_left = refunds_left.saturating_sub(refunded_offers);
+				if refunds_left > 0 {
+					weight = weight.saturating_add(T::DbWeight::get().writes(1));
+					ClosedListings::<T>::remove(listing_id);
+				}
+			}
+
+			// allocates the token of the auctions that end in this block.
+			for listing_id in AuctionsEnding::<T>::take(n) {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 4));
+				let bids = AuctionBids::<T>::decode_len(listing_id).unwrap_or_default() as u64;
+				weight = weight.saturating_add(
+					T::DbWeight::get().reads_writes(10, 6).saturating_mul(bids),
+				);
+				if frame_support::storage::with_storage_layer(|| Self::finish_auction(listing_id))
+					.is_err()
+				{
+					Self::refund_bids(listing_id);
+				}
+			}
+
+			// removes the lawyers that did not review the documents in time.
+			for (listing_id, legal_side) in ReviewDeadlines::<T>::take(n) {
+				let buyers = TokenBuyer::<T>::decode_len(listing_id).unwrap_or_default() as u32;
+				weight = weight.saturating_add(
+					<T as pallet::Config>::WeightInfo::review_timeout(buyers),
+				);
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::review_timeout(listing_id, legal_side, n)
+				});
+			}
+
+			// refunds the investors of disputed cases that the arbiter did not resolve in time.
+			for listing_id in ArbitrationsExpiring::<T>::take(n) {
+				let buyers = TokenBuyer::<T>::decode_len(listing_id).unwrap_or_default() as u32;
+				weight = weight.saturating_add(
+					<T as pallet::Config>::WeightInfo::expire_arbitration(buyers),
+				);
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::expire_arbitration(listing_id, n)
+				});
+			}
+
+			// settles or refunds the listings whose soft cap deadline ends in this block.
+			for listing_id in SoftCapsEnding::<T>::take(n) {
+				let buyers = TokenBuyer::<T>::decode_len(listing_id).unwrap_or_default() as u32;
+				weight = weight.saturating_add(
+					<T as pallet::Config>::WeightInfo::finish_soft_cap(buyers),
+				);
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::finish_soft_cap(listing_id)
+				});
+			}
+			weight
+		}
 	}
 
 	#[pallet::call]
@@ -650,10 +1504,15 @@ pub mod pallet {
 		/// - `token_price`: The price of a single token.
 		/// - `token_amount`: The amount of tokens for a object.
 		/// - `data`: The Metadata of the nft.
+		/// - `payment_asset`: The asset that the token are paid with.
+		/// - `soft_cap`: The minimum share of token that has to be sold until the deadline.
+		/// If the soft cap is reached, the sold token get settled and the real estate developer
+		/// keeps the unsold token. Otherwise all investors get refunded.
 		///
 		/// Emits `ObjectListed` event when succesfful
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::list_object())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::list_object()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 2)))]
 		pub fn list_object(
 			origin: OriginFor<T>,
 			region: RegionId,
@@ -661,99 +1520,21 @@ pub mod pallet {
 			token_price: AssetBalanceOf<T>,
 			token_amount: u32,
 			data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
+			payment_asset: u32,
+			soft_cap: Option<SoftCap<BlockNumberFor<T>>>,
 		) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
-			ensure!(
-				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
-				Error::<T>::UserNotWhitelisted
-			);
-			ensure!(token_amount <= T::MaxNftToken::get(), Error::<T>::TooManyToken);
-			let collection_id: CollectionId<T> =
-				RegionCollections::<T>::get(region).ok_or(Error::<T>::RegionUnknown)?;
-			ensure!(
-				LocationRegistration::<T>::get(region, location.clone()),
-				Error::<T>::LocationUnknown
-			);
-			let mut next_item_id = NextNftId::<T>::get(collection_id);
-			let mut asset_number: u32 = NextAssetId::<T>::get();
-			let mut asset_id: AssetId<T> = asset_number.into();
-			while pallet_assets::Pallet::<T, Instance1>::maybe_total_supply(asset_id.into())
-				.is_some()
-			{
-				asset_number = asset_number.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-				asset_id = asset_number.into();
-			}
-			let asset_id: FractionalizedAssetId<T> = asset_number.into();
-			let item_id: ItemId<T> = next_item_id.into();
-			let mut listing_id = NextListingId::<T>::get();
-			let nft = NftListingDetails {
-				real_estate_developer: signer.clone(),
+			let listing_id = Self::do_list_object(
+				origin,
+				region,
+				location,
 				token_price,
-				collected_funds: Default::default(),
-				collected_tax: Default::default(),
-				collected_fees: Default::default(),
-				asset_id: asset_number,
-				item_id,
-				collection_id,
 				token_amount,
-			};
-			let pallet_account = Self::account_id();
-			pallet_nfts::Pallet::<T>::do_mint(
-				collection_id.into(),
-				item_id.into(),
-				Some(pallet_account.clone()),
-				pallet_account.clone(),
-				Self::default_item_config(),
-				|_, _| Ok(()),
-			)?;
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(pallet_account.clone()).into();
-			pallet_nfts::Pallet::<T>::set_metadata(
-				pallet_origin.clone(),
-				collection_id.into(),
-				item_id.into(),
-				data.clone(),
-			)?;
-			let registered_nft_details = NftDetails {
-				spv_created: false,
-				asset_id: asset_number,
-				region,
-				location: location.clone(),
-			};
-			RegisteredNftDetails::<T>::insert(collection_id, item_id, registered_nft_details);
-			OngoingObjectListing::<T>::insert(listing_id, nft.clone());
-			ListedToken::<T>::insert(listing_id, token_amount);
-
-			let user_lookup = <T::Lookup as StaticLookup>::unlookup(pallet_account);
-			let nft_balance: FrationalizedNftBalanceOf<T> = token_amount.into();
-			let fractionalize_collection_id = FractionalizeCollectionId::<T>::from(collection_id);
-			let fractionalize_item_id = FractionalizeItemId::<T>::from(item_id);
-			pallet_nft_fractionalization::Pallet::<T>::fractionalize(
-				pallet_origin.clone(),
-				fractionalize_collection_id.into(),
-				fractionalize_item_id.into(),
-				asset_id.into(),
-				user_lookup,
-				nft_balance,
+				data,
+				payment_asset,
 			)?;
-			let property_price = token_price
-				.checked_mul(&Self::u64_to_balance_option(token_amount as u64)?)
-				.ok_or(Error::<T>::MultiplyError)?;
-			let asset_details =
-				AssetDetails { collection_id, item_id, region, location, price: property_price, token_amount };
-			AssetIdDetails::<T>::insert(asset_number, asset_details);
-			next_item_id = next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			asset_number = asset_number.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			NextNftId::<T>::insert(collection_id, next_item_id);
-			NextAssetId::<T>::put(asset_number);
-			listing_id = Self::next_listing_id(listing_id)?;
-			NextListingId::<T>::put(listing_id);
-
-			Self::deposit_event(Event::<T>::ObjectListed {
-				collection_index: collection_id,
-				item_index: item_id,
-				price: token_price,
-				seller: signer,
-			});
+			if let Some(soft_cap) = soft_cap {
+				Self::set_soft_cap(listing_id, soft_cap)?;
+			}
 			Ok(())
 		}
 
@@ -764,114 +1545,24 @@ pub mod pallet {
 		/// Parameters:
 		/// - `listing_id`: The listing that the investor wants to buy token from.
 		/// - `amount`: The amount of token that the investor wants to buy.
+		/// - `max_total_price`: The maximum price including fees and tax that the investor pays.
 		///
 		/// Emits `TokenBoughtObject` event when succesfful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_token())]
-		pub fn buy_token(origin: OriginFor<T>, listing_id: ListingId, amount: u32) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
+		pub fn buy_token(
+			origin: OriginFor<T>,
+			listing_id: ListingId,
+			amount: u32,
+			max_total_price: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(
 				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
 				Error::<T>::UserNotWhitelisted
 			);
-
-			ListedToken::<T>::try_mutate_exists(listing_id, |maybe_listed_token| {
-				let listed_token = maybe_listed_token.as_mut().ok_or(Error::<T>::TokenNotForSale)?;
-				ensure!(*listed_token >= amount, Error::<T>::NotEnoughTokenAvailable);
-				let mut nft_details =
-					OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
-				ensure!(
-					!RegisteredNftDetails::<T>::get(nft_details.collection_id, nft_details.item_id)
-						.ok_or(Error::<T>::InvalidIndex)?
-						.spv_created,
-					Error::<T>::SpvAlreadyCreated
-				);
-
-				let transfer_price = nft_details
-					.token_price
-					.checked_mul(&Self::u64_to_balance_option(amount as u64)?)
-					.ok_or(Error::<T>::MultiplyError)?;
-
-				let fee = transfer_price
-					.checked_mul(&Self::u64_to_balance_option(1)?)
-					.ok_or(Error::<T>::MultiplyError)?
-					.checked_div(&Self::u64_to_balance_option(100)?) 
-					.ok_or(Error::<T>::DivisionError)?;
-				
-				let tax = transfer_price
-					.checked_mul(&Self::u64_to_balance_option(3)?)
-					.ok_or(Error::<T>::MultiplyError)?
-					.checked_div(&Self::u64_to_balance_option(100)?) 
-					.ok_or(Error::<T>::DivisionError)?;
-				
-				let total_transfer_price = transfer_price
-					.checked_add(&fee)
-					.ok_or(Error::<T>::ArithmeticOverflow)?
-					.checked_add(&tax)
-					.ok_or(Error::<T>::ArithmeticOverflow)?;
-
-				Self::transfer_funds(signer.clone(), Self::account_id(), total_transfer_price)?;
-				*listed_token =
-					listed_token.checked_sub(amount).ok_or(Error::<T>::ArithmeticUnderflow)?;
-				if !TokenBuyer::<T>::get(listing_id).contains(&signer) {
-					TokenBuyer::<T>::try_mutate(listing_id, |keys| {
-						keys.try_push(signer.clone()).map_err(|_| Error::<T>::TooManyTokenBuyer)?;
-						Ok::<(), DispatchError>(())
-					})?;
-				}
-				TokenOwner::<T>::try_mutate_exists(signer.clone(), listing_id, |maybe_token_owner_details| {
-					let token_owner_details = maybe_token_owner_details.get_or_insert( TokenOwnerDetails {
-						token_amount: 0,
-						paid_funds: Default::default(),
-						paid_tax: Default::default(),
-					});
-					token_owner_details.token_amount = token_owner_details.token_amount
-						.checked_add(amount)
-						.ok_or(Error::<T>::ArithmeticOverflow)?;
-					token_owner_details.paid_funds = token_owner_details.paid_funds
-						.checked_add(&transfer_price)
-						.ok_or(Error::<T>::ArithmeticOverflow)?;
-					token_owner_details.paid_tax = token_owner_details.paid_tax
-						.checked_add(&tax)
-						.ok_or(Error::<T>::ArithmeticOverflow)?;
-
-					Ok::<(), DispatchError>(())
-				})?;
-				nft_details.collected_funds = nft_details
-					.collected_funds
-					.checked_add(&transfer_price)
-					.ok_or(Error::<T>::ArithmeticOverflow)?;
-				nft_details.collected_tax = nft_details
-					.collected_tax
-					.checked_add(&tax)
-					.ok_or(Error::<T>::ArithmeticOverflow)?;
-				nft_details.collected_fees = nft_details
-					.collected_fees
-					.checked_add(&fee)
-					.ok_or(Error::<T>::ArithmeticOverflow)?;
-				OngoingObjectListing::<T>::insert(listing_id, nft_details.clone());
-				if *listed_token == 0 {
-					let property_lawyer_details = PropertyLawyerDetails {
-						real_estate_developer_lawyer: None,
-						spv_lawyer: None,
-						real_estate_developer_status: DocumentStatus::Pending,
-						spv_status: DocumentStatus::Pending,
-						real_estate_developer_lawyer_costs: Default::default(),
-						spv_lawyer_costs: Default::default(),
-						second_attempt: false,
-					};
-					PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
-					*maybe_listed_token = None;
-				} 
-				Self::deposit_event(Event::<T>::TokenBoughtObject {
-					asset_id: nft_details.asset_id,
-					buyer: signer.clone(),
-					amount,
-					price: transfer_price,
-				});
-				Ok::<(), DispatchError>(())
-			})?;
-			Ok(())
+			Self::do_buy_token(signer, listing_id, amount, max_total_price)
 		}
 
 		/// Relist token on the marketplace.
@@ -884,6 +1575,7 @@ pub mod pallet {
 		/// - `item_id`: The item id of the nft.
 		/// - `token_price`: The price of a single token.
 		/// - `amount`: The amount of token of the real estate object that should be listed.
+		/// - `payment_asset`: The asset that the token are paid with.
 		///
 		/// Emits `TokenListed` event when succesfful
 		#[pallet::call_index(4)]
@@ -894,47 +1586,20 @@ pub mod pallet {
 			item_id: <T as pallet::Config>::ItemId,
 			token_price: AssetBalanceOf<T>,
 			amount: u32,
+			payment_asset: u32,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin.clone())?;
-
-			ensure!(
-				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
-				Error::<T>::UserNotWhitelisted
-			);
-			let collection_id: CollectionId<T> =
-				RegionCollections::<T>::get(region).ok_or(Error::<T>::RegionUnknown)?;
-
-			let nft_details = RegisteredNftDetails::<T>::get(collection_id, item_id)
-				.ok_or(Error::<T>::NftNotFound)?;
-			ensure!(
-				LocationRegistration::<T>::get(region, nft_details.location),
-				Error::<T>::LocationUnknown
-			);
-			let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
-			let asset_id: AssetId<T> = nft_details.asset_id.into();
-			let token_amount = amount.into();
-			pallet_assets::Pallet::<T, Instance1>::transfer(
+			let (_, asset_id) = Self::do_relist_token(
 				origin,
-				asset_id.into().into(),
-				pallet_lookup,
-				token_amount,
-			)
-			.map_err(|_| Error::<T>::NotEnoughFunds)?;
-			let mut listing_id = NextListingId::<T>::get();
-			let token_listing = TokenListingDetails {
-				seller: signer.clone(),
-				token_price,
-				asset_id: nft_details.asset_id,
+				region,
 				item_id,
-				collection_id,
+				token_price,
 				amount,
-			};
-			TokenListings::<T>::insert(listing_id, token_listing);
-			listing_id = Self::next_listing_id(listing_id)?;
-			NextListingId::<T>::put(listing_id);
-
+				payment_asset,
+				None,
+			)?;
 			Self::deposit_event(Event::<T>::TokenListed {
-				asset_id: nft_details.asset_id,
+				asset_id,
 				price: token_price,
 				seller: signer,
 			});
@@ -948,6 +1613,7 @@ pub mod pallet {
 		/// Parameters:
 		/// - `listing_id`: The listing that the investor wants to buy from.
 		/// - `amount`: The amount of token the investor wants to buy.
+		/// - `max_total_price`: The maximum price including fees and tax that the investor pays.
 		///
 		/// Emits `TokenBought` event when succesfful.
 		#[pallet::call_index(5)]
@@ -956,28 +1622,15 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			listing_id: ListingId,
 			amount: u32,
+			max_total_price: AssetBalanceOf<T>,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&origin)?;
 			ensure!(
 				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(origin.clone()),
 				Error::<T>::UserNotWhitelisted
 			);
-			let listing_details =
-				TokenListings::<T>::take(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
-			ensure!(listing_details.amount >= amount, Error::<T>::NotEnoughTokenAvailable);
-			let price = listing_details
-				.token_price
-				.checked_mul(&Self::u64_to_balance_option(amount.into())?)
-				.ok_or(Error::<T>::MultiplyError)?;
-			Self::buying_token_process(
-				listing_id,
-				origin.clone(),
-				origin,
-				listing_details,
-				price,
-				amount,
-			)?;
-			Ok(())
+			Self::do_buy_relisted_token(origin, listing_id, amount, max_total_price)
 		}
 
 		/// Created an offer for a token listing.
@@ -988,6 +1641,7 @@ pub mod pallet {
 		/// - `listing_id`: The listing that the investor wants to buy from.
 		/// - `offer_price`: The offer price for token that are offered.
 		/// - `amount`: The amount of token that the investor wants to buy.
+		/// - `max_total_price`: The maximum price including fees that the investor escrows.
 		///
 		/// Emits `OfferCreated` event when succesfful.
 		#[pallet::call_index(6)]
@@ -997,8 +1651,10 @@ pub mod pallet {
 			listing_id: ListingId,
 			offer_price: AssetBalanceOf<T>,
 			amount: u32,
+			max_total_price: Option<AssetBalanceOf<T>>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(
 				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
 				Error::<T>::UserNotWhitelisted
@@ -1010,8 +1666,36 @@ pub mod pallet {
 			let price = offer_price
 				.checked_mul(&Self::u64_to_balance_option(amount.into())?)
 				.ok_or(Error::<T>::MultiplyError)?;
-			Self::transfer_funds(signer.clone(), Self::account_id(), price)?;
-			let offer_details = OfferDetails { buyer: signer.clone(), token_price: offer_price, amount };
+			let fees = Self::calculate_buyer_fees(price, &FeeSchedule::<T>::get().secondary)?;
+			if let Some(max_total_price) = max_total_price {
+				Self::ensure_price_limit(
+					price.checked_add(&fees).ok_or(Error::<T>::ArithmeticOverflow)?,
+					max_total_price,
+				)?;
+			}
+			let expiry = <frame_system::Pallet<T>>::block_number()
+				.checked_add(&T::OfferDuration::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let expiry = Self::push_to_free_block::<OffersExpiring<T>, _, _>(
+				expiry,
+				(listing_id, signer.clone()),
+				Error::<T>::TooManyOffers,
+			)?;
+			let offer_details = OfferDetails {
+				buyer: signer.clone(),
+				token_price: offer_price,
+				amount,
+				fees,
+				payment_asset: listing_details.payment_asset,
+				expiry,
+				state: OfferState::Pending,
+			};
+			Self::transfer_funds(
+				signer.clone(),
+				Self::account_id(),
+				offer_details.get_escrowed_amount()?,
+				offer_details.payment_asset,
+			)?;
 			OngoingOffers::<T>::insert(listing_id, signer, offer_details);
 			Self::deposit_event(Event::<T>::OfferCreated { listing_id, price: offer_price });
 			Ok(())
@@ -1024,16 +1708,17 @@ pub mod pallet {
 		/// Parameters:
 		/// - `listing_id`: The listing that the investor wants to buy from.
 		/// - `offeror`: AccountId of the person that the seller wants to handle the offer from.
-		/// - `offer`: Enum for offer which is either Accept or Reject.
+		/// - `offer`: Enum for offer which is either Accept, Reject, Counter or PartialAccept.
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::handle_offer())]
 		pub fn handle_offer(
 			origin: OriginFor<T>,
 			listing_id: ListingId,
 			offeror: AccountIdOf<T>,
-			offer: Offer,
+			offer: Offer<AssetBalanceOf<T>>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(
 				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
 				Error::<T>::UserNotWhitelisted
@@ -1041,30 +1726,72 @@ pub mod pallet {
 			let listing_details =
 				TokenListings::<T>::get(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
 			ensure!(listing_details.seller == signer, Error::<T>::NoPermission);
-			let offer_details =
-				OngoingOffers::<T>::take(listing_id, offeror).ok_or(Error::<T>::InvalidIndex)?;
-			ensure!(listing_details.amount >= offer_details.amount, Error::<T>::NotEnoughTokenAvailable);
-			let price = offer_details.get_total_amount()?;
+			let mut offer_details =
+				OngoingOffers::<T>::take(listing_id, offeror.clone()).ok_or(Error::<T>::InvalidIndex)?;
 			let pallet_account = Self::account_id();
 			match offer {
 				Offer::Accept => {
+					ensure!(listing_details.amount >= offer_details.amount, Error::<T>::NotEnoughTokenAvailable);
+					let price = offer_details.get_total_amount()?;
 					Self::buying_token_process(
 						listing_id,
 						pallet_account,
 						offer_details.buyer,
 						listing_details,
+						offer_details.token_price,
 						price,
+						offer_details.fees,
 						offer_details.amount,
 					)?;
 				}
 				Offer::Reject => {
-					Self::transfer_funds(pallet_account, offer_details.buyer, price)?;
+					Self::refund_offer(offer_details)?;
+				}
+				Offer::Counter(token_price) => {
+					offer_details.state = OfferState::Countered { token_price };
+					OngoingOffers::<T>::insert(listing_id, offeror.clone(), offer_details);
+					Self::deposit_event(Event::<T>::OfferCountered { listing_id, offeror, token_price });
+				}
+				Offer::PartialAccept(amount) => {
+					ensure!(
+						amount > 0 && amount < offer_details.amount,
+						Error::<T>::InvalidPartialAmount
+					);
+					ensure!(listing_details.amount >= amount, Error::<T>::NotEnoughTokenAvailable);
+					let price = offer_details
+						.token_price
+						.checked_mul(&Self::u64_to_balance_option(amount.into())?)
+						.ok_or(Error::<T>::MultiplyError)?;
+					let fees = offer_details
+						.fees
+						.checked_mul(&Self::u64_to_balance_option(amount.into())?)
+						.ok_or(Error::<T>::MultiplyError)?
+						.checked_div(&Self::u64_to_balance_option(offer_details.amount.into())?)
+						.ok_or(Error::<T>::DivisionError)?;
+					let refund_amount = offer_details
+						.get_escrowed_amount()?
+						.checked_sub(&price)
+						.ok_or(Error::<T>::ArithmeticUnderflow)?
+						.checked_sub(&fees)
+						.ok_or(Error::<T>::ArithmeticUnderflow)?;
+					Self::buying_token_process(
+						listing_id,
+						pallet_account,
+						offer_details.buyer.clone(),
+						listing_details,
+						offer_details.token_price,
+						price,
+						fees,
+						amount,
+					)?;
+					Self::pay_out(offer_details.buyer, refund_amount, offer_details.payment_asset)?;
 				}
 			}
 			Ok(())
 		}
 
-		/// Lets the investor cancel an offer.
+		/// Lets the investor cancel an offer or claim the refund of an offer that could not be
+		/// refunded automatically.
 		///
 		/// The origin must be Signed and the sender must have sufficient funds free.
 		///
@@ -1079,11 +1806,12 @@ pub mod pallet {
 			listing_id: ListingId,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			let offer_details =
-				OngoingOffers::<T>::take(listing_id, signer.clone()).ok_or(Error::<T>::InvalidIndex)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			let offer_details = OngoingOffers::<T>::take(listing_id, signer.clone())
+				.or_else(|| FailedOfferRefunds::<T>::take(listing_id, signer.clone()))
+				.ok_or(Error::<T>::InvalidIndex)?;
 			ensure!(offer_details.buyer == signer.clone(), Error::<T>::NoPermission);
-			let price = offer_details.get_total_amount()?;
-			Self::transfer_funds(Self::account_id(), offer_details.buyer, price)?;
+			Self::refund_offer(offer_details)?;
 			Self::deposit_event(Event::<T>::OfferCancelled { listing_id, account_id: signer.clone() });
 			Ok(())
 		}
@@ -1105,6 +1833,7 @@ pub mod pallet {
 			new_price: AssetBalanceOf<T>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(
 				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
 				Error::<T>::UserNotWhitelisted
@@ -1112,6 +1841,7 @@ pub mod pallet {
 			let _ = TokenListings::<T>::try_mutate(listing_id, |maybe_listing_details| {
 				let listing_details = maybe_listing_details.as_mut().ok_or(Error::<T>::TokenNotForSale)?;
 				ensure!(listing_details.seller == signer, Error::<T>::NoPermission);
+				ensure!(listing_details.dutch_auction.is_none(), Error::<T>::PriceSetByAuction);
 				listing_details.token_price = new_price;
 				Ok::<(), DispatchError>(())
 			})?;
@@ -1139,11 +1869,13 @@ pub mod pallet {
 			new_price: AssetBalanceOf<T>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(
 				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
 				Error::<T>::UserNotWhitelisted
 			);
 			ensure!(ListedToken::<T>::contains_key(listing_id), Error::<T>::TokenNotForSale);
+			ensure!(!ObjectAuctions::<T>::contains_key(listing_id), Error::<T>::ListingInAuction);
 			let _ = OngoingObjectListing::<T>::try_mutate(listing_id, |maybe_nft_details| {
 				let nft_details = maybe_nft_details.as_mut().ok_or(Error::<T>::InvalidIndex)?;
 				ensure!(nft_details.real_estate_developer == signer.clone(), Error::<T>::NoPermission);
@@ -1173,46 +1905,30 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::delist_token())]
 		pub fn delist_token(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			ensure!(
-				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
-				Error::<T>::UserNotWhitelisted
-			);
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			let listing_details =
 				TokenListings::<T>::take(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
 			ensure!(listing_details.seller == signer, Error::<T>::NoPermission);
-			let user_lookup = <T::Lookup as StaticLookup>::unlookup(signer.clone());
-			let asset_id: AssetId<T> = listing_details.asset_id.into();
-			let token_amount = listing_details.amount.into();
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_assets::Pallet::<T, Instance1>::transfer(
-				pallet_origin,
-				asset_id.into().into(),
-				user_lookup,
-				token_amount,
-			)
-			.map_err(|_| Error::<T>::NotEnoughFunds)?;
-			Self::deposit_event(Event::<T>::ListingDelisted { listing_index: listing_id });
-			Ok(())
+			Self::return_listed_token(listing_id, listing_details)
 		}
 
-		/// Registers a new lawyer.
+		/// Approves the application of a lawyer and registers the lawyer.
 		///
 		/// The origin must be the LocationOrigin.
 		///
 		/// Parameters:
-		/// - `lawyer`: The lawyer that should be registered.
+		/// - `lawyer`: The lawyer that applied with `apply_as_lawyer`.
 		///
 		/// Emits `LawyerRegistered` event when succesfful.
 		#[pallet::call_index(12)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
-		pub fn register_lawyer(
-			origin: OriginFor<T>,
-			lawyer: AccountIdOf<T>,
-		) -> DispatchResult {
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn register_lawyer(origin: OriginFor<T>, lawyer: AccountIdOf<T>) -> DispatchResult {
 			T::LocationOrigin::ensure_origin(origin)?;
-			ensure!(!RealEstateLawyer::<T>::get(lawyer.clone()), Error::<T>::LawyerAlreadyRegistered);
-			RealEstateLawyer::<T>::insert(lawyer.clone(), true);
-			Self::deposit_event(Event::<T>::LawyerRegistered {lawyer});
+			let lawyer_details = LawyerApplications::<T>::take(lawyer.clone())
+				.ok_or(Error::<T>::LawyerApplicationNotFound)?;
+			let regions = lawyer_details.regions.clone();
+			RealEstateLawyer::<T>::insert(lawyer.clone(), lawyer_details);
+			Self::deposit_event(Event::<T>::LawyerRegistered { lawyer, regions });
 			Ok(())
 		}
 
@@ -1227,7 +1943,7 @@ pub mod pallet {
 		///
 		/// Emits `LawyerClaimedProperty` event when succesfful.
 		#[pallet::call_index(13)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::weight(T::DbWeight::get().reads_writes(5, 3))]
 		pub fn lawyer_claim_property(
 			origin: OriginFor<T>,
 			listing_id: ListingId,
@@ -1235,10 +1951,18 @@ pub mod pallet {
 			costs: AssetBalanceOf<T>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			ensure!(RealEstateLawyer::<T>::get(signer.clone()), Error::<T>::NoPermission);
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			let mut lawyer_details =
+				RealEstateLawyer::<T>::get(signer.clone()).ok_or(Error::<T>::NoPermission)?;
+			ensure!(lawyer_details.unbonding_until.is_none(), Error::<T>::LawyerUnbonding);
+			ensure!(lawyer_details.bond >= T::LawyerBond::get(), Error::<T>::LawyerBondTooLow);
 			let mut property_lawyer_details = PropertyLawyer::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
 			let nft_details =
 				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			let region = RegisteredNftDetails::<T>::get(nft_details.collection_id, nft_details.item_id)
+				.ok_or(Error::<T>::InvalidIndex)?
+				.region;
+			ensure!(lawyer_details.regions.contains(&region), Error::<T>::RegionNotLicensed);
 			ensure!(nft_details.collected_fees >= costs, Error::<T>::CostsTooHigh);
 
 			match legal_side {
@@ -1247,6 +1971,8 @@ pub mod pallet {
 					ensure!(property_lawyer_details.spv_lawyer != Some(signer.clone()), Error::<T>::NoPermission);
 					property_lawyer_details.real_estate_developer_lawyer = Some(signer.clone());
 					property_lawyer_details.real_estate_developer_lawyer_costs = costs;
+					property_lawyer_details.real_estate_developer_deadline =
+						Some(Self::schedule_review(listing_id, legal_side.clone())?);
 					PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
 				}
 				LegalProperty::SpvSide => {
@@ -1254,9 +1980,14 @@ pub mod pallet {
 					ensure!(property_lawyer_details.real_estate_developer_lawyer != Some(signer.clone()), Error::<T>::NoPermission);
 					property_lawyer_details.spv_lawyer = Some(signer.clone());
 					property_lawyer_details.spv_lawyer_costs = costs;
+					property_lawyer_details.spv_deadline =
+						Some(Self::schedule_review(listing_id, legal_side.clone())?);
 					PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
 				}
 			}
+			lawyer_details.active_cases =
+				lawyer_details.active_cases.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			RealEstateLawyer::<T>::insert(signer.clone(), lawyer_details);
 			Self::deposit_event(Event::<T>::LawyerClaimedProperty {lawyer: signer, listing_id, legal_side});
 			Ok(())
 		}
@@ -1270,26 +2001,30 @@ pub mod pallet {
 		///
 		/// Emits `LawyerRemovedFromCase` event when succesfful.
 		#[pallet::call_index(14)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
 		pub fn remove_from_case(
 			origin: OriginFor<T>,
 			listing_id: ListingId,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			ensure!(RealEstateLawyer::<T>::get(signer.clone()), Error::<T>::NoPermission);
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(RealEstateLawyer::<T>::contains_key(signer.clone()), Error::<T>::NoPermission);
 			let mut property_lawyer_details = PropertyLawyer::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
 			if property_lawyer_details.real_estate_developer_lawyer == Some(signer.clone()) {
 				ensure!(property_lawyer_details.real_estate_developer_status == DocumentStatus::Pending,
 					Error::<T>::AlreadyConfirmed);
 				property_lawyer_details.real_estate_developer_lawyer = None;
+				property_lawyer_details.real_estate_developer_deadline = None;
 			} else if property_lawyer_details.spv_lawyer == Some(signer.clone()) {
 				ensure!(property_lawyer_details.spv_status == DocumentStatus::Pending,
 					Error::<T>::AlreadyConfirmed);
 				property_lawyer_details.spv_lawyer = None;
+				property_lawyer_details.spv_deadline = None;
 			} else {
 				return Err(Error::<T>::NoPermission.into());
 			}
 			PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);	
+			Self::release_lawyer(&signer);
 			Self::deposit_event(Event::<T>::LawyerRemovedFromCase {lawyer: signer, listing_id});	
 			Ok(())
 		}
@@ -1301,19 +2036,29 @@ pub mod pallet {
 		/// Parameters:
 		/// - `listing_id`: The listing from the property.
 		/// - `approve`: Approves or Rejects the case.
+		/// - `document_hashes`: The hashes of the documents that the lawyer reviewed.
+		/// - `reason`: The reason for the rejection. Must only be set if the case is rejected.
 		///
 		/// Emits `DocumentsConfirmed` event when succesfful.
 		#[pallet::call_index(15)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::lawyer_confirm_documents(
+			T::MaxNftToken::get(),
+		))]
 		pub fn lawyer_confirm_documents(
 			origin: OriginFor<T>,
 			listing_id: ListingId,
 			approve: bool,
+			document_hashes: BoundedVec<T::Hash, T::MaxDocumentHashes>,
+			reason: Option<RejectionReason>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(!document_hashes.is_empty(), Error::<T>::NoDocumentHashes);
+			ensure!(approve == reason.is_none(), Error::<T>::InvalidRejectionReason);
 
 			let mut property_lawyer_details = PropertyLawyer::<T>::take(listing_id).ok_or(Error::<T>::InvalidIndex)?;
-			if property_lawyer_details.real_estate_developer_lawyer == Some(signer.clone()) {
+			let round = property_lawyer_details.review_round;
+			let legal_side = if property_lawyer_details.real_estate_developer_lawyer == Some(signer.clone()) {
 				ensure!(property_lawyer_details.real_estate_developer_status == DocumentStatus::Pending,
 					Error::<T>::AlreadyConfirmed);
 				property_lawyer_details.real_estate_developer_status = if approve {
@@ -1321,7 +2066,7 @@ pub mod pallet {
 				} else {
 					DocumentStatus::Rejected
 				};
-				Self::deposit_event(Event::<T>::DocumentsConfirmed { signer, listing_id, approve });
+				LegalProperty::RealEstateDeveloperSide
 			} else if property_lawyer_details.spv_lawyer == Some(signer.clone()) {
 				ensure!(property_lawyer_details.spv_status == DocumentStatus::Pending,
 					Error::<T>::AlreadyConfirmed);
@@ -1330,10 +2075,19 @@ pub mod pallet {
 				} else {
 					DocumentStatus::Rejected
 				};
-				Self::deposit_event(Event::<T>::DocumentsConfirmed {signer, listing_id, approve});
+				LegalProperty::SpvSide
 			} else {
 				return Err(Error::<T>::NoPermission.into());
-			}
+			};
+			let document_review = DocumentReview {
+				lawyer: signer.clone(),
+				document_hashes,
+				approved: approve,
+				reason,
+				reviewed_at: <frame_system::Pallet<T>>::block_number(),
+			};
+			LegalDocuments::<T>::insert((listing_id, legal_side, round), document_review);
+			Self::deposit_event(Event::<T>::DocumentsConfirmed { signer, listing_id, approve });
 
 			let developer_status = property_lawyer_details.real_estate_developer_status.clone();
 			let spv_status = property_lawyer_details.spv_status.clone();
@@ -1354,76 +2108,1875 @@ pub mod pallet {
 						property_lawyer_details.spv_status = DocumentStatus::Pending;
 						property_lawyer_details.real_estate_developer_status = DocumentStatus::Pending;
 						property_lawyer_details.second_attempt = true;
+						Self::schedule_second_review(listing_id, &mut property_lawyer_details)?;
+						PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
+					} else {
+						Self::start_arbitration(listing_id, property_lawyer_details)?;
+					}
+				}
+				(DocumentStatus::Rejected, DocumentStatus::Approved) => {
+					if !property_lawyer_details.second_attempt {
+						property_lawyer_details.spv_status = DocumentStatus::Pending;
+						property_lawyer_details.real_estate_developer_status = DocumentStatus::Pending;
+						property_lawyer_details.second_attempt = true;
+						Self::schedule_second_review(listing_id, &mut property_lawyer_details)?;
 						PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
 					} else {
-						Self::burn_tokens_and_nfts(listing_id)?;
-						Self::refund_investors(listing_id, property_lawyer_details)?;
+						Self::start_arbitration(listing_id, property_lawyer_details)?;
+					}
+				}
+				_ => {
+					PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
+				}
+			}
+			Ok(())
+		}
+
+		/// Updates the fees and tax of a sale type.
+		///
+		/// The origin must be the FeeScheduleOrigin.
+		///
+		/// Parameters:
+		/// - `sale_type`: The sale type that the fees apply to.
+		/// - `fee_details`: The new fees and tax for the sale type.
+		///
+		/// Emits `FeeScheduleUpdated` event when succesfful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_fee_schedule(
+			origin: OriginFor<T>,
+			sale_type: SaleType,
+			fee_details: FeeDetails,
+		) -> DispatchResult {
+			T::FeeScheduleOrigin::ensure_origin(origin)?;
+			FeeSchedule::<T>::mutate(|fee_schedule| match sale_type {
+				SaleType::Primary => fee_schedule.primary = fee_details.clone(),
+				SaleType::Secondary => fee_schedule.secondary = fee_details.clone(),
+			});
+			Self::deposit_event(Event::<T>::FeeScheduleUpdated { sale_type, fee_details });
+			Ok(())
+		}
+
+		/// Adds an asset to the assets that are accepted as payment.
+		///
+		/// The origin must be the PaymentAssetOrigin.
+		///
+		/// Parameters:
+		/// - `asset_id`: The asset that should be accepted as payment.
+		///
+		/// Emits `PaymentAssetAdded` event when succesfful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 1))]
+		pub fn add_payment_asset(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
+			T::PaymentAssetOrigin::ensure_origin(origin)?;
+			ensure!(!PaymentAssets::<T>::get(asset_id), Error::<T>::PaymentAssetAlreadySupported);
+			ensure!(!AssetIdDetails::<T>::contains_key(asset_id), Error::<T>::PropertyTokenNotAllowed);
+			let asset: AssetId<T> = asset_id.into();
+			ensure!(
+				pallet_assets::Pallet::<T, Instance1>::maybe_total_supply(asset.into()).is_some(),
+				Error::<T>::AssetNotFound
+			);
+			PaymentAssets::<T>::insert(asset_id, true);
+			Self::deposit_event(Event::<T>::PaymentAssetAdded { asset_id });
+			Ok(())
+		}
+
+		/// Removes an asset from the assets that are accepted as payment.
+		/// Existing listings keep settling in their payment asset.
+		///
+		/// The origin must be the PaymentAssetOrigin.
+		///
+		/// Parameters:
+		/// - `asset_id`: The asset that should no longer be accepted as payment.
+		///
+		/// Emits `PaymentAssetRemoved` event when succesfful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn remove_payment_asset(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
+			T::PaymentAssetOrigin::ensure_origin(origin)?;
+			ensure!(PaymentAssets::<T>::get(asset_id), Error::<T>::PaymentAssetNotSupported);
+			PaymentAssets::<T>::remove(asset_id);
+			Self::deposit_event(Event::<T>::PaymentAssetRemoved { asset_id });
+			Ok(())
+		}
+
+		/// Closes a listed object that did not sell out before its expiry.
+		/// The nft gets burned and the investors get their funds and tax refunded.
+		///
+		/// The origin must be Signed.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing that has expired.
+		///
+		/// Emits `ListingExpired` event when succesfful.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(8, 8).saturating_add(
+			T::DbWeight::get().reads_writes(2, 2).saturating_mul(T::MaxNftToken::get().into())
+		))]
+		pub fn expire_listing(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(ListedToken::<T>::contains_key(listing_id), Error::<T>::TokenNotForSale);
+			ensure!(!ObjectAuctions::<T>::contains_key(listing_id), Error::<T>::ListingInAuction);
+			let nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(
+				nft_details.listing_expiry < <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ListingNotExpired
+			);
+			ListedToken::<T>::remove(listing_id);
+			Self::burn_tokens_and_nfts(listing_id)?;
+			Self::refund_unsold_listing(listing_id, false)?;
+			Self::deposit_event(Event::<T>::ListingExpired { listing_id });
+			Ok(())
+		}
+
+		/// Cancels a listed object that has not been sold out.
+		/// The nft gets burned and the investors get their funds, fees and tax refunded.
+		///
+		/// The origin must be the LocationOrigin or Signed by the real estate developer.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing that should be cancelled.
+		///
+		/// Emits `ObjectListingCancelled` event when succesfful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(8, 8).saturating_add(
+			T::DbWeight::get().reads_writes(2, 2).saturating_mul(T::MaxNftToken::get().into())
+		))]
+		pub fn cancel_object_listing(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResult {
+			let maybe_signer = match T::LocationOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			ensure!(ListedToken::<T>::contains_key(listing_id), Error::<T>::TokenNotForSale);
+			ensure!(!ObjectAuctions::<T>::contains_key(listing_id), Error::<T>::ListingInAuction);
+			let nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			if let Some(signer) = maybe_signer {
+				pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+				ensure!(nft_details.real_estate_developer == signer, Error::<T>::NoPermission);
+			}
+			ListedToken::<T>::remove(listing_id);
+			Self::burn_tokens_and_nfts(listing_id)?;
+			Self::refund_unsold_listing(listing_id, true)?;
+			Self::deposit_event(Event::<T>::ObjectListingCancelled { listing_id });
+			Ok(())
+		}
+
+		/// Lets the offeror accept the counter offer of the seller.
+		/// The escrowed funds get refunded and the token are bought at the countered price.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing that the investor wants to buy from.
+		/// - `max_total_price`: The maximum price including fees and tax that the investor pays.
+		///
+		/// Emits `TokenBought` event when succesfful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::handle_offer())]
+		pub fn accept_counter_offer(
+			origin: OriginFor<T>,
+			listing_id: ListingId,
+			max_total_price: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
+			let offer_details =
+				OngoingOffers::<T>::take(listing_id, signer.clone()).ok_or(Error::<T>::InvalidIndex)?;
+			let token_price = match offer_details.state {
+				OfferState::Countered { token_price } => token_price,
+				OfferState::Pending => return Err(Error::<T>::NoCounterOffer.into()),
+			};
+			let listing_details =
+				TokenListings::<T>::get(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
+			let amount = offer_details.amount;
+			ensure!(listing_details.amount >= amount, Error::<T>::NotEnoughTokenAvailable);
+			let price = token_price
+				.checked_mul(&Self::u64_to_balance_option(amount.into())?)
+				.ok_or(Error::<T>::MultiplyError)?;
+			let fees = Self::calculate_buyer_fees(price, &FeeSchedule::<T>::get().secondary)?;
+			Self::ensure_price_limit(
+				price.checked_add(&fees).ok_or(Error::<T>::ArithmeticOverflow)?,
+				max_total_price,
+			)?;
+			Self::refund_offer(offer_details)?;
+			Self::buying_token_process(
+				listing_id,
+				signer.clone(),
+				signer,
+				listing_details,
+				token_price,
+				price,
+				fees,
+				amount,
+			)?;
+			Ok(())
+		}
+
+		/// Relist token on the marketplace in a dutch auction.
+		/// The price per token falls linearly from the start price to the floor price.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `region`: The region where the object is located.
+		/// - `item_id`: The item id of the nft.
+		/// - `start_price`: The price of a single token at the start of the auction.
+		/// - `floor_price`: The lowest price of a single token.
+		/// - `duration`: The amount of blocks until the floor price is reached.
+		/// - `amount`: The amount of token of the real estate object that should be listed.
+		/// - `payment_asset`: The asset that the token are paid with.
+		///
+		/// Emits `DutchAuctionListed` event when succesfful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::relist_token())]
+		#[allow(clippy::too_many_arguments)]
+		pub fn relist_token_dutch_auction(
+			origin: OriginFor<T>,
+			region: RegionId,
+			item_id: <T as pallet::Config>::ItemId,
+			start_price: AssetBalanceOf<T>,
+			floor_price: AssetBalanceOf<T>,
+			duration: BlockNumberFor<T>,
+			amount: u32,
+			payment_asset: u32,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin.clone())?;
+			ensure!(
+				floor_price < start_price && duration > Zero::zero(),
+				Error::<T>::InvalidAuctionParameters
+			);
+			let start_block = <frame_system::Pallet<T>>::block_number();
+			let end_block =
+				start_block.checked_add(&duration).ok_or(Error::<T>::ArithmeticOverflow)?;
+			let dutch_auction = DutchAuctionDetails { start_price, floor_price, start_block, end_block };
+			let (listing_id, asset_id) = Self::do_relist_token(
+				origin,
+				region,
+				item_id,
+				start_price,
+				amount,
+				payment_asset,
+				Some(dutch_auction),
+			)?;
+			Self::deposit_event(Event::<T>::DutchAuctionListed {
+				listing_id,
+				asset_id,
+				start_price,
+				floor_price,
+				end_block,
+				seller: signer,
+			});
+			Ok(())
+		}
+
+		/// Buy token from several listings at once. Either all purchases succeed or none.
+		/// Listed objects and relisted token can be bought in the same batch.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `purchases`: The listing, the amount of token and the maximum total price including
+		/// fees and tax of every purchase.
+		///
+		/// Emits `TokenBoughtObject` or `TokenBought` event for every purchase when succesfful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_token()
+			.max(<T as pallet::Config>::WeightInfo::buy_relisted_token())
+			.saturating_mul(purchases.len() as u64))]
+		pub fn buy_batch(
+			origin: OriginFor<T>,
+			purchases: BoundedVec<(ListingId, u32, AssetBalanceOf<T>), T::MaxBatchPurchases>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
+			Self::do_buy_batch(signer, purchases)
+		}
+
+		/// List a real estate object in an auction. A new nft gets minted.
+		/// After the auction the token are allocated to the highest bids, the remaining token
+		/// stay listed at the reserve price.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `region`: The region where the object is located.
+		/// - `location`: The location where the object is located.
+		/// - `reserve_price`: The lowest price of a single token that can be bid.
+		/// - `token_amount`: The amount of tokens for a object.
+		/// - `data`: The Metadata of the nft.
+		/// - `payment_asset`: The asset that the token are paid with.
+		///
+		/// Emits `AuctionStarted` event when succesfful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::list_object()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 3)))]
+		pub fn list_object_for_auction(
+			origin: OriginFor<T>,
+			region: RegionId,
+			location: LocationId<T>,
+			reserve_price: AssetBalanceOf<T>,
+			token_amount: u32,
+			data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
+			payment_asset: u32,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin.clone())?;
+			let listing_id = Self::do_list_object(
+				origin,
+				region,
+				location,
+				reserve_price,
+				token_amount,
+				data,
+				payment_asset,
+			)?;
+			let end_block = <frame_system::Pallet<T>>::block_number()
+				.checked_add(&T::AuctionDuration::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let listing_expiry = end_block
+				.checked_add(&T::ListingDuration::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			OngoingObjectListing::<T>::try_mutate(listing_id, |maybe_nft_details| {
+				let nft_details = maybe_nft_details.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+				nft_details.listing_expiry = listing_expiry;
+				Ok::<(), DispatchError>(())
+			})?;
+			AuctionsEnding::<T>::try_mutate(end_block, |keys| {
+				keys.try_push(listing_id).map_err(|_| Error::<T>::TooManyAuctions)?;
+				Ok::<(), DispatchError>(())
+			})?;
+			ObjectAuctions::<T>::insert(listing_id, end_block);
+			Self::deposit_event(Event::<T>::AuctionStarted {
+				listing_id,
+				reserve_price,
+				end_block,
+				seller: signer,
+			});
+			Ok(())
+		}
+
+		/// Place a bid in the auction of a listed object.
+		/// The price of the bid plus the fees and tax are held in escrow until the auction ends.
+		/// A bidder that already placed a bid raises it and only pays the difference of the
+		/// escrow. If the auction has the maximum amount of bids, the lowest bid gets refunded
+		/// and removed.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing that the investor wants to bid on.
+		/// - `token_price`: The price per token that the investor bids.
+		/// - `amount`: The amount of token that the investor wants to buy.
+		/// - `max_total_price`: The maximum price including fees and tax that the investor escrows.
+		///
+		/// Emits `BidPlaced` event when succesfful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(11, 5))]
+		pub fn place_bid(
+			origin: OriginFor<T>,
+			listing_id: ListingId,
+			token_price: AssetBalanceOf<T>,
+			amount: u32,
+			max_total_price: Option<AssetBalanceOf<T>>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
+			let end_block = ObjectAuctions::<T>::get(listing_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < end_block,
+				Error::<T>::AuctionEnded
+			);
+			let nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(token_price >= nft_details.token_price, Error::<T>::BidTooLow);
+			ensure!(
+				amount > 0 && amount <= nft_details.token_amount,
+				Error::<T>::InvalidBidAmount
+			);
+			Self::ensure_holding_limits(nft_details.asset_id, amount)?;
+			let price = token_price
+				.checked_mul(&Self::u64_to_balance_option(amount as u64)?)
+				.ok_or(Error::<T>::MultiplyError)?;
+			Self::ensure_investment_allowed(signer.clone(), price, nft_details.payment_asset)?;
+			let fee_details = FeeSchedule::<T>::get().primary;
+			let fee = fee_details.buyer_fee.mul_floor(price);
+			let tax = fee_details.tax.mul_floor(price);
+			let bid = BidDetails { bidder: signer.clone(), token_price, amount, fee, tax };
+			if let Some(max_total_price) = max_total_price {
+				Self::ensure_price_limit(Self::bid_escrowed_amount(&bid)?, max_total_price)?;
+			}
+			let (previous_escrow, outbid) = AuctionBids::<T>::try_mutate(listing_id, |bids| {
+				let mut previous_escrow: AssetBalanceOf<T> = Zero::zero();
+				let mut outbid = None;
+				if let Some(index) = bids.iter().position(|bid| bid.bidder == signer) {
+					let previous_bid = bids.remove(index);
+					ensure!(
+						token_price >= previous_bid.token_price
+							&& amount >= previous_bid.amount
+							&& (token_price > previous_bid.token_price
+								|| amount > previous_bid.amount),
+						Error::<T>::BidNotRaised
+					);
+					previous_escrow = Self::bid_escrowed_amount(&previous_bid)?;
+				} else if bids.is_full() {
+					// the lowest bid that has been placed last gets outbid.
+					let (index, lowest_bid) = bids
+						.iter()
+						.enumerate()
+						.rev()
+						.min_by_key(|(_, bid)| bid.token_price)
+						.ok_or(Error::<T>::TooManyBids)?;
+					ensure!(token_price > lowest_bid.token_price, Error::<T>::TooManyBids);
+					outbid = Some(bids.remove(index));
+				}
+				bids.try_push(bid.clone()).map_err(|_| Error::<T>::TooManyBids)?;
+				Ok::<_, DispatchError>((previous_escrow, outbid))
+			})?;
+			Self::transfer_funds(
+				signer.clone(),
+				Self::account_id(),
+				Self::bid_escrowed_amount(&bid)?
+					.checked_sub(&previous_escrow)
+					.ok_or(Error::<T>::ArithmeticUnderflow)?,
+				nft_details.payment_asset,
+			)?;
+			if let Some(outbid) = outbid {
+				let escrowed_amount = Self::bid_escrowed_amount(&outbid)?;
+				if Self::try_refund_bid(
+					listing_id,
+					outbid.bidder.clone(),
+					escrowed_amount,
+					nft_details.payment_asset,
+				) {
+					Self::deposit_event(Event::<T>::BidOutbid { listing_id, bidder: outbid.bidder });
+				}
+			}
+			Self::deposit_event(Event::<T>::BidPlaced {
+				listing_id,
+				bidder: signer,
+				token_price,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Lets a lawyer leave the registry. The lawyer can not claim new cases and the bond
+		/// can be withdrawn after the unbonding period.
+		///
+		/// The origin must be Signed and the sender must be a registered lawyer.
+		///
+		/// Emits `LawyerUnbonding` event when succesfful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn unregister_lawyer(origin: OriginFor<T>) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			let mut lawyer_details =
+				RealEstateLawyer::<T>::get(signer.clone()).ok_or(Error::<T>::NoPermission)?;
+			ensure!(lawyer_details.unbonding_until.is_none(), Error::<T>::LawyerUnbonding);
+			let unbonding_until = <frame_system::Pallet<T>>::block_number()
+				.checked_add(&T::LawyerUnbondingPeriod::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			lawyer_details.unbonding_until = Some(unbonding_until);
+			RealEstateLawyer::<T>::insert(signer.clone(), lawyer_details);
+			Self::deposit_event(Event::<T>::LawyerUnbonding { lawyer: signer, unbonding_until });
+			Ok(())
+		}
+
+		/// Withdraws the bond of a deregistered lawyer and removes the lawyer from the registry.
+		///
+		/// The origin must be Signed and the sender must be a registered lawyer.
+		/// The unbonding period must be over and the lawyer can't have any active cases.
+		///
+		/// Emits `LawyerUnregistered` event when succesfful.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn withdraw_lawyer_bond(origin: OriginFor<T>) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			let lawyer_details =
+				RealEstateLawyer::<T>::get(signer.clone()).ok_or(Error::<T>::NoPermission)?;
+			let unbonding_until =
+				lawyer_details.unbonding_until.ok_or(Error::<T>::LawyerNotUnbonding)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= unbonding_until,
+				Error::<T>::UnbondingPeriodNotOver
+			);
+			ensure!(lawyer_details.active_cases == 0, Error::<T>::LawyerHasActiveCases);
+			<T as pallet::Config>::Currency::unreserve(&signer, lawyer_details.bond);
+			RealEstateLawyer::<T>::remove(signer.clone());
+			Self::deposit_event(Event::<T>::LawyerUnregistered { lawyer: signer });
+			Ok(())
+		}
+
+		/// Slashes the bond of a lawyer. The slashed amount is sent to the treasury.
+		/// A lawyer whose bond is below `LawyerBond` can't claim new cases until the bond has been
+		/// topped up with `top_up_lawyer_bond`.
+		///
+		/// The origin must be the LocationOrigin.
+		///
+		/// Parameters:
+		/// - `lawyer`: The lawyer that gets slashed.
+		/// - `amount`: The amount that should be slashed from the bond.
+		///
+		/// Emits `LawyerSlashed` event when succesfful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 3))]
+		pub fn slash_lawyer(
+			origin: OriginFor<T>,
+			lawyer: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::LocationOrigin::ensure_origin(origin)?;
+			let mut lawyer_details =
+				RealEstateLawyer::<T>::get(lawyer.clone()).ok_or(Error::<T>::NoPermission)?;
+			let amount = amount.min(lawyer_details.bond);
+			let remaining = <T as pallet::Config>::Currency::repatriate_reserved(
+				&lawyer,
+				&Self::treasury_account_id(),
+				amount,
+				BalanceStatus::Free,
+			)?;
+			let slashed = amount.saturating_sub(remaining);
+			lawyer_details.bond = lawyer_details.bond.saturating_sub(slashed);
+			RealEstateLawyer::<T>::insert(lawyer.clone(), lawyer_details);
+			Self::deposit_event(Event::<T>::LawyerSlashed { lawyer, amount: slashed });
+			Ok(())
+		}
+
+		/// Resolves a legal case where the lawyers disagreed twice.
+		///
+		/// The origin must be the ArbiterOrigin.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing from the property.
+		/// - `decision`: Executes the deal, refunds the investors or orders another review round.
+		///
+		/// Emits `ArbitrationResolved` event when succesfful.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 4).saturating_add(
+			T::DbWeight::get().reads_writes(3, 3).saturating_mul(T::MaxNftToken::get().into())
+		))]
+		pub fn resolve_arbitration(
+			origin: OriginFor<T>,
+			listing_id: ListingId,
+			decision: ArbitrationDecision,
+		) -> DispatchResult {
+			T::ArbiterOrigin::ensure_origin(origin)?;
+			Arbitrations::<T>::take(listing_id).ok_or(Error::<T>::ArbitrationNotFound)?;
+			let mut property_lawyer_details =
+				PropertyLawyer::<T>::take(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			match decision {
+				ArbitrationDecision::Execute => {
+					Self::execute_deal(listing_id, property_lawyer_details)?;
+				}
+				ArbitrationDecision::Refund => {
+					Self::burn_tokens_and_nfts(listing_id)?;
+					Self::refund_investors(listing_id, property_lawyer_details)?;
+				}
+				ArbitrationDecision::Review => {
+					property_lawyer_details.spv_status = DocumentStatus::Pending;
+					property_lawyer_details.real_estate_developer_status = DocumentStatus::Pending;
+					Self::schedule_second_review(listing_id, &mut property_lawyer_details)?;
+					PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
+				}
+			}
+			Self::deposit_event(Event::<T>::ArbitrationResolved { listing_id, decision });
+			Ok(())
+		}
+
+		/// Sets the maximum amount of token of a property that a single investor can hold.
+		/// The limit applies to the primary sale and all later purchases of the token.
+		///
+		/// The origin must be Signed and the sender must be the real estate developer.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing of the object that is still for sale.
+		/// - `max_token`: The maximum amount of token per investor. `None` removes the limit.
+		///
+		/// Emits `InvestorLimitSet` event when succesfful.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+		pub fn set_investor_limit(
+			origin: OriginFor<T>,
+			listing_id: ListingId,
+			max_token: Option<u32>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(ListedToken::<T>::contains_key(listing_id), Error::<T>::TokenNotForSale);
+			let nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(nft_details.real_estate_developer == signer, Error::<T>::NoPermission);
+			match max_token {
+				Some(max_token) => {
+					ensure!(max_token > 0, Error::<T>::InvalidInvestorLimit);
+					InvestorLimits::<T>::insert(nft_details.asset_id, max_token);
+				}
+				None => InvestorLimits::<T>::remove(nft_details.asset_id),
+			}
+			Self::deposit_event(Event::<T>::InvestorLimitSet {
+				listing_id,
+				asset_id: nft_details.asset_id,
+				max_token,
+			});
+			Ok(())
+		}
+
+		/// Returns the listed token and refunds the offers, auction bids and pending purchases of
+		/// an account that is no longer whitelisted or that has been blocked. Pending purchases
+		/// are only refunded while the object is not sold out, afterwards the token of the account
+		/// stay frozen.
+		///
+		/// The origin must be Signed. Anyone can enforce the compliance of an account.
+		///
+		/// Parameters:
+		/// - `account`: The account that has been removed from the whitelist or blocked.
+		/// - `listing_ids`: The listings that the account listed token in, made offers or bids on
+		/// or bought token from.
+		///
+		/// Emits `ComplianceEnforced` event when succesfful.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delist_token()
+			.saturating_add(<T as pallet::Config>::WeightInfo::cancel_offer())
+			.saturating_add(<T as pallet::Config>::WeightInfo::buy_token())
+			.saturating_add(T::DbWeight::get().reads_writes(7, 6))
+			.saturating_mul(listing_ids.len() as u64))]
+		pub fn enforce_compliance(
+			origin: OriginFor<T>,
+			account: AccountIdOf<T>,
+			listing_ids: BoundedVec<ListingId, T::MaxComplianceListings>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				!pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(account.clone()) ||
+					pallet_xcavate_whitelist::Pallet::<T>::is_blocked(&account),
+				Error::<T>::AccountWhitelisted
+			);
+			let mut returned_listings: u32 = 0;
+			let mut refunded_offers: u32 = 0;
+			let mut refunded_bids: u32 = 0;
+			let mut failed_refunds: u32 = 0;
+			let mut refunded_purchases: u32 = 0;
+			for listing_id in listing_ids {
+				if let Some(offer_details) = OngoingOffers::<T>::take(listing_id, account.clone()) {
+					Self::refund_offer(offer_details)?;
+					Self::deposit_event(Event::<T>::OfferRefunded {
+						listing_id,
+						account_id: account.clone(),
+					});
+					refunded_offers = refunded_offers.saturating_add(1);
+				}
+				if let Some(listing_details) = TokenListings::<T>::get(listing_id) {
+					if listing_details.seller == account {
+						TokenListings::<T>::remove(listing_id);
+						Self::return_listed_token(listing_id, listing_details)?;
+						returned_listings = returned_listings.saturating_add(1);
+					}
+				}
+				let bids = AuctionBids::<T>::get(listing_id);
+				if bids.iter().any(|bid| bid.bidder == account) {
+					let payment_asset = OngoingObjectListing::<T>::get(listing_id)
+						.ok_or(Error::<T>::InvalidIndex)?
+						.payment_asset;
+					for bid in bids.iter().filter(|bid| bid.bidder == account) {
+						if Self::try_refund_bid(
+							listing_id,
+							account.clone(),
+							Self::bid_escrowed_amount(bid)?,
+							payment_asset,
+						) {
+							refunded_bids = refunded_bids.saturating_add(1);
+						} else {
+							failed_refunds = failed_refunds.saturating_add(1);
+						}
+					}
+					AuctionBids::<T>::mutate(listing_id, |bids| {
+						bids.retain(|bid| bid.bidder != account)
+					});
+				}
+				if Self::refund_pending_purchase(listing_id, &account)? {
+					refunded_purchases = refunded_purchases.saturating_add(1);
+				}
+			}
+			ensure!(
+				returned_listings > 0 ||
+					refunded_offers > 0 || refunded_bids > 0 ||
+					failed_refunds > 0 || refunded_purchases > 0,
+				Error::<T>::NothingToEnforce
+			);
+			Self::deposit_event(Event::<T>::ComplianceEnforced {
+				account,
+				returned_listings,
+				refunded_offers,
+				refunded_bids,
+				failed_refunds,
+				refunded_purchases,
+			});
+			Ok(())
+		}
+
+		/// Sets the flat fee for transfers of property token between two investors that pay the
+		/// fee with a payment asset.
+		///
+		/// The origin must be the FeeScheduleOrigin.
+		///
+		/// Parameters:
+		/// - `payment_asset`: The asset that the fee is paid with.
+		/// - `fee`: The fee per transfer. A fee of zero disables the fee.
+		///
+		/// Emits `TransferFeeSet` event when succesfful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_transfer_fee())]
+		pub fn set_transfer_fee(
+			origin: OriginFor<T>,
+			payment_asset: u32,
+			fee: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			T::FeeScheduleOrigin::ensure_origin(origin)?;
+			ensure!(PaymentAssets::<T>::get(payment_asset), Error::<T>::PaymentAssetNotSupported);
+			TransferFee::<T>::insert(payment_asset, fee);
+			Self::deposit_event(Event::<T>::TransferFeeSet { payment_asset, fee });
+			Ok(())
+		}
+
+		/// Transfers property token to another investor, e.g. as a gift or for an inheritance.
+		/// Both investors have to be whitelisted. If a transfer fee is set for the payment asset,
+		/// the sender pays it to the treasury.
+		///
+		/// The origin must be Signed and the sender must own enough token of the property.
+		///
+		/// Parameters:
+		/// - `asset_id`: The asset id of the property.
+		/// - `to`: The account that receives the token.
+		/// - `amount`: The amount of token that get transferred.
+		/// - `payment_asset`: The asset that the transfer fee is paid with, it has to be an
+		/// accepted payment asset.
+		///
+		/// Emits `PropertyTokenTransferred` event when succesfful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_property_token())]
+		pub fn transfer_property_token(
+			origin: OriginFor<T>,
+			asset_id: u32,
+			to: AccountIdOf<T>,
+			amount: u32,
+			payment_asset: u32,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&to)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(to.clone()),
+				Error::<T>::ReceiverNotWhitelisted
+			);
+			ensure!(amount > 0 && signer != to, Error::<T>::InvalidTransfer);
+			ensure!(AssetIdDetails::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+			ensure!(
+				PropertyOwnerToken::<T>::get(asset_id, signer.clone()) >= amount,
+				Error::<T>::NotEnoughTokenAvailable
+			);
+			Self::ensure_holding_limits(
+				asset_id,
+				PropertyOwnerToken::<T>::get(asset_id, to.clone())
+					.checked_add(amount)
+					.ok_or(Error::<T>::ArithmeticOverflow)?,
+			)?;
+			ensure!(PaymentAssets::<T>::get(payment_asset), Error::<T>::PaymentAssetNotSupported);
+			let fee = TransferFee::<T>::get(payment_asset);
+			if !fee.is_zero() {
+				Self::transfer_funds(
+					signer.clone(),
+					Self::treasury_account_id(),
+					fee,
+					payment_asset,
+				)?;
+			}
+			let user_lookup = <T::Lookup as StaticLookup>::unlookup(to.clone());
+			let asset: AssetId<T> = asset_id.into();
+			let origin: OriginFor<T> = RawOrigin::Signed(signer.clone()).into();
+			pallet_assets::Pallet::<T, Instance1>::transfer(
+				origin,
+				asset.into().into(),
+				user_lookup,
+				amount.into(),
+			)
+			.map_err(|_| Error::<T>::NotEnoughTokenAvailable)?;
+			Self::move_property_ownership(asset_id, signer.clone(), to.clone(), amount)?;
+			Self::deposit_event(Event::<T>::PropertyTokenTransferred {
+				asset_id,
+				from: signer,
+				to,
+				amount,
+				payment_asset,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Claims the escrowed funds of bids that could not be refunded when they were outbid or
+		/// their auction ended.
+		///
+		/// The origin must be Signed and the sender must have a failed bid refund.
+		///
+		/// Parameters:
+		/// - `payment_asset`: The asset that the bids have been paid with.
+		///
+		/// Emits `BidRefundClaimed` event when succesfful.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_bid_refund())]
+		pub fn claim_bid_refund(origin: OriginFor<T>, payment_asset: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			let amount = FailedBidRefunds::<T>::take(signer.clone(), payment_asset);
+			ensure!(!amount.is_zero(), Error::<T>::NoBidRefund);
+			Self::transfer_funds(Self::account_id(), signer.clone(), amount, payment_asset)?;
+			Self::deposit_event(Event::<T>::BidRefundClaimed {
+				bidder: signer,
+				payment_asset,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Reserves the missing part of the bond of a slashed lawyer, so that the lawyer can claim
+		/// new cases again.
+		///
+		/// The origin must be Signed and the sender must be a registered lawyer.
+		///
+		/// Emits `LawyerBondToppedUp` event when succesfful.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn top_up_lawyer_bond(origin: OriginFor<T>) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			let mut lawyer_details =
+				RealEstateLawyer::<T>::get(signer.clone()).ok_or(Error::<T>::NoPermission)?;
+			let amount = T::LawyerBond::get().saturating_sub(lawyer_details.bond);
+			ensure!(!amount.is_zero(), Error::<T>::LawyerBondComplete);
+			<T as pallet::Config>::Currency::reserve(&signer, amount)?;
+			lawyer_details.bond = lawyer_details.bond.saturating_add(amount);
+			RealEstateLawyer::<T>::insert(signer.clone(), lawyer_details);
+			Self::deposit_event(Event::<T>::LawyerBondToppedUp { lawyer: signer, amount });
+			Ok(())
+		}
+
+		/// Releases the quarantined funds of an account that is no longer blocked.
+		///
+		/// The origin must be the ComplianceOrigin.
+		///
+		/// Parameters:
+		/// - `account`: The account whose funds get released.
+		/// - `payment_asset`: The asset of the quarantined funds.
+		///
+		/// Emits `QuarantinedFundsReleased` event when succesfful.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::release_quarantined_funds())]
+		pub fn release_quarantined_funds(
+			origin: OriginFor<T>,
+			account: AccountIdOf<T>,
+			payment_asset: u32,
+		) -> DispatchResult {
+			<T as pallet_xcavate_whitelist::Config>::ComplianceOrigin::ensure_origin(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&account)?;
+			let amount = QuarantinedFunds::<T>::take(account.clone(), payment_asset);
+			ensure!(!amount.is_zero(), Error::<T>::NoQuarantinedFunds);
+			Self::transfer_funds(Self::account_id(), account.clone(), amount, payment_asset)?;
+			Self::deposit_event(Event::<T>::QuarantinedFundsReleased {
+				account,
+				payment_asset,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Applies for the registration as a lawyer and reserves the bond from the sender.
+		/// The lawyer gets registered once the LocationOrigin approves the application.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `firm_name_hash`: The hash of the name of the law firm.
+		/// - `regions`: The regions that the lawyer is licensed for.
+		///
+		/// Emits `LawyerApplied` event when succesfful.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 2)
+			.saturating_add(T::DbWeight::get().reads(T::MaxLawyerRegions::get().into())))]
+		pub fn apply_as_lawyer(
+			origin: OriginFor<T>,
+			firm_name_hash: T::Hash,
+			regions: BoundedVec<RegionId, T::MaxLawyerRegions>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				!RealEstateLawyer::<T>::contains_key(signer.clone()),
+				Error::<T>::LawyerAlreadyRegistered
+			);
+			ensure!(
+				!LawyerApplications::<T>::contains_key(signer.clone()),
+				Error::<T>::LawyerAlreadyApplied
+			);
+			for region in regions.iter() {
+				ensure!(RegionCollections::<T>::contains_key(region), Error::<T>::RegionUnknown);
+			}
+			let bond = T::LawyerBond::get();
+			<T as pallet::Config>::Currency::reserve(&signer, bond)?;
+			let lawyer_details = LawyerDetails {
+				firm_name_hash,
+				regions: regions.clone(),
+				bond,
+				active_cases: 0,
+				unbonding_until: None,
+			};
+			LawyerApplications::<T>::insert(signer.clone(), lawyer_details);
+			Self::deposit_event(Event::<T>::LawyerApplied { lawyer: signer, regions });
+			Ok(())
+		}
+
+		/// Rejects the application of a lawyer and releases the bond of the lawyer.
+		///
+		/// The origin must be the LocationOrigin.
+		///
+		/// Parameters:
+		/// - `lawyer`: The lawyer that applied with `apply_as_lawyer`.
+		///
+		/// Emits `LawyerApplicationRejected` event when succesfful.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn reject_lawyer(origin: OriginFor<T>, lawyer: AccountIdOf<T>) -> DispatchResult {
+			T::LocationOrigin::ensure_origin(origin)?;
+			let lawyer_details = LawyerApplications::<T>::take(lawyer.clone())
+				.ok_or(Error::<T>::LawyerApplicationNotFound)?;
+			<T as pallet::Config>::Currency::unreserve(&lawyer, lawyer_details.bond);
+			Self::deposit_event(Event::<T>::LawyerApplicationRejected { lawyer });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Get the account id of the pallet
+		pub fn account_id() -> AccountIdOf<T> {
+			<T as pallet::Config>::PalletId::get().into_account_truncating()
+		}
+
+		/// Get the account id of the treasury pallet
+		pub fn treasury_account_id() -> AccountIdOf<T> {
+			T::TreasuryId::get().into_account_truncating()
+		}
+
+		/// Get the account id of the community pallet
+		pub fn community_account_id() -> AccountIdOf<T> {
+			T::CommunityProjectsId::get().into_account_truncating()
+		}
+
+		pub fn next_listing_id(listing_id: ListingId) -> Result<ListingId, Error<T>> {
+			listing_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)
+		}
+
+		/// Mints and fractionalizes the nft of a real estate object and lists its token.
+		/// Returns the id of the new listing.
+		fn do_list_object(
+			origin: OriginFor<T>,
+			region: RegionId,
+			location: LocationId<T>,
+			token_price: AssetBalanceOf<T>,
+			token_amount: u32,
+			data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
+			payment_asset: u32,
+		) -> Result<ListingId, DispatchError> {
+			let signer = ensure_signed(origin.clone())?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
+			ensure!(token_amount <= T::MaxNftToken::get(), Error::<T>::TooManyToken);
+			ensure!(PaymentAssets::<T>::get(payment_asset), Error::<T>::PaymentAssetNotSupported);
+			let collection_id: CollectionId<T> =
+				RegionCollections::<T>::get(region).ok_or(Error::<T>::RegionUnknown)?;
+			ensure!(
+				LocationRegistration::<T>::get(region, location.clone()),
+				Error::<T>::LocationUnknown
+			);
+			let mut next_item_id = NextNftId::<T>::get(collection_id);
+			let mut asset_number: u32 = NextAssetId::<T>::get();
+			let mut asset_id: AssetId<T> = asset_number.into();
+			while pallet_assets::Pallet::<T, Instance1>::maybe_total_supply(asset_id.into())
+				.is_some()
+			{
+				asset_number = asset_number.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+				asset_id = asset_number.into();
+			}
+			let asset_id: FractionalizedAssetId<T> = asset_number.into();
+			let item_id: ItemId<T> = next_item_id.into();
+			let listing_id = NextListingId::<T>::get();
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let listing_expiry = current_block_number
+				.checked_add(&T::ListingDuration::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let nft = NftListingDetails {
+				real_estate_developer: signer.clone(),
+				token_price,
+				collected_funds: Default::default(),
+				collected_tax: Default::default(),
+				collected_fees: Default::default(),
+				asset_id: asset_number,
+				item_id,
+				collection_id,
+				token_amount,
+				payment_asset,
+				listing_expiry,
+			};
+			let pallet_account = Self::account_id();
+			pallet_nfts::Pallet::<T>::do_mint(
+				collection_id.into(),
+				item_id.into(),
+				Some(pallet_account.clone()),
+				pallet_account.clone(),
+				Self::default_item_config(),
+				|_, _| Ok(()),
+			)?;
+			let pallet_origin: OriginFor<T> = RawOrigin::Signed(pallet_account.clone()).into();
+			pallet_nfts::Pallet::<T>::set_metadata(
+				pallet_origin.clone(),
+				collection_id.into(),
+				item_id.into(),
+				data.clone(),
+			)?;
+			let registered_nft_details = NftDetails {
+				spv_created: false,
+				asset_id: asset_number,
+				region,
+				location: location.clone(),
+			};
+			RegisteredNftDetails::<T>::insert(collection_id, item_id, registered_nft_details);
+			OngoingObjectListing::<T>::insert(listing_id, nft.clone());
+			ListedToken::<T>::insert(listing_id, token_amount);
+
+			let user_lookup = <T::Lookup as StaticLookup>::unlookup(pallet_account);
+			let nft_balance: FrationalizedNftBalanceOf<T> = token_amount.into();
+			let fractionalize_collection_id = FractionalizeCollectionId::<T>::from(collection_id);
+			let fractionalize_item_id = FractionalizeItemId::<T>::from(item_id);
+			pallet_nft_fractionalization::Pallet::<T>::fractionalize(
+				pallet_origin.clone(),
+				fractionalize_collection_id.into(),
+				fractionalize_item_id.into(),
+				asset_id.into(),
+				user_lookup,
+				nft_balance,
+			)?;
+			let property_price = token_price
+				.checked_mul(&Self::u64_to_balance_option(token_amount as u64)?)
+				.ok_or(Error::<T>::MultiplyError)?;
+			let asset_details =
+				AssetDetails { collection_id, item_id, region, location, price: property_price, token_amount };
+			AssetIdDetails::<T>::insert(asset_number, asset_details);
+			next_item_id = next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			asset_number = asset_number.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			NextNftId::<T>::insert(collection_id, next_item_id);
+			NextAssetId::<T>::put(asset_number);
+			NextListingId::<T>::put(Self::next_listing_id(listing_id)?);
+
+			Self::deposit_event(Event::<T>::ObjectListed {
+				collection_index: collection_id,
+				item_index: item_id,
+				price: token_price,
+				seller: signer,
+			});
+			Ok(listing_id)
+		}
+
+
+		/// Buys the token of every purchase of a batch. If a purchase fails, `BatchItemFailed` is
+		/// emitted and the error of the purchase is returned unchanged.
+		pub(crate) fn do_buy_batch(
+			signer: AccountIdOf<T>,
+			purchases: BoundedVec<(ListingId, u32, AssetBalanceOf<T>), T::MaxBatchPurchases>,
+		) -> DispatchResult {
+			for (index, (listing_id, amount, max_price)) in purchases.into_iter().enumerate() {
+				let result = if ListedToken::<T>::contains_key(listing_id) {
+					Self::do_buy_token(signer.clone(), listing_id, amount, max_price)
+				} else {
+					Self::do_buy_relisted_token(signer.clone(), listing_id, amount, max_price)
+				};
+				if let Err(error) = result {
+					Self::deposit_event(Event::<T>::BatchItemFailed {
+						index: index as u32,
+						listing_id,
+						error,
+					});
+					return Err(error);
+				}
+			}
+			Ok(())
+		}
+
+		/// Buys token of a listed object and starts the legal process once all token are sold.
+		fn do_buy_token(
+			signer: AccountIdOf<T>,
+			listing_id: ListingId,
+			amount: u32,
+			max_total_price: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(!ObjectAuctions::<T>::contains_key(listing_id), Error::<T>::ListingInAuction);
+			ListedToken::<T>::try_mutate_exists(listing_id, |maybe_listed_token| {
+				let listed_token = maybe_listed_token.as_mut().ok_or(Error::<T>::TokenNotForSale)?;
+				ensure!(*listed_token >= amount, Error::<T>::NotEnoughTokenAvailable);
+				let mut nft_details =
+					OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+				ensure!(
+					!RegisteredNftDetails::<T>::get(nft_details.collection_id, nft_details.item_id)
+						.ok_or(Error::<T>::InvalidIndex)?
+						.spv_created,
+					Error::<T>::SpvAlreadyCreated
+				);
+				ensure!(
+					nft_details.listing_expiry >= <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::ListingExpired
+				);
+				Self::ensure_holding_limits(
+					nft_details.asset_id,
+					TokenOwner::<T>::get(signer.clone(), listing_id)
+						.token_amount
+						.checked_add(amount)
+						.ok_or(Error::<T>::ArithmeticOverflow)?,
+				)?;
+
+				let transfer_price = nft_details
+					.token_price
+					.checked_mul(&Self::u64_to_balance_option(amount as u64)?)
+					.ok_or(Error::<T>::MultiplyError)?;
+
+				let fee_details = FeeSchedule::<T>::get().primary;
+				let fee = fee_details.buyer_fee.mul_floor(transfer_price);
+				let tax = fee_details.tax.mul_floor(transfer_price);
+
+				let total_transfer_price = transfer_price
+					.checked_add(&fee)
+					.ok_or(Error::<T>::ArithmeticOverflow)?
+					.checked_add(&tax)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				Self::ensure_price_limit(total_transfer_price, max_total_price)?;
+				Self::record_investment(signer.clone(), transfer_price, nft_details.payment_asset)?;
+
+				Self::transfer_funds(
+					signer.clone(),
+					Self::account_id(),
+					total_transfer_price,
+					nft_details.payment_asset,
+				)?;
+				*listed_token =
+					listed_token.checked_sub(amount).ok_or(Error::<T>::ArithmeticUnderflow)?;
+				Self::record_token_purchase(
+					listing_id,
+					&mut nft_details,
+					signer,
+					amount,
+					transfer_price,
+					fee,
+					tax,
+				)?;
+				if *listed_token == 0 {
+					Self::start_legal_process(listing_id);
+					*maybe_listed_token = None;
+				}
+				Ok::<(), DispatchError>(())
+			})
+		}
+
+		/// Buys relisted token at the current price of the listing.
+		fn do_buy_relisted_token(
+			signer: AccountIdOf<T>,
+			listing_id: ListingId,
+			amount: u32,
+			max_total_price: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let listing_details =
+				TokenListings::<T>::take(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
+			ensure!(listing_details.amount >= amount, Error::<T>::NotEnoughTokenAvailable);
+			let token_price = Self::current_token_price(&listing_details)?;
+			let price = token_price
+				.checked_mul(&Self::u64_to_balance_option(amount.into())?)
+				.ok_or(Error::<T>::MultiplyError)?;
+			let fees = Self::calculate_buyer_fees(price, &FeeSchedule::<T>::get().secondary)?;
+			Self::ensure_price_limit(
+				price.checked_add(&fees).ok_or(Error::<T>::ArithmeticOverflow)?,
+				max_total_price,
+			)?;
+			Self::buying_token_process(
+				listing_id,
+				signer.clone(),
+				signer,
+				listing_details,
+				token_price,
+				price,
+				fees,
+				amount,
+			)
+		}
+
+		/// Checks that an investor does not hold more token of a property than allowed after a purchase.
+		fn ensure_holding_limits(asset_id: u32, token_after_purchase: u32) -> DispatchResult {
+			if let Some(max_token) = InvestorLimits::<T>::get(asset_id) {
+				ensure!(token_after_purchase <= max_token, Error::<T>::InvestorLimitExceeded);
+			}
+			let asset_details = AssetIdDetails::<T>::get(asset_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(
+				token_after_purchase <= T::MaxOwnershipShare::get().mul_ceil(asset_details.token_amount).max(1),
+				Error::<T>::ConcentrationCapExceeded
+			);
+			Ok(())
+		}
+
+		/// Checks a purchase against the investment limits of the KYC tier of the investor and
+		/// records it.
+		fn record_investment(
+			investor: AccountIdOf<T>,
+			price: AssetBalanceOf<T>,
+			payment_asset: u32,
+		) -> DispatchResult {
+			let price: u128 = price.try_into().map_err(|_| Error::<T>::ConversionError)?;
+			pallet_xcavate_whitelist::Pallet::<T>::record_investment(
+				investor,
+				price,
+				Self::payment_asset_decimals(payment_asset),
+			)
+		}
+
+		/// Checks a bid against the investment limits of the KYC tier of the bidder, the bid is
+		/// recorded once it gets filled.
+		fn ensure_investment_allowed(
+			investor: AccountIdOf<T>,
+			price: AssetBalanceOf<T>,
+			payment_asset: u32,
+		) -> DispatchResult {
+			let price: u128 = price.try_into().map_err(|_| Error::<T>::ConversionError)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_investment_allowed(
+				investor,
+				price,
+				Self::payment_asset_decimals(payment_asset),
+			)
+		}
+
+		/// Returns the decimals of a payment asset.
+		fn payment_asset_decimals(payment_asset: u32) -> u8 {
+			let asset_id: AssetId<T> = payment_asset.into();
+			<pallet_assets::Pallet<T, Instance1> as fungibles::metadata::Inspect<AccountIdOf<T>>>::decimals(
+				asset_id.into(),
+			)
+		}
+
+		/// Checks that the total price of a purchase does not exceed the maximum price of the buyer.
+		fn ensure_price_limit(
+			total_price: AssetBalanceOf<T>,
+			max_total_price: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(total_price <= max_total_price, Error::<T>::PriceLimitExceeded);
+			Ok(())
+		}
+
+		/// Registers the buyer of token of a listed object and adds the paid amounts to the listing.
+		fn record_token_purchase(
+			listing_id: ListingId,
+			nft_details: &mut NftListingDetailsType<T>,
+			buyer: AccountIdOf<T>,
+			amount: u32,
+			transfer_price: AssetBalanceOf<T>,
+			fee: AssetBalanceOf<T>,
+			tax: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			if !TokenBuyer::<T>::get(listing_id).contains(&buyer) {
+				TokenBuyer::<T>::try_mutate(listing_id, |keys| {
+					keys.try_push(buyer.clone()).map_err(|_| Error::<T>::TooManyTokenBuyer)?;
+					Ok::<(), DispatchError>(())
+				})?;
+			}
+			TokenOwner::<T>::try_mutate_exists(buyer.clone(), listing_id, |maybe_token_owner_details| {
+				let token_owner_details = maybe_token_owner_details.get_or_insert( TokenOwnerDetails {
+					token_amount: 0,
+					paid_funds: Default::default(),
+					paid_tax: Default::default(),
+					paid_fee: Default::default(),
+				});
+				token_owner_details.token_amount = token_owner_details.token_amount
+					.checked_add(amount)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				token_owner_details.paid_funds = token_owner_details.paid_funds
+					.checked_add(&transfer_price)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				token_owner_details.paid_tax = token_owner_details.paid_tax
+					.checked_add(&tax)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				token_owner_details.paid_fee = token_owner_details.paid_fee
+					.checked_add(&fee)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+
+				Ok::<(), DispatchError>(())
+			})?;
+			nft_details.collected_funds = nft_details
+				.collected_funds
+				.checked_add(&transfer_price)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			nft_details.collected_tax = nft_details
+				.collected_tax
+				.checked_add(&tax)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			nft_details.collected_fees = nft_details
+				.collected_fees
+				.checked_add(&fee)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			OngoingObjectListing::<T>::insert(listing_id, nft_details.clone());
+			Self::deposit_event(Event::<T>::TokenBoughtObject {
+				asset_id: nft_details.asset_id,
+				buyer,
+				amount,
+				price: transfer_price,
+			});
+			Ok(())
+		}
+
+		/// Returns the price plus the fees and tax that are held in escrow for a bid.
+		fn bid_escrowed_amount(
+			bid: &BidDetails<AssetBalanceOf<T>, T>,
+		) -> Result<AssetBalanceOf<T>, DispatchError> {
+			Ok(bid
+				.token_price
+				.checked_mul(&Self::u64_to_balance_option(bid.amount as u64)?)
+				.ok_or(Error::<T>::MultiplyError)?
+				.checked_add(&bid.fee)
+				.ok_or(Error::<T>::ArithmeticOverflow)?
+				.checked_add(&bid.tax)
+				.ok_or(Error::<T>::ArithmeticOverflow)?)
+		}
+
+		/// Allocates the token of an auction from the highest bid down and refunds the
+		/// unfilled part of the bids. Starts the legal process if all token are sold.
+		fn finish_auction(listing_id: ListingId) -> DispatchResult {
+			ObjectAuctions::<T>::remove(listing_id);
+			let mut bids = AuctionBids::<T>::take(listing_id).into_inner();
+			// stable sort, so equal bids are filled in the order they were placed.
+			bids.sort_by(|a, b| b.token_price.cmp(&a.token_price));
+			let mut nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			let mut listed_token =
+				ListedToken::<T>::get(listing_id).ok_or(Error::<T>::TokenNotForSale)?;
+			let mut sold_token: u32 = 0;
+			for bid in bids {
+				let escrowed_amount = Self::bid_escrowed_amount(&bid)?;
+				let filled_amount = bid.amount.min(listed_token);
+				let mut paid_amount: AssetBalanceOf<T> = Zero::zero();
+				let filled_balance = Self::u64_to_balance_option(filled_amount as u64)?;
+				let transfer_price = bid
+					.token_price
+					.checked_mul(&filled_balance)
+					.ok_or(Error::<T>::MultiplyError)?;
+				// a bid of a bidder that is no longer whitelisted, that has been blocked or that
+				// exceeds the investment limits by now is not filled.
+				if filled_amount > 0
+					&& !pallet_xcavate_whitelist::Pallet::<T>::is_blocked(&bid.bidder)
+					&& Self::record_investment(
+						bid.bidder.clone(),
+						transfer_price,
+						nft_details.payment_asset,
+					)
+					.is_ok()
+				{
+					let bid_balance = Self::u64_to_balance_option(bid.amount as u64)?;
+					let fee = bid
+						.fee
+						.checked_mul(&filled_balance)
+						.ok_or(Error::<T>::MultiplyError)?
+						.checked_div(&bid_balance)
+						.ok_or(Error::<T>::DivisionError)?;
+					let tax = bid
+						.tax
+						.checked_mul(&filled_balance)
+						.ok_or(Error::<T>::MultiplyError)?
+						.checked_div(&bid_balance)
+						.ok_or(Error::<T>::DivisionError)?;
+					paid_amount = transfer_price
+						.checked_add(&fee)
+						.ok_or(Error::<T>::ArithmeticOverflow)?
+						.checked_add(&tax)
+						.ok_or(Error::<T>::ArithmeticOverflow)?;
+					listed_token = listed_token
+						.checked_sub(filled_amount)
+						.ok_or(Error::<T>::ArithmeticUnderflow)?;
+					sold_token =
+						sold_token.checked_add(filled_amount).ok_or(Error::<T>::ArithmeticOverflow)?;
+					Self::record_token_purchase(
+						listing_id,
+						&mut nft_details,
+						bid.bidder.clone(),
+						filled_amount,
+						transfer_price,
+						fee,
+						tax,
+					)?;
+				}
+				let refund_amount = escrowed_amount
+					.checked_sub(&paid_amount)
+					.ok_or(Error::<T>::ArithmeticUnderflow)?;
+				if !refund_amount.is_zero() {
+					Self::try_refund_bid(
+						listing_id,
+						bid.bidder,
+						refund_amount,
+						nft_details.payment_asset,
+					);
+				}
+			}
+			if listed_token == 0 {
+				ListedToken::<T>::remove(listing_id);
+				Self::start_legal_process(listing_id);
+			} else {
+				ListedToken::<T>::insert(listing_id, listed_token);
+			}
+			Self::deposit_event(Event::<T>::AuctionFinished { listing_id, sold_token });
+			Ok(())
+		}
+
+		/// Refunds all bids of an auction that could not be finished.
+		/// The token stay listed at the reserve price.
+		fn refund_bids(listing_id: ListingId) {
+			ObjectAuctions::<T>::remove(listing_id);
+			let payment_asset = match OngoingObjectListing::<T>::get(listing_id) {
+				Some(nft_details) => nft_details.payment_asset,
+				None => return,
+			};
+			for bid in AuctionBids::<T>::take(listing_id) {
+				if let Ok(escrowed_amount) = Self::bid_escrowed_amount(&bid) {
+					Self::try_refund_bid(listing_id, bid.bidder, escrowed_amount, payment_asset);
+				}
+			}
+			Self::deposit_event(Event::<T>::AuctionFinished { listing_id, sold_token: 0 });
+		}
+
+		/// Refunds the pending purchase of an account on an object listing that is not sold out
+		/// yet and lists the token again. Returns false if there is no such purchase.
+		fn refund_pending_purchase(
+			listing_id: ListingId,
+			account: &AccountIdOf<T>,
+		) -> Result<bool, DispatchError> {
+			let listed_token = match ListedToken::<T>::get(listing_id) {
+				Some(listed_token) => listed_token,
+				None => return Ok(false),
+			};
+			if !TokenOwner::<T>::contains_key(account.clone(), listing_id) {
+				return Ok(false);
+			}
+			let token_details = TokenOwner::<T>::take(account.clone(), listing_id);
+			let mut nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			nft_details.collected_funds = nft_details
+				.collected_funds
+				.checked_sub(&token_details.paid_funds)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			nft_details.collected_tax = nft_details
+				.collected_tax
+				.checked_sub(&token_details.paid_tax)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			nft_details.collected_fees = nft_details
+				.collected_fees
+				.checked_sub(&token_details.paid_fee)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			OngoingObjectListing::<T>::insert(listing_id, nft_details.clone());
+			TokenBuyer::<T>::mutate(listing_id, |buyers| buyers.retain(|buyer| buyer != account));
+			ListedToken::<T>::insert(
+				listing_id,
+				listed_token
+					.checked_add(token_details.token_amount)
+					.ok_or(Error::<T>::ArithmeticOverflow)?,
+			);
+			let refund_amount = token_details
+				.paid_funds
+				.checked_add(&token_details.paid_tax)
+				.ok_or(Error::<T>::ArithmeticOverflow)?
+				.checked_add(&token_details.paid_fee)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			Self::pay_out(account.clone(), refund_amount, nft_details.payment_asset)?;
+			Ok(true)
+		}
+
+		/// Refunds the escrowed funds of a bid and returns true on success. The refund of a blocked
+		/// bidder is put into quarantine. If the refund fails,
+		/// the funds are added to `FailedBidRefunds` so that the bidder can claim them with
+		/// `claim_bid_refund`.
+		fn try_refund_bid(
+			listing_id: ListingId,
+			bidder: AccountIdOf<T>,
+			amount: AssetBalanceOf<T>,
+			payment_asset: u32,
+		) -> bool {
+			let result = frame_support::storage::with_storage_layer(|| {
+				Self::pay_out(bidder.clone(), amount, payment_asset)
+			});
+			if result.is_err() {
+				FailedBidRefunds::<T>::mutate(bidder.clone(), payment_asset, |refund| {
+					*refund = refund.saturating_add(amount)
+				});
+				Self::deposit_event(Event::<T>::BidRefundFailed { listing_id, bidder });
+			}
+			result.is_ok()
+		}
+
+		/// Starts the legal process of a sold out object.
+		fn start_legal_process(listing_id: ListingId) {
+			let property_lawyer_details = PropertyLawyerDetails {
+				real_estate_developer_lawyer: None,
+				spv_lawyer: None,
+				real_estate_developer_status: DocumentStatus::Pending,
+				spv_status: DocumentStatus::Pending,
+				real_estate_developer_lawyer_costs: Default::default(),
+				spv_lawyer_costs: Default::default(),
+				second_attempt: false,
+				real_estate_developer_deadline: None,
+				spv_deadline: None,
+				review_timeouts: 0,
+				review_round: 1,
+			};
+			PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
+		}
+
+		/// Transfers the token to the marketplace and creates a new token listing.
+		/// Returns the listing id and the asset id of the token.
+		fn do_relist_token(
+			origin: OriginFor<T>,
+			region: RegionId,
+			item_id: <T as pallet::Config>::ItemId,
+			token_price: AssetBalanceOf<T>,
+			amount: u32,
+			payment_asset: u32,
+			dutch_auction: Option<DutchAuctionDetails<AssetBalanceOf<T>, BlockNumberFor<T>>>,
+		) -> Result<(ListingId, u32), DispatchError> {
+			let signer = ensure_signed(origin.clone())?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
+			ensure!(PaymentAssets::<T>::get(payment_asset), Error::<T>::PaymentAssetNotSupported);
+			let collection_id: CollectionId<T> =
+				RegionCollections::<T>::get(region).ok_or(Error::<T>::RegionUnknown)?;
+
+			let nft_details = RegisteredNftDetails::<T>::get(collection_id, item_id)
+				.ok_or(Error::<T>::NftNotFound)?;
+			ensure!(
+				LocationRegistration::<T>::get(region, nft_details.location),
+				Error::<T>::LocationUnknown
+			);
+			let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
+			let asset_id: AssetId<T> = nft_details.asset_id.into();
+			let token_amount = amount.into();
+			pallet_assets::Pallet::<T, Instance1>::transfer(
+				origin,
+				asset_id.into().into(),
+				pallet_lookup,
+				token_amount,
+			)
+			.map_err(|_| Error::<T>::NotEnoughFunds)?;
+			let listing_id = NextListingId::<T>::get();
+			let token_listing = TokenListingDetails {
+				seller: signer,
+				token_price,
+				asset_id: nft_details.asset_id,
+				item_id,
+				collection_id,
+				amount,
+				payment_asset,
+				dutch_auction,
+			};
+			TokenListings::<T>::insert(listing_id, token_listing);
+			NextListingId::<T>::put(Self::next_listing_id(listing_id)?);
+			Ok((listing_id, nft_details.asset_id))
+		}
+
+		/// Returns the current price of a single token of a token listing.
+		pub fn current_token_price(
+			listing_details: &ListingDetailsType<T>,
+		) -> Result<AssetBalanceOf<T>, Error<T>> {
+			let dutch_auction = match &listing_details.dutch_auction {
+				Some(dutch_auction) => dutch_auction,
+				None => return Ok(listing_details.token_price),
+			};
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			if current_block_number >= dutch_auction.end_block {
+				return Ok(dutch_auction.floor_price);
+			}
+			let elapsed = Self::u64_to_balance_option(
+				current_block_number.saturating_sub(dutch_auction.start_block).saturated_into(),
+			)?;
+			let duration = Self::u64_to_balance_option(
+				dutch_auction.end_block.saturating_sub(dutch_auction.start_block).saturated_into(),
+			)?;
+			let price_drop = dutch_auction
+				.start_price
+				.checked_sub(&dutch_auction.floor_price)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?
+				.checked_mul(&elapsed)
+				.ok_or(Error::<T>::MultiplyError)?
+				.checked_div(&duration)
+				.ok_or(Error::<T>::DivisionError)?;
+			dutch_auction.start_price.checked_sub(&price_drop).ok_or(Error::<T>::ArithmeticUnderflow)
+		}
+
+		/// Adds the review deadline of a legal side and returns the block of the deadline.
+		fn schedule_review(
+			listing_id: ListingId,
+			legal_side: LegalProperty,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			let deadline = <frame_system::Pallet<T>>::block_number()
+				.checked_add(&T::LawyerReviewPeriod::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			Self::push_to_free_block::<ReviewDeadlines<T>, _, _>(
+				deadline,
+				(listing_id, legal_side),
+				Error::<T>::TooManyReviewDeadlines,
+			)
+		}
+
+		/// Adds a key to the first block from `block` on that has space left and returns this
+		/// block, so that a full block moves the deadline to the next block instead of failing.
+		fn push_to_free_block<S, K, B>(
+			block: BlockNumberFor<T>,
+			key: K,
+			error: Error<T>,
+		) -> Result<BlockNumberFor<T>, DispatchError>
+		where
+			S: frame_support::storage::StorageMap<
+				BlockNumberFor<T>,
+				BoundedVec<K, B>,
+				Query = BoundedVec<K, B>,
+			>,
+			K: Clone,
+			B: Get<u32>,
+			BoundedVec<K, B>: FullCodec,
+		{
+			let mut block = block;
+			for _ in 0..MAX_ROLLOVER_BLOCKS {
+				if S::try_mutate(block, |keys| keys.try_push(key.clone())).is_ok() {
+					return Ok(block);
+				}
+				block = block.checked_add(&One::one()).ok_or(Error::<T>::ArithmeticOverflow)?;
+			}
+			Err(error.into())
+		}
+
+		/// Starts a new review round for both lawyers of a case.
+		fn schedule_second_review(
+			listing_id: ListingId,
+			property_lawyer_details: &mut PropertyLawyerDetails<T>,
+		) -> DispatchResult {
+			property_lawyer_details.review_round =
+				property_lawyer_details.review_round.saturating_add(1);
+			property_lawyer_details.real_estate_developer_deadline =
+				Some(Self::schedule_review(listing_id, LegalProperty::RealEstateDeveloperSide)?);
+			property_lawyer_details.spv_deadline =
+				Some(Self::schedule_review(listing_id, LegalProperty::SpvSide)?);
+			Ok(())
+		}
+
+		/// Removes a lawyer that did not review the documents until the deadline from the case.
+		/// The investors get refunded once the maximum amount of timeouts is reached.
+		pub(crate) fn review_timeout(
+			listing_id: ListingId,
+			legal_side: LegalProperty,
+			block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let mut property_lawyer_details = match PropertyLawyer::<T>::get(listing_id) {
+				Some(details) => details,
+				None => return Ok(()),
+			};
+			let lawyer = match legal_side {
+				LegalProperty::RealEstateDeveloperSide => {
+					if property_lawyer_details.real_estate_developer_deadline != Some(block)
+						|| property_lawyer_details.real_estate_developer_status != DocumentStatus::Pending
+					{
+						return Ok(());
 					}
+					property_lawyer_details.real_estate_developer_deadline = None;
+					property_lawyer_details.real_estate_developer_lawyer_costs = Default::default();
+					property_lawyer_details.real_estate_developer_lawyer.take()
 				}
-				(DocumentStatus::Rejected, DocumentStatus::Approved) => {
-					if !property_lawyer_details.second_attempt {
-						property_lawyer_details.spv_status = DocumentStatus::Pending;
-						property_lawyer_details.real_estate_developer_status = DocumentStatus::Pending;
-						property_lawyer_details.second_attempt = true;
-						PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
-					} else {
-						Self::burn_tokens_and_nfts(listing_id)?;
-						Self::refund_investors(listing_id, property_lawyer_details)?;
+				LegalProperty::SpvSide => {
+					if property_lawyer_details.spv_deadline != Some(block)
+						|| property_lawyer_details.spv_status != DocumentStatus::Pending
+					{
+						return Ok(());
 					}
+					property_lawyer_details.spv_deadline = None;
+					property_lawyer_details.spv_lawyer_costs = Default::default();
+					property_lawyer_details.spv_lawyer.take()
 				}
-				_ => {
-					PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
-				}
+			};
+			let lawyer = match lawyer {
+				Some(lawyer) => lawyer,
+				None => return Ok(()),
+			};
+			Self::release_lawyer(&lawyer);
+			property_lawyer_details.review_timeouts =
+				property_lawyer_details.review_timeouts.saturating_add(1);
+			Self::deposit_event(Event::<T>::LawyerReviewTimedOut { lawyer, listing_id, legal_side });
+			if property_lawyer_details.review_timeouts >= T::MaxReviewTimeouts::get() {
+				PropertyLawyer::<T>::remove(listing_id);
+				Self::burn_tokens_and_nfts(listing_id)?;
+				Self::refund_investors(listing_id, property_lawyer_details)?;
+				Self::deposit_event(Event::<T>::LegalProcessTimedOut { listing_id });
+			} else {
+				PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
 			}
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		/// Get the account id of the pallet
-		pub fn account_id() -> AccountIdOf<T> {
-			<T as pallet::Config>::PalletId::get().into_account_truncating()
+		/// Stores the soft cap of a listing and adds the listing to the listings that end at the deadline.
+		fn set_soft_cap(
+			listing_id: ListingId,
+			mut soft_cap: SoftCap<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(
+				!soft_cap.minimum_sold.is_zero()
+					&& soft_cap.deadline > <frame_system::Pallet<T>>::block_number()
+					&& soft_cap.deadline <= nft_details.listing_expiry,
+				Error::<T>::InvalidSoftCap
+			);
+			soft_cap.deadline = Self::push_to_free_block::<SoftCapsEnding<T>, _, _>(
+				soft_cap.deadline,
+				listing_id,
+				Error::<T>::TooManySoftCaps,
+			)?;
+			SoftCaps::<T>::insert(listing_id, soft_cap);
+			Ok(())
 		}
 
-		/// Get the account id of the treasury pallet
-		pub fn treasury_account_id() -> AccountIdOf<T> {
-			T::TreasuryId::get().into_account_truncating()
+		/// Starts the legal process with the sold token if the soft cap of a listing is reached.
+		/// Otherwise the nft gets burned and the investors get refunded.
+		pub(crate) fn finish_soft_cap(listing_id: ListingId) -> DispatchResult {
+			let soft_cap = match SoftCaps::<T>::take(listing_id) {
+				Some(soft_cap) => soft_cap,
+				None => return Ok(()),
+			};
+			// the listing has already been sold out or closed.
+			let listed_token = match ListedToken::<T>::take(listing_id) {
+				Some(listed_token) => listed_token,
+				None => return Ok(()),
+			};
+			let nft_details =
+				OngoingObjectListing::<T>::get(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			let sold_token = nft_details
+				.token_amount
+				.checked_sub(listed_token)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			if sold_token > 0 && sold_token >= soft_cap.minimum_sold.mul_ceil(nft_details.token_amount) {
+				Self::start_legal_process(listing_id);
+				Self::deposit_event(Event::<T>::SoftCapReached { listing_id, sold_token });
+			} else {
+				Self::burn_tokens_and_nfts(listing_id)?;
+				Self::refund_unsold_listing(listing_id, false)?;
+				Self::deposit_event(Event::<T>::SoftCapMissed { listing_id, sold_token });
+			}
+			Ok(())
 		}
 
-		/// Get the account id of the community pallet
-		pub fn community_account_id() -> AccountIdOf<T> {
-			T::CommunityProjectsId::get().into_account_truncating()
+		/// Hands a disputed legal case to the arbiter.
+		fn start_arbitration(
+			listing_id: ListingId,
+			property_lawyer_details: PropertyLawyerDetails<T>,
+		) -> DispatchResult {
+			let deadline = <frame_system::Pallet<T>>::block_number()
+				.checked_add(&T::ArbitrationPeriod::get())
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let deadline = Self::push_to_free_block::<ArbitrationsExpiring<T>, _, _>(
+				deadline,
+				listing_id,
+				Error::<T>::TooManyArbitrations,
+			)?;
+			Arbitrations::<T>::insert(listing_id, deadline);
+			PropertyLawyer::<T>::insert(listing_id, property_lawyer_details);
+			Self::deposit_event(Event::<T>::ArbitrationStarted { listing_id, deadline });
+			Ok(())
 		}
 
-		pub fn next_listing_id(listing_id: ListingId) -> Result<ListingId, Error<T>> {
-			listing_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)
+		/// Refunds the investors of a disputed legal case that has not been resolved until the deadline.
+		pub(crate) fn expire_arbitration(
+			listing_id: ListingId,
+			block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			if Arbitrations::<T>::get(listing_id) != Some(block) {
+				return Ok(());
+			}
+			Arbitrations::<T>::remove(listing_id);
+			let property_lawyer_details =
+				PropertyLawyer::<T>::take(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			Self::burn_tokens_and_nfts(listing_id)?;
+			Self::refund_investors(listing_id, property_lawyer_details)?;
+			Self::deposit_event(Event::<T>::ArbitrationExpired { listing_id });
+			Ok(())
+		}
+
+		/// Decreases the active cases of a lawyer once the lawyer is no longer on a case.
+		fn release_lawyer(lawyer: &AccountIdOf<T>) {
+			RealEstateLawyer::<T>::mutate(lawyer, |maybe_details| {
+				if let Some(details) = maybe_details {
+					details.active_cases = details.active_cases.saturating_sub(1);
+				}
+			});
+		}
+
+		/// Releases both lawyers of a case once the case is closed.
+		fn release_lawyers(property_lawyer_details: &PropertyLawyerDetails<T>) {
+			if let Some(lawyer) = &property_lawyer_details.real_estate_developer_lawyer {
+				Self::release_lawyer(lawyer);
+			}
+			if let Some(lawyer) = &property_lawyer_details.spv_lawyer {
+				Self::release_lawyer(lawyer);
+			}
 		}
 
 		/// Sends the token to the new owners and the funds to the real estate developer once all 100 token
 		/// of a collection are sold.
 		fn execute_deal(listing_id: u32, property_lawyer_details: PropertyLawyerDetails<T>) -> DispatchResult {
+			Self::release_lawyers(&property_lawyer_details);
 			let list = <TokenBuyer<T>>::take(listing_id);
 			let pallet_account = Self::account_id();
 			let nft_details =
 				OngoingObjectListing::<T>::take(listing_id).ok_or(Error::<T>::InvalidIndex)?;
 			let price = nft_details.collected_funds;
 			let treasury_id = Self::treasury_account_id();
+			let treasury_part = FeeSchedule::<T>::get().primary.treasury_fee.mul_floor(price);
 			let seller_part = price
-				.checked_mul(&Self::u64_to_balance_option(99)?)
-				.ok_or(Error::<T>::MultiplyError)?
-				.checked_div(&Self::u64_to_balance_option(100)?)
-				.ok_or(Error::<T>::DivisionError)?;
+				.checked_sub(&treasury_part)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
 			let tax = nft_details.collected_tax;
-			let treasury_fees = price
-				.checked_div(&Self::u64_to_balance_option(100)?)
-				.ok_or(Error::<T>::DivisionError)?
+			let treasury_fees = treasury_part
 				.checked_add(&nft_details.collected_fees)
 				.ok_or(Error::<T>::ArithmeticOverflow)?
 				.checked_sub(&property_lawyer_details.real_estate_developer_lawyer_costs)
 				.ok_or(Error::<T>::ArithmeticUnderflow)?
 				.checked_sub(&property_lawyer_details.spv_lawyer_costs)
 				.ok_or(Error::<T>::ArithmeticUnderflow)?;
-			//Self::transfer_funds(sender.clone(), treasury_id, fees)?;
 			let real_estate_developer_lawyer_id = match property_lawyer_details.real_estate_developer_lawyer {
 				Some(account_id) => account_id,
 				None => return Err(Error::<T>::LawyerNotFound.into()),
@@ -1436,12 +3989,14 @@ pub mod pallet {
 				.checked_add(&property_lawyer_details.real_estate_developer_lawyer_costs)
 				.ok_or(Error::<T>::ArithmeticOverflow)?;
 
-			Self::transfer_funds(pallet_account.clone(), real_estate_developer_lawyer_id, real_estate_developer_part)?;
-			Self::transfer_funds(pallet_account.clone(), spv_lawyer_id, property_lawyer_details.spv_lawyer_costs)?;
-			Self::transfer_funds(pallet_account.clone(), treasury_id, treasury_fees)?;
-			Self::transfer_funds(pallet_account.clone(), nft_details.real_estate_developer, seller_part)?;
+			let payment_asset = nft_details.payment_asset;
+			Self::pay_out(real_estate_developer_lawyer_id, real_estate_developer_part, payment_asset)?;
+			Self::pay_out(spv_lawyer_id, property_lawyer_details.spv_lawyer_costs, payment_asset)?;
+			Self::transfer_funds(pallet_account.clone(), treasury_id, treasury_fees, payment_asset)?;
+			Self::pay_out(nft_details.real_estate_developer, seller_part, payment_asset)?;
 			let origin: OriginFor<T> = RawOrigin::Signed(pallet_account).into();
 			let asset_id: AssetId<T> = nft_details.asset_id.into();
+			let mut sold_token: u32 = 0;
 			for owner in list {
 				let user_lookup = <T::Lookup as StaticLookup>::unlookup(owner.clone());
 				let token_details: TokenOwnerDetails<AssetBalanceOf<T>> = TokenOwner::<T>::take(owner.clone(), listing_id);
@@ -1458,7 +4013,38 @@ pub mod pallet {
 					keys.try_push(owner.clone()).map_err(|_| Error::<T>::TooManyTokenBuyer)?;
 					Ok::<(), DispatchError>(())
 				})?;
-				PropertyOwnerToken::<T>::insert(nft_details.asset_id, owner, token_details.token_amount as u32)
+				PropertyOwnerToken::<T>::insert(nft_details.asset_id, owner, token_details.token_amount as u32);
+				sold_token = sold_token
+					.checked_add(token_details.token_amount as u32)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+			}
+			// the real estate developer keeps the unsold token of a listing with a soft cap.
+			let unsold_token = nft_details
+				.token_amount
+				.checked_sub(sold_token)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			if unsold_token > 0 {
+				let developer = nft_details.real_estate_developer.clone();
+				let token_amount = unsold_token.try_into().map_err(|_| Error::<T>::ConversionError)?;
+				pallet_assets::Pallet::<T, Instance1>::transfer(
+					origin,
+					asset_id.into().into(),
+					<T::Lookup as StaticLookup>::unlookup(developer.clone()),
+					token_amount,
+				)
+				.map_err(|_| Error::<T>::NotEnoughFunds)?;
+				let owned_token = PropertyOwnerToken::<T>::get(nft_details.asset_id, developer.clone());
+				if owned_token == 0 {
+					PropertyOwner::<T>::try_mutate(nft_details.asset_id, |keys| {
+						keys.try_push(developer.clone()).map_err(|_| Error::<T>::TooManyTokenBuyer)?;
+						Ok::<(), DispatchError>(())
+					})?;
+				}
+				PropertyOwnerToken::<T>::insert(
+					nft_details.asset_id,
+					developer,
+					owned_token.checked_add(unsold_token).ok_or(Error::<T>::ArithmeticOverflow)?,
+				);
 			}
 			let mut registered_nft_details =
 				RegisteredNftDetails::<T>::get(nft_details.collection_id, nft_details.item_id)
@@ -1500,11 +4086,13 @@ pub mod pallet {
 			});
 			RegisteredNftDetails::<T>::take(nft_details.collection_id, nft_details.item_id)
 				.ok_or(Error::<T>::InvalidIndex)?;
+			AssetIdDetails::<T>::remove(nft_details.asset_id);
+			InvestorLimits::<T>::remove(nft_details.asset_id);
 			Ok(())
 		}
 
 		fn refund_investors(listing_id: ListingId, property_lawyer_details: PropertyLawyerDetails<T>) -> DispatchResult {
-			let list = <TokenBuyer<T>>::take(listing_id);
+			Self::release_lawyers(&property_lawyer_details);
 			let pallet_account = Self::account_id();
 			let nft_details =
 				OngoingObjectListing::<T>::take(listing_id).ok_or(Error::<T>::InvalidIndex)?;
@@ -1513,18 +4101,59 @@ pub mod pallet {
 			let treasury_amount = fees
 				.checked_sub(&property_lawyer_details.spv_lawyer_costs)
 				.ok_or(Error::<T>::ArithmeticUnderflow)?;
-			Self::transfer_funds(pallet_account.clone(), treasury_id, treasury_amount)?;
-			let spv_lawyer_id = match property_lawyer_details.spv_lawyer {
-				Some(account_id) => account_id,
-				None => return Err(Error::<T>::LawyerNotFound.into()),
-			};
-			Self::transfer_funds(pallet_account.clone(), spv_lawyer_id, property_lawyer_details.spv_lawyer_costs)?;
+			Self::transfer_funds(
+				pallet_account,
+				treasury_id,
+				treasury_amount,
+				nft_details.payment_asset,
+			)?;
+			// the spv lawyer might have been removed after a review timeout.
+			if let Some(spv_lawyer_id) = property_lawyer_details.spv_lawyer {
+				Self::pay_out(
+					spv_lawyer_id,
+					property_lawyer_details.spv_lawyer_costs,
+					nft_details.payment_asset,
+				)?;
+			}
+			Self::refund_token_buyers(listing_id, &nft_details, false)
+		}
+
+		/// Refunds the investors of a listing that has not been sold out.
+		/// If `refund_fees` is set, the investors get their fees back, otherwise the collected
+		/// fees are sent to the treasury.
+		fn refund_unsold_listing(listing_id: ListingId, refund_fees: bool) -> DispatchResult {
+			let nft_details =
+				OngoingObjectListing::<T>::take(listing_id).ok_or(Error::<T>::InvalidIndex)?;
+			if !refund_fees {
+				Self::transfer_funds(
+					Self::account_id(),
+					Self::treasury_account_id(),
+					nft_details.collected_fees,
+					nft_details.payment_asset,
+				)?;
+			}
+			Self::refund_token_buyers(listing_id, &nft_details, refund_fees)
+		}
+
+		/// Returns the paid funds and tax to every buyer of a listing. The paid fees are only
+		/// returned if `refund_fees` is set.
+		fn refund_token_buyers(
+			listing_id: ListingId,
+			nft_details: &NftListingDetailsType<T>,
+			refund_fees: bool,
+		) -> DispatchResult {
+			let list = <TokenBuyer<T>>::take(listing_id);
 			for owner in list {
 				let token_details: TokenOwnerDetails<AssetBalanceOf<T>> = TokenOwner::<T>::take(owner.clone(), listing_id);
-				let refund_amount = token_details.paid_funds
+				let mut refund_amount = token_details.paid_funds
 					.checked_add(&token_details.paid_tax)
 					.ok_or(Error::<T>::ArithmeticOverflow)?;
-				Self::transfer_funds(pallet_account.clone(), owner.clone(), refund_amount)?;
+				if refund_fees {
+					refund_amount = refund_amount
+						.checked_add(&token_details.paid_fee)
+						.ok_or(Error::<T>::ArithmeticOverflow)?;
+				}
+				Self::pay_out(owner.clone(), refund_amount, nft_details.payment_asset)?;
 				PropertyOwner::<T>::take(nft_details.asset_id);
 				PropertyOwnerToken::<T>::take(nft_details.asset_id, owner);
 			}
@@ -1536,10 +4165,33 @@ pub mod pallet {
 			transfer_from: AccountIdOf<T>,
 			account: AccountIdOf<T>,
 			mut listing_details: ListingDetailsType<T>,
+			token_price: AssetBalanceOf<T>,
 			price: AssetBalanceOf<T>,
+			fees: AssetBalanceOf<T>,
 			amount: u32,
 		) -> DispatchResult {
-			Self::calculate_fees(price, transfer_from.clone(), listing_details.seller.clone())?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&account)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&listing_details.seller)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(
+					listing_details.seller.clone()
+				),
+				Error::<T>::SellerNotWhitelisted
+			);
+			Self::ensure_holding_limits(
+				listing_details.asset_id,
+				PropertyOwnerToken::<T>::get(listing_details.asset_id, account.clone())
+					.checked_add(amount)
+					.ok_or(Error::<T>::ArithmeticOverflow)?,
+			)?;
+			Self::record_investment(account.clone(), price, listing_details.payment_asset)?;
+			Self::calculate_fees(
+				price,
+				fees,
+				transfer_from.clone(),
+				listing_details.seller.clone(),
+				listing_details.payment_asset,
+			)?;
 			let user_lookup = <T::Lookup as StaticLookup>::unlookup(account.clone());
 			let asset_id: AssetId<T> = listing_details.asset_id.into();
 			let token_amount = amount.into();
@@ -1551,79 +4203,183 @@ pub mod pallet {
 				token_amount,
 			)
 			.map_err(|_| Error::<T>::NotEnoughFunds)?;
-			let mut old_token_owner_amount = PropertyOwnerToken::<T>::take(
+			Self::move_property_ownership(
 				listing_details.asset_id,
 				listing_details.seller.clone(),
-			);
+				account.clone(),
+				amount,
+			)?;
+			listing_details.amount = listing_details
+				.amount
+				.checked_sub(amount)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			if listing_details.amount > 0 {
+				TokenListings::<T>::insert(listing_id, listing_details.clone());
+			} else {
+				Self::close_token_listing(listing_id);
+			}
+			Self::deposit_event(Event::<T>::TokenBought {
+				asset_id: listing_details.asset_id,
+				buyer: account.clone(),
+				price: token_price,
+			});
+			Ok(())
+		}
+
+		/// Moves the ownership of property token in `PropertyOwner` and `PropertyOwnerToken`.
+		fn move_property_ownership(
+			asset_id: u32,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+			amount: u32,
+		) -> DispatchResult {
+			let mut old_token_owner_amount = PropertyOwnerToken::<T>::take(asset_id, from.clone());
 			old_token_owner_amount = old_token_owner_amount
 				.checked_sub(amount)
 				.ok_or(Error::<T>::ArithmeticUnderflow)?;
 			if old_token_owner_amount == 0 {
-				let mut owner_list = PropertyOwner::<T>::take(listing_details.asset_id);
+				let mut owner_list = PropertyOwner::<T>::take(asset_id);
 				let index = owner_list
 					.iter()
-					.position(|x| *x == listing_details.seller.clone())
+					.position(|x| *x == from.clone())
 					.ok_or(Error::<T>::InvalidIndex)?;
 				owner_list.remove(index);
-				PropertyOwner::<T>::insert(listing_details.asset_id, owner_list);
+				PropertyOwner::<T>::insert(asset_id, owner_list);
 			} else {
-				PropertyOwnerToken::<T>::insert(
-					listing_details.asset_id,
-					listing_details.seller.clone(),
-					old_token_owner_amount,
-				);
+				PropertyOwnerToken::<T>::insert(asset_id, from.clone(), old_token_owner_amount);
 			}
-			if PropertyOwner::<T>::get(listing_details.asset_id).contains(&account) {
-				let mut buyer_token_amount =
-					PropertyOwnerToken::<T>::take(listing_details.asset_id, account.clone());
+			if PropertyOwner::<T>::get(asset_id).contains(&to) {
+				let mut buyer_token_amount = PropertyOwnerToken::<T>::take(asset_id, to.clone());
 				buyer_token_amount =
 					buyer_token_amount.checked_add(amount).ok_or(Error::<T>::ArithmeticOverflow)?;
-				PropertyOwnerToken::<T>::insert(
-					listing_details.asset_id,
-					account.clone(),
-					buyer_token_amount,
-				);
+				PropertyOwnerToken::<T>::insert(asset_id, to.clone(), buyer_token_amount);
 			} else {
-				PropertyOwner::<T>::try_mutate(listing_details.asset_id, |keys| {
-					keys.try_push(account.clone()).map_err(|_| Error::<T>::TooManyTokenBuyer)?;
+				PropertyOwner::<T>::try_mutate(asset_id, |keys| {
+					keys.try_push(to.clone()).map_err(|_| Error::<T>::TooManyTokenBuyer)?;
 					Ok::<(), DispatchError>(())
 				})?;
-				PropertyOwnerToken::<T>::insert(listing_details.asset_id, account.clone(), amount);
+				PropertyOwnerToken::<T>::insert(asset_id, to, amount);
 			}
-			listing_details.amount = listing_details
-				.amount
-				.checked_sub(amount)
-				.ok_or(Error::<T>::ArithmeticUnderflow)?;
-			if listing_details.amount > 0 {
-				TokenListings::<T>::insert(listing_id, listing_details.clone());
+			Ok(())
+		}
+
+		/// Marks a closed token listing so that its remaining offers get refunded.
+		fn close_token_listing(listing_id: ListingId) {
+			if OngoingOffers::<T>::iter_prefix(listing_id).next().is_some() {
+				ClosedListings::<T>::insert(listing_id, true);
 			}
-			Self::deposit_event(Event::<T>::TokenBought {
-				asset_id: listing_details.asset_id,
-				buyer: account.clone(),
-				price: listing_details.token_price,
+		}
+
+		/// Refunds an offer if it is still open and expires in this block.
+		pub(crate) fn expire_offer(
+			listing_id: ListingId,
+			offeror: AccountIdOf<T>,
+			block_number: BlockNumberFor<T>,
+		) {
+			let offer_details = match OngoingOffers::<T>::get(listing_id, offeror.clone()) {
+				Some(offer_details) if offer_details.expiry == block_number => offer_details,
+				_ => return,
+			};
+			OngoingOffers::<T>::remove(listing_id, offeror.clone());
+			if Self::try_refund_offer(listing_id, offeror.clone(), offer_details) {
+				Self::deposit_event(Event::<T>::OfferExpired { listing_id, account_id: offeror });
+			}
+		}
+
+		/// Refunds up to `limit` offers of a closed listing and returns the amount of processed
+		/// offers.
+		pub(crate) fn refund_offers(listing_id: ListingId, limit: u32) -> u32 {
+			let mut refunded_offers: u32 = 0;
+			for (offeror, offer_details) in
+				OngoingOffers::<T>::drain_prefix(listing_id).take(limit as usize)
+			{
+				if Self::try_refund_offer(listing_id, offeror.clone(), offer_details) {
+					Self::deposit_event(Event::<T>::OfferRefunded { listing_id, account_id: offeror });
+				}
+				refunded_offers = refunded_offers.saturating_add(1);
+			}
+			refunded_offers
+		}
+
+		/// Refunds an offer that has been removed from the ongoing offers and returns true on
+		/// success. If the refund fails, the offer is stored in `FailedOfferRefunds` so that the
+		/// offeror can claim the refund with `cancel_offer`.
+		fn try_refund_offer(
+			listing_id: ListingId,
+			offeror: AccountIdOf<T>,
+			offer_details: OfferDetails<AssetBalanceOf<T>, T>,
+		) -> bool {
+			let result = frame_support::storage::with_storage_layer(|| {
+				Self::refund_offer(offer_details.clone())
 			});
+			if result.is_err() {
+				FailedOfferRefunds::<T>::insert(listing_id, offeror.clone(), offer_details);
+				Self::deposit_event(Event::<T>::OfferRefundFailed { listing_id, account_id: offeror });
+			}
+			result.is_ok()
+		}
+
+		/// Sends the token of a removed token listing back to the seller and closes the listing.
+		fn return_listed_token(
+			listing_id: ListingId,
+			listing_details: ListingDetailsType<T>,
+		) -> DispatchResult {
+			let user_lookup = <T::Lookup as StaticLookup>::unlookup(listing_details.seller);
+			let asset_id: AssetId<T> = listing_details.asset_id.into();
+			let token_amount = listing_details.amount.into();
+			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+			pallet_assets::Pallet::<T, Instance1>::transfer(
+				pallet_origin,
+				asset_id.into().into(),
+				user_lookup,
+				token_amount,
+			)
+			.map_err(|_| Error::<T>::NotEnoughFunds)?;
+			Self::close_token_listing(listing_id);
+			Self::deposit_event(Event::<T>::ListingDelisted { listing_index: listing_id });
 			Ok(())
 		}
 
+		/// Sends the escrowed funds of an offer back to the offeror.
+		fn refund_offer(offer_details: OfferDetails<AssetBalanceOf<T>, T>) -> DispatchResult {
+			let amount = offer_details.get_escrowed_amount()?;
+			Self::pay_out(offer_details.buyer, amount, offer_details.payment_asset)
+		}
+
+		/// Sends the treasury part of a secondary sale and the fees paid by the buyer to the
+		/// treasury and the rest of the price to the seller.
 		fn calculate_fees(
 			price: AssetBalanceOf<T>,
+			buyer_fees: AssetBalanceOf<T>,
 			sender: AccountIdOf<T>,
 			receiver: AccountIdOf<T>,
+			payment_asset: u32,
 		) -> DispatchResult {
-			let fees = price
-				.checked_div(&Self::u64_to_balance_option(100)?)
-				.ok_or(Error::<T>::DivisionError)?;
+			let treasury_part = FeeSchedule::<T>::get().secondary.treasury_fee.mul_floor(price);
 			let treasury_id = Self::treasury_account_id();
 			let seller_part = price
-				.checked_mul(&Self::u64_to_balance_option(99)?)
-				.ok_or(Error::<T>::MultiplyError)?
-				.checked_div(&Self::u64_to_balance_option(100)?)
-				.ok_or(Error::<T>::DivisionError)?;
-			Self::transfer_funds(sender.clone(), treasury_id, fees)?;
-			Self::transfer_funds(sender, receiver, seller_part)?;
+				.checked_sub(&treasury_part)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?;
+			let fees = treasury_part
+				.checked_add(&buyer_fees)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			Self::transfer_funds(sender.clone(), treasury_id, fees, payment_asset)?;
+			Self::transfer_funds(sender, receiver, seller_part, payment_asset)?;
 			Ok(())
 		}
 
+		/// Calculates the fee and tax that a buyer pays on top of the price.
+		fn calculate_buyer_fees(
+			price: AssetBalanceOf<T>,
+			fee_details: &FeeDetails,
+		) -> Result<AssetBalanceOf<T>, Error<T>> {
+			fee_details
+				.buyer_fee
+				.mul_floor(price)
+				.checked_add(&fee_details.tax.mul_floor(price))
+				.ok_or(Error::<T>::ArithmeticOverflow)
+		}
+
 		/// Set the default collection configuration for creating a collection.
 		fn default_collection_config() -> CollectionConfig<
 			CurrencyBalanceOf<T>,
@@ -1659,33 +4415,270 @@ pub mod pallet {
 			input.try_into().map_err(|_| Error::<T>::ConversionError)
 		}
 
+		/// Pays out funds held by the pallet account. The funds owed to a blocked account stay in
+		/// the pallet account and are put into quarantine instead.
+		fn pay_out(
+			to: AccountIdOf<T>,
+			amount: AssetBalanceOf<T>,
+			payment_asset: u32,
+		) -> DispatchResult {
+			if pallet_xcavate_whitelist::Pallet::<T>::is_blocked(&to) {
+				QuarantinedFunds::<T>::try_mutate(to.clone(), payment_asset, |funds| {
+					*funds = funds.checked_add(&amount).ok_or(Error::<T>::ArithmeticOverflow)?;
+					Ok::<(), DispatchError>(())
+				})?;
+				Self::deposit_event(Event::<T>::FundsQuarantined {
+					account: to,
+					payment_asset,
+					amount,
+				});
+				return Ok(());
+			}
+			Self::transfer_funds(Self::account_id(), to, amount, payment_asset)
+		}
+
+		/// Transfers funds in the payment asset of a listing.
 		fn transfer_funds(
 			from: AccountIdOf<T>,
 			to: AccountIdOf<T>,
 			amount: AssetBalanceOf<T>,
+			payment_asset: u32,
 		) -> DispatchResult {
-			let u32_amunt =
-				TryInto::<u32>::try_into(amount).map_err(|_| Error::<T>::ConversionError)?;
 			let origin: OriginFor<T> = RawOrigin::Signed(from).into();
 			let account_lookup = <T::Lookup as StaticLookup>::unlookup(to);
-			let asset_id: AssetId<T> = 1.into();
-			let token_amount = u32_amunt.into();
+			let asset_id: AssetId<T> = payment_asset.into();
 			Ok(pallet_assets::Pallet::<T, Instance1>::transfer(
 				origin,
 				asset_id.into().into(),
 				account_lookup,
-				token_amount,
+				amount,
 			)
 			.map_err(|_| Error::<T>::NotEnoughFunds)?)
 		}
+
+		/// Returns up to `limit` active listings of a region, starting at the listing id `start`.
+		/// If `location` is set, only the listings of this location are returned.
+		/// At most `MAX_LISTINGS_PER_PAGE` listings are returned and at most
+		/// `MAX_SCANNED_LISTINGS` listing ids are searched, the search continues at `next`.
+		pub fn listings(
+			region: RegionId,
+			location: Option<sp_std::vec::Vec<u8>>,
+			start: ListingId,
+			limit: u32,
+		) -> ListingPageOf<T> {
+			let limit = limit.min(MAX_LISTINGS_PER_PAGE) as usize;
+			let next_listing_id = NextListingId::<T>::get();
+			let end = next_listing_id.min(start.saturating_add(MAX_SCANNED_LISTINGS));
+			let mut listings = sp_std::vec::Vec::new();
+			let mut next = start;
+			while next < end && listings.len() < limit {
+				if let Some(listing) = Self::listing(next).filter(|listing| {
+					listing.region == region &&
+						location.as_ref().map_or(true, |location| *location == listing.location)
+				}) {
+					listings.push(listing);
+				}
+				next = next.saturating_add(1);
+			}
+			ListingPage { listings, next: (next < next_listing_id).then_some(next) }
+		}
+
+		/// Returns an active listing with its remaining token.
+		pub fn listing(listing_id: ListingId) -> Option<ListingInfoOf<T>> {
+			if let Some(remaining_token) = ListedToken::<T>::get(listing_id) {
+				let nft_details = OngoingObjectListing::<T>::get(listing_id)?;
+				let asset_details = AssetIdDetails::<T>::get(nft_details.asset_id)?;
+				return Some(ListingInfo {
+					listing_id,
+					listing_type: ListingType::Object,
+					seller: nft_details.real_estate_developer,
+					asset_id: nft_details.asset_id,
+					region: asset_details.region,
+					location: asset_details.location.into_inner(),
+					token_price: nft_details.token_price,
+					remaining_token,
+					payment_asset: nft_details.payment_asset,
+					expiry: Some(nft_details.listing_expiry),
+				});
+			}
+			let listing_details = TokenListings::<T>::get(listing_id)?;
+			let asset_details = AssetIdDetails::<T>::get(listing_details.asset_id)?;
+			Some(ListingInfo {
+				listing_id,
+				listing_type: ListingType::Relisted,
+				token_price: Self::current_token_price(&listing_details).ok()?,
+				seller: listing_details.seller,
+				asset_id: listing_details.asset_id,
+				region: asset_details.region,
+				location: asset_details.location.into_inner(),
+				remaining_token: listing_details.amount,
+				payment_asset: listing_details.payment_asset,
+				expiry: None,
+			})
+		}
+
+		/// Returns the documents that the lawyers reviewed for a property, ordered by review round.
+		pub fn legal_documents(listing_id: ListingId) -> sp_std::vec::Vec<LegalDocumentsInfoOf<T>> {
+			let mut documents: sp_std::vec::Vec<LegalDocumentsInfoOf<T>> =
+				LegalDocuments::<T>::iter_prefix((listing_id,))
+					.map(|((legal_side, round), review)| LegalDocumentsInfo {
+						legal_side,
+						round,
+						lawyer: review.lawyer,
+						document_hashes: review.document_hashes.into_inner(),
+						approved: review.approved,
+						reason: review.reason,
+						reviewed_at: review.reviewed_at,
+					})
+					.collect();
+			documents.sort_by_key(|info| {
+				(info.round, info.legal_side != LegalProperty::RealEstateDeveloperSide)
+			});
+			documents
+		}
+
+		/// Returns all offers on a listing.
+		pub fn offers(listing_id: ListingId) -> sp_std::vec::Vec<OfferInfoOf<T>> {
+			OngoingOffers::<T>::iter_prefix(listing_id)
+				.map(|(offeror, offer_details)| OfferInfo {
+					offeror,
+					token_price: offer_details.token_price,
+					amount: offer_details.amount,
+					fees: offer_details.fees,
+					payment_asset: offer_details.payment_asset,
+					expiry: offer_details.expiry,
+					counter_price: match offer_details.state {
+						OfferState::Countered { token_price } => Some(token_price),
+						OfferState::Pending => None,
+					},
+				})
+				.collect()
+		}
+
+		/// Returns the token of an account, both from pending purchases and settled properties.
+		pub fn holdings(account: AccountIdOf<T>) -> sp_std::vec::Vec<HoldingInfo<AssetBalanceOf<T>>> {
+			let pending = TokenOwner::<T>::iter_prefix(&account).filter_map(|(listing_id, details)| {
+				let nft_details = OngoingObjectListing::<T>::get(listing_id)?;
+				Some(HoldingInfo {
+					asset_id: nft_details.asset_id,
+					token_amount: details.token_amount,
+					status: HoldingStatus::Pending {
+						listing_id,
+						paid_funds: details.paid_funds,
+						paid_tax: details.paid_tax,
+					},
+				})
+			});
+			let settled = AssetIdDetails::<T>::iter_keys().filter_map(|asset_id| {
+				let token_amount = PropertyOwnerToken::<T>::get(asset_id, &account);
+				(token_amount > 0).then_some(HoldingInfo {
+					asset_id,
+					token_amount,
+					status: HoldingStatus::Settled,
+				})
+			});
+			pending.chain(settled).collect()
+		}
+	}
+}
+
+impl<T: Config> pallet_xcavate_whitelist::OnWhitelistRemoval<AccountIdOf<T>> for Pallet<T> {
+	/// The settled token of a removed account are frozen: the runtime filters direct transfers
+	/// of property token, and the marketplace only relists, sells and transfers the token of
+	/// whitelisted accounts that are not blocked. Bids of the account are not filled anymore.
+	/// Its listings, offers, bids and pending purchases get closed with `enforce_compliance`
+	/// and the governance does not count its votes. Funds that the marketplace owes a blocked
+	/// account are put into quarantine.
+	fn on_removal(account: &AccountIdOf<T>) {
+		Self::deposit_event(Event::<T>::ComplianceCheckRequired { account: account.clone() });
+	}
+}
+
+/// The maximum amount of listings that the runtime api returns at once.
+pub const MAX_LISTINGS_PER_PAGE: u32 = 100;
+
+/// The maximum amount of listing ids that the runtime api searches for listings at once.
+pub const MAX_SCANNED_LISTINGS: u32 = 1_000;
+
+/// Serializes balances as strings in the results of the runtime apis, since JSON numbers can
+/// not represent every `u128`.
+#[cfg(feature = "std")]
+pub mod serde_balance {
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer, T: std::fmt::Display>(
+		t: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&t.to_string())
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		s.parse::<T>().map_err(|_| serde::de::Error::custom("Parse from string failed"))
+	}
+
+	/// Serializes optional balances as strings.
+	pub mod option {
+		use serde::{Deserialize, Deserializer, Serializer};
+
+		pub fn serialize<S: Serializer, T: std::fmt::Display>(
+			t: &Option<T>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			match t {
+				Some(t) => serializer.serialize_some(&t.to_string()),
+				None => serializer.serialize_none(),
+			}
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+			deserializer: D,
+		) -> Result<Option<T>, D::Error> {
+			Option::<String>::deserialize(deserializer)?
+				.map(|s| {
+					s.parse::<T>().map_err(|_| serde::de::Error::custom("Parse from string failed"))
+				})
+				.transpose()
+		}
 	}
 }
 
 sp_api::decl_runtime_apis! {
-    pub trait NftMarketplaceApi<AccountId> 
+	/// Version 2 adds the queries for listings, offers, holdings and legal documents.
+	#[api_version(2)]
+    pub trait NftMarketplaceApi<AccountId, Balance, BlockNumber, Hash> 
 	where
-		AccountId: Codec
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Hash: Codec,
 	{
         fn get_marketplace_account_id() -> AccountId;
+
+		/// Returns up to `limit` active listings of a region, starting at the listing id `start`.
+		/// If `location` is set, only the listings of this location are returned.
+		fn get_listings(
+			region: u32,
+			location: Option<sp_std::vec::Vec<u8>>,
+			start: u32,
+			limit: u32,
+		) -> ListingPage<AccountId, Balance, BlockNumber>;
+
+		/// Returns an active listing with its remaining token.
+		fn get_listing(listing_id: u32) -> Option<ListingInfo<AccountId, Balance, BlockNumber>>;
+
+		/// Returns all offers on a listing.
+		fn get_offers(listing_id: u32) -> sp_std::vec::Vec<OfferInfo<AccountId, Balance, BlockNumber>>;
+
+		/// Returns the pending and settled token of an account.
+		fn get_holdings(account: AccountId) -> sp_std::vec::Vec<HoldingInfo<Balance>>;
+
+		/// Returns the documents that the lawyers reviewed for a property.
+		fn get_legal_documents(
+			listing_id: u32,
+		) -> sp_std::vec::Vec<LegalDocumentsInfo<AccountId, Hash, BlockNumber>>;
     }
 }
\ No newline at end of file
@@ -0,0 +1,242 @@
+//! Storage migrations for the nft-marketplace pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::One,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+/// Migrates the listings and offers from the layout before the payment assets were introduced.
+pub mod v1 {
+	use super::*;
+
+	/// The asset that all listings and offers were paid with before the payment assets were
+	/// introduced.
+	pub const LEGACY_PAYMENT_ASSET: u32 = 1;
+
+	/// Infos regarding the listing of a real estate object in storage version 0.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldNftListingDetails<Balance, ItemId, CollectionId, AccountId> {
+		pub real_estate_developer: AccountId,
+		pub token_price: Balance,
+		pub collected_funds: Balance,
+		pub collected_tax: Balance,
+		pub collected_fees: Balance,
+		pub asset_id: u32,
+		pub item_id: ItemId,
+		pub collection_id: CollectionId,
+		pub token_amount: u32,
+	}
+
+	/// Infos regarding the listing of a token in storage version 0.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldTokenListingDetails<Balance, ItemId, CollectionId, AccountId> {
+		pub seller: AccountId,
+		pub token_price: Balance,
+		pub asset_id: u32,
+		pub item_id: ItemId,
+		pub collection_id: CollectionId,
+		pub amount: u32,
+	}
+
+	/// Infos regarding an offer in storage version 0.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldOfferDetails<Balance, AccountId> {
+		pub buyer: AccountId,
+		pub token_price: Balance,
+		pub amount: u32,
+	}
+
+	/// Infos regarding the token that an investor bought from a listed object in storage
+	/// version 0.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldTokenOwnerDetails<Balance> {
+		pub token_amount: u32,
+		pub paid_funds: Balance,
+		pub paid_tax: Balance,
+	}
+
+	/// Infos regarding the lawyers of a property in storage version 0.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldPropertyLawyerDetails<AccountId, Balance> {
+		pub real_estate_developer_lawyer: Option<AccountId>,
+		pub spv_lawyer: Option<AccountId>,
+		pub real_estate_developer_status: DocumentStatus,
+		pub spv_status: DocumentStatus,
+		pub real_estate_developer_lawyer_costs: Balance,
+		pub spv_lawyer_costs: Balance,
+		pub second_attempt: bool,
+	}
+
+	pub type OldNftListingDetailsOf<T> = OldNftListingDetails<
+		AssetBalanceOf<T>,
+		<T as pallet::Config>::ItemId,
+		<T as pallet::Config>::CollectionId,
+		AccountIdOf<T>,
+	>;
+
+	pub type OldTokenListingDetailsOf<T> = OldTokenListingDetails<
+		AssetBalanceOf<T>,
+		<T as pallet::Config>::ItemId,
+		<T as pallet::Config>::CollectionId,
+		AccountIdOf<T>,
+	>;
+
+	pub type OldOfferDetailsOf<T> = OldOfferDetails<AssetBalanceOf<T>, AccountIdOf<T>>;
+
+	pub type OldPropertyLawyerDetailsOf<T> =
+		OldPropertyLawyerDetails<AccountIdOf<T>, AssetBalanceOf<T>>;
+
+	/// Adds the payment asset to all listings and offers, schedules the expiry of the
+	/// listed objects and offers that existed before and splits the collected fees of a listed
+	/// object between its buyers.
+	///
+	/// The registered lawyers get licensed for the existing regions without a bond, they have to
+	/// top up their bond with `top_up_lawyer_bond` before they can claim new cases.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let listing_expiry = current_block_number.saturating_add(T::ListingDuration::get());
+			let offer_expiry = current_block_number.saturating_add(T::OfferDuration::get());
+			let mut translated: u64 = 0;
+
+			OngoingObjectListing::<T>::translate::<OldNftListingDetailsOf<T>, _>(|_, old| {
+				translated.saturating_inc();
+				Some(NftListingDetails {
+					real_estate_developer: old.real_estate_developer,
+					token_price: old.token_price,
+					collected_funds: old.collected_funds,
+					collected_tax: old.collected_tax,
+					collected_fees: old.collected_fees,
+					asset_id: old.asset_id,
+					item_id: old.item_id,
+					collection_id: old.collection_id,
+					token_amount: old.token_amount,
+					payment_asset: LEGACY_PAYMENT_ASSET,
+					listing_expiry,
+				})
+			});
+
+			// the fees of a buyer have not been stored, they are split pro rata of the paid funds.
+			TokenOwner::<T>::translate::<OldTokenOwnerDetails<AssetBalanceOf<T>>, _>(
+				|_, listing_id, old| {
+					translated.saturating_inc();
+					let paid_fee = OngoingObjectListing::<T>::get(listing_id)
+						.and_then(|listing| {
+							listing
+								.collected_fees
+								.checked_mul(&old.paid_funds)?
+								.checked_div(&listing.collected_funds)
+						})
+						.unwrap_or_default();
+					Some(TokenOwnerDetails {
+						token_amount: old.token_amount,
+						paid_funds: old.paid_funds,
+						paid_tax: old.paid_tax,
+						paid_fee,
+					})
+				},
+			);
+
+			TokenListings::<T>::translate::<OldTokenListingDetailsOf<T>, _>(|_, old| {
+				translated.saturating_inc();
+				Some(TokenListingDetails {
+					seller: old.seller,
+					token_price: old.token_price,
+					asset_id: old.asset_id,
+					item_id: old.item_id,
+					collection_id: old.collection_id,
+					amount: old.amount,
+					payment_asset: LEGACY_PAYMENT_ASSET,
+					dutch_auction: None,
+				})
+			});
+
+			OngoingOffers::<T>::translate::<OldOfferDetailsOf<T>, _>(|listing_id, offeror, old| {
+				translated.saturating_inc();
+				// the escrow of the old offers did not include any fees.
+				let mut expiry = offer_expiry;
+				while OffersExpiring::<T>::try_mutate(expiry, |keys| {
+					keys.try_push((listing_id, offeror.clone()))
+				})
+				.is_err()
+				{
+					expiry = expiry.saturating_add(One::one());
+				}
+				Some(OfferDetails {
+					buyer: old.buyer,
+					token_price: old.token_price,
+					amount: old.amount,
+					fees: Default::default(),
+					payment_asset: LEGACY_PAYMENT_ASSET,
+					expiry,
+					state: OfferState::Pending,
+				})
+			});
+
+			// the cases of the lawyers are counted, so that the lawyers can't leave the registry
+			// while they are still on a case.
+			let mut active_cases: BTreeMap<AccountIdOf<T>, u32> = BTreeMap::new();
+			PropertyLawyer::<T>::translate::<OldPropertyLawyerDetailsOf<T>, _>(|_, old| {
+				translated.saturating_inc();
+				for lawyer in [&old.real_estate_developer_lawyer, &old.spv_lawyer].into_iter().flatten() {
+					active_cases.entry(lawyer.clone()).or_default().saturating_inc();
+				}
+				Some(PropertyLawyerDetails {
+					real_estate_developer_lawyer: old.real_estate_developer_lawyer,
+					spv_lawyer: old.spv_lawyer,
+					real_estate_developer_status: old.real_estate_developer_status,
+					spv_status: old.spv_status,
+					real_estate_developer_lawyer_costs: old.real_estate_developer_lawyer_costs,
+					spv_lawyer_costs: old.spv_lawyer_costs,
+					second_attempt: old.second_attempt,
+					real_estate_developer_deadline: None,
+					spv_deadline: None,
+					review_timeouts: 0,
+					review_round: if old.second_attempt { 2 } else { 1 },
+				})
+			});
+
+			let regions: BoundedVec<RegionId, T::MaxLawyerRegions> = BoundedVec::truncate_from(
+				RegionCollections::<T>::iter_keys()
+					.take(T::MaxLawyerRegions::get() as usize)
+					.collect(),
+			);
+			translated.saturating_accrue(regions.len() as u64);
+			RealEstateLawyer::<T>::translate::<bool, _>(|lawyer, registered| {
+				translated.saturating_inc();
+				registered.then(|| LawyerDetails {
+					firm_name_hash: Default::default(),
+					regions: regions.clone(),
+					bond: Zero::zero(),
+					active_cases: active_cases.get(&lawyer).copied().unwrap_or_default(),
+					unbonding_until: None,
+				})
+			});
+
+			PaymentAssets::<T>::insert(LEGACY_PAYMENT_ASSET, true);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(1, 2)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2).saturating_mul(translated))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(
+			_state: sp_std::vec::Vec<u8>,
+		) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"the storage version of the nft marketplace has not been updated"
+			);
+			Ok(())
+		}
+	}
+}
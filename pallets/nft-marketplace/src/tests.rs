@@ -1,11 +1,22 @@
 use crate::ItemId;
 use crate::{mock::*, Error};
 use frame_support::BoundedVec;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, OnFinalize, OnInitialize, OnRuntimeUpgrade, StorageVersion},
+};
+use crate::migrations::v1;
+use sp_runtime::{traits::BadOrigin, Percent};
+use sp_core::H256;
 use crate::{RegionCollections, LocationRegistration, ListedToken, NextNftId,
 	OngoingObjectListing, NextAssetId, RegisteredNftDetails, TokenOwner, TokenBuyer,
 	TokenListings, OngoingOffers, PropertyOwnerToken, PropertyOwner, PropertyLawyer,
-	RealEstateLawyer};
+	RealEstateLawyer, LawyerApplications, FeeSchedule, FeeDetails, SaleType, PaymentAssets, AssetIdDetails,
+	OffersExpiring, ClosedListings, ObjectAuctions, AuctionBids, ListingType, HoldingStatus,
+	ReviewDeadlines, LegalDocuments, Arbitrations, ArbitrationsExpiring, SoftCaps, SoftCapsEnding,
+	InvestorLimits, TransferFee, FailedOfferRefunds, FailedBidRefunds, QuarantinedFunds};
+use pallet_xcavate_whitelist::KycTier;
 
 macro_rules! bvec {
 	($( $x:tt )*) => {
@@ -13,6 +24,19 @@ macro_rules! bvec {
 	}
 }
 
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		if System::block_number() > 0 {
+			NftMarketplace::on_finalize(System::block_number());
+			System::on_finalize(System::block_number());
+		}
+		System::reset_events();
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+		NftMarketplace::on_initialize(System::block_number());
+	}
+}
+
 // create_new_region function
 #[test]
 fn create_new_region_works() {
@@ -80,9 +104,25 @@ fn create_new_location_fails() {
 fn register_lawyer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([0; 32].into()), false);
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([0; 32].into()), true);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).is_some(), false);
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0, 1]));
+		System::assert_last_event(
+			crate::Event::LawyerApplied { lawyer: [10; 32].into(), regions: bvec![0, 1] }.into(),
+		);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).is_some(), false);
+		assert_eq!(LawyerApplications::<Test>::get::<AccountId>([10; 32].into()).is_some(), true);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 1_000);
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_eq!(LawyerApplications::<Test>::get::<AccountId>([10; 32].into()).is_some(), false);
+		let lawyer_details = RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap();
+		assert_eq!(lawyer_details.regions.into_inner(), vec![0, 1]);
+		assert_eq!(lawyer_details.bond, 1_000);
+		assert_eq!(lawyer_details.active_cases, 0);
+		assert_eq!(lawyer_details.unbonding_until, None);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 1_000);
+		assert_eq!(Balances::free_balance(&([10; 32].into())), 9_000);
 	})
 }
 
@@ -90,8 +130,295 @@ fn register_lawyer_works() {
 fn register_lawyer_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [0; 32].into()));
-		assert_noop!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [0; 32].into()), Error::<Test>::LawyerAlreadyRegistered);
+		assert_noop!(
+			NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]),
+			Error::<Test>::RegionUnknown
+		);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_noop!(
+			NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()),
+			Error::<Test>::LawyerApplicationNotFound
+		);
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_noop!(
+			NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]),
+			Error::<Test>::LawyerAlreadyApplied
+		);
+		assert_noop!(
+			NftMarketplace::register_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into()),
+			BadOrigin
+		);
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_noop!(
+			NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]),
+			Error::<Test>::LawyerAlreadyRegistered
+		);
+		assert_noop!(
+			NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()),
+			Error::<Test>::LawyerApplicationNotFound
+		);
+	})
+}
+
+#[test]
+fn reject_lawyer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 1_000);
+		assert_noop!(
+			NftMarketplace::reject_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into()),
+			BadOrigin
+		);
+		assert_ok!(NftMarketplace::reject_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		System::assert_last_event(
+			crate::Event::LawyerApplicationRejected { lawyer: [10; 32].into() }.into(),
+		);
+		assert_eq!(LawyerApplications::<Test>::get::<AccountId>([10; 32].into()).is_some(), false);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).is_some(), false);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 0);
+		assert_eq!(Balances::free_balance(&([10; 32].into())), 10_000);
+		assert_noop!(
+			NftMarketplace::reject_lawyer(RuntimeOrigin::root(), [10; 32].into()),
+			Error::<Test>::LawyerApplicationNotFound
+		);
+	})
+}
+
+#[test]
+fn lawyer_claim_property_fails_without_license() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![1]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_noop!(
+			NftMarketplace::lawyer_claim_property(
+				RuntimeOrigin::signed([10; 32].into()),
+				0,
+				crate::LegalProperty::RealEstateDeveloperSide,
+				4_000,
+			),
+			Error::<Test>::RegionNotLicensed
+		);
+		assert_noop!(
+			NftMarketplace::lawyer_claim_property(
+				RuntimeOrigin::signed([12; 32].into()),
+				0,
+				crate::LegalProperty::RealEstateDeveloperSide,
+				4_000,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftMarketplace::unregister_lawyer(RuntimeOrigin::signed([11; 32].into())));
+		assert_noop!(
+			NftMarketplace::lawyer_claim_property(
+				RuntimeOrigin::signed([11; 32].into()),
+				0,
+				crate::LegalProperty::SpvSide,
+				4_000,
+			),
+			Error::<Test>::LawyerUnbonding
+		);
+	})
+}
+
+// unregister_lawyer and withdraw_lawyer_bond function
+#[test]
+fn withdraw_lawyer_bond_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 1);
+		assert_ok!(NftMarketplace::unregister_lawyer(RuntimeOrigin::signed([10; 32].into())));
+		System::assert_last_event(
+			crate::Event::LawyerUnbonding { lawyer: [10; 32].into(), unbonding_until: 11 }.into(),
+		);
+		run_to_block(11);
+		assert_noop!(
+			NftMarketplace::withdraw_lawyer_bond(RuntimeOrigin::signed([10; 32].into())),
+			Error::<Test>::LawyerHasActiveCases
+		);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 0);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([11; 32].into()).unwrap().active_cases, 0);
+		assert_ok!(NftMarketplace::withdraw_lawyer_bond(RuntimeOrigin::signed([10; 32].into())));
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).is_none(), true);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 0);
+		assert_eq!(Balances::free_balance(&([10; 32].into())), 10_000);
+	})
+}
+
+#[test]
+fn withdraw_lawyer_bond_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_noop!(
+			NftMarketplace::unregister_lawyer(RuntimeOrigin::signed([10; 32].into())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_noop!(
+			NftMarketplace::withdraw_lawyer_bond(RuntimeOrigin::signed([10; 32].into())),
+			Error::<Test>::LawyerNotUnbonding
+		);
+		assert_ok!(NftMarketplace::unregister_lawyer(RuntimeOrigin::signed([10; 32].into())));
+		assert_noop!(
+			NftMarketplace::unregister_lawyer(RuntimeOrigin::signed([10; 32].into())),
+			Error::<Test>::LawyerUnbonding
+		);
+		run_to_block(10);
+		assert_noop!(
+			NftMarketplace::withdraw_lawyer_bond(RuntimeOrigin::signed([10; 32].into())),
+			Error::<Test>::UnbondingPeriodNotOver
+		);
+	})
+}
+
+// slash_lawyer function
+#[test]
+fn slash_lawyer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::slash_lawyer(RuntimeOrigin::root(), [10; 32].into(), 400));
+		System::assert_last_event(
+			crate::Event::LawyerSlashed { lawyer: [10; 32].into(), amount: 400 }.into(),
+		);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().bond, 600);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 600);
+		assert_eq!(Balances::free_balance(&NftMarketplace::treasury_account_id()), 400);
+		assert_ok!(NftMarketplace::slash_lawyer(RuntimeOrigin::root(), [10; 32].into(), 5_000));
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().bond, 0);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 0);
+		assert_eq!(Balances::free_balance(&NftMarketplace::treasury_account_id()), 1_000);
+		assert_eq!(Balances::free_balance(&([10; 32].into())), 9_000);
+	})
+}
+
+#[test]
+fn top_up_lawyer_bond_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_noop!(
+			NftMarketplace::top_up_lawyer_bond(RuntimeOrigin::signed([10; 32].into())),
+			Error::<Test>::LawyerBondComplete
+		);
+		assert_ok!(NftMarketplace::slash_lawyer(RuntimeOrigin::root(), [10; 32].into(), 400));
+		assert_noop!(
+			NftMarketplace::lawyer_claim_property(
+				RuntimeOrigin::signed([10; 32].into()),
+				0,
+				crate::LegalProperty::RealEstateDeveloperSide,
+				4_000,
+			),
+			Error::<Test>::LawyerBondTooLow
+		);
+		assert_noop!(
+			NftMarketplace::top_up_lawyer_bond(RuntimeOrigin::signed([11; 32].into())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftMarketplace::top_up_lawyer_bond(RuntimeOrigin::signed([10; 32].into())));
+		System::assert_last_event(
+			crate::Event::LawyerBondToppedUp { lawyer: [10; 32].into(), amount: 400 }.into(),
+		);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().bond, 1_000);
+		assert_eq!(Balances::reserved_balance(&([10; 32].into())), 1_000);
+		assert_noop!(
+			NftMarketplace::lawyer_claim_property(
+				RuntimeOrigin::signed([10; 32].into()),
+				0,
+				crate::LegalProperty::RealEstateDeveloperSide,
+				4_000,
+			),
+			Error::<Test>::InvalidIndex
+		);
+	})
+}
+
+#[test]
+fn slash_lawyer_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_noop!(
+			NftMarketplace::slash_lawyer(RuntimeOrigin::root(), [10; 32].into(), 400),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_noop!(
+			NftMarketplace::slash_lawyer(RuntimeOrigin::signed([0; 32].into()), [10; 32].into(), 400),
+			BadOrigin
+		);
 	})
 }
 
@@ -102,14 +429,16 @@ fn list_object_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
 		assert_eq!(ListedToken::<Test>::get(0).unwrap(), 100);
 		assert_eq!(NextNftId::<Test>::get(0), 1);
@@ -125,7 +454,7 @@ fn list_object_works() {
 fn list_object_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_noop!(
 			NftMarketplace::list_object(
 				RuntimeOrigin::signed([0; 32].into()),
@@ -133,7 +462,9 @@ fn list_object_fails() {
 				bvec![10, 10],
 				10_000,
 				100,
-				bvec![22, 22]
+				bvec![22, 22],
+				1,
+				None
 			),
 			Error::<Test>::RegionUnknown
 		);
@@ -145,7 +476,9 @@ fn list_object_fails() {
 				bvec![10, 10],
 				10_000,
 				100,
-				bvec![22, 22]
+				bvec![22, 22],
+				1,
+				None
 			),
 			Error::<Test>::LocationUnknown
 		);
@@ -157,116 +490,316 @@ fn list_object_fails() {
 				bvec![10, 10],
 				10_000,
 				251,
-				bvec![22, 22]
+				bvec![22, 22],
+				1,
+				None
 			),
 			Error::<Test>::TooManyToken
 		);
 	})
 }
 
-// buy_token function
 #[test]
-fn buy_token_works() {
+fn soft_cap_reached_settles_sold_token() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			Some(crate::SoftCap { minimum_sold: Percent::from_percent(50), deadline: 10 }),
+		));
+		assert_eq!(SoftCapsEnding::<Test>::get(10).into_inner(), vec![0]);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 60, u32::MAX));
+		run_to_block(9);
+		assert_eq!(ListedToken::<Test>::get(0), Some(40));
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), false);
+		run_to_block(10);
+		System::assert_last_event(crate::Event::SoftCapReached { listing_id: 0, sold_token: 60 }.into());
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert_eq!(SoftCaps::<Test>::get(0), None);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), true);
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			3_000,
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30));
-		assert_eq!(ListedToken::<Test>::get(0).unwrap(), 70);
-		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 30);
-		assert_eq!(TokenBuyer::<Test>::get(0).len(), 1);
-		assert_eq!(Balances::free_balance(&([1; 32].into())), 15_000_000);
-		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_188_000);
-	})
-}
-
-
-
-#[test]
-fn buy_token_doesnt_work() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_noop!(
-			NftMarketplace::buy_token(RuntimeOrigin::signed([0; 32].into()), 1, 1),
-			Error::<Test>::TokenNotForSale
-		);
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			3_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 60);
+		assert_eq!(Assets::balance(0, &[0; 32].into()), 40);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()), 60);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [0; 32].into()), 40);
+		assert_eq!(PropertyOwner::<Test>::get(0).len(), 2);
 	})
 }
 
 #[test]
-fn buy_token_doesnt_work_2() {
+fn soft_cap_missed_refunds_investors() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			Some(crate::SoftCap { minimum_sold: Percent::from_percent(50), deadline: 10 }),
 		));
-		assert_noop!(
-			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 101),
-			Error::<Test>::NotEnoughTokenAvailable
-		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 40, u32::MAX));
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_084_000);
+		run_to_block(10);
+		System::assert_last_event(crate::Event::SoftCapMissed { listing_id: 0, sold_token: 40 }.into());
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert_eq!(OngoingObjectListing::<Test>::get(0).is_none(), true);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).is_none(), true);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), false);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_496_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 4_000);
+		assert_eq!(TokenBuyer::<Test>::get(0).len(), 0);
 	})
 }
 
 #[test]
-fn listing_and_selling_multiple_objects() {
+fn soft_cap_deadline_moves_to_next_free_block() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
-		assert_ok!(NftMarketplace::list_object(
-			RuntimeOrigin::signed([3; 32].into()),
-			0,
-			bvec![10, 10],
-			10_000,
-			100,
-			bvec![22, 22]
-		));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		SoftCapsEnding::<Test>::insert(10, BoundedVec::try_from((100..200).collect::<Vec<u32>>()).unwrap());
 		assert_ok!(NftMarketplace::list_object(
-			RuntimeOrigin::signed([2; 32].into()),
+			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
-		));
+			bvec![22, 22],
+			1,
+			Some(crate::SoftCap { minimum_sold: Percent::from_percent(50), deadline: 10 }),
+		));
+		assert_eq!(SoftCapsEnding::<Test>::get(11).into_inner(), vec![0]);
+		assert_eq!(SoftCaps::<Test>::get(0).unwrap().deadline, 11);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 40, u32::MAX));
+		run_to_block(10);
+		assert_eq!(ListedToken::<Test>::get(0), Some(60));
+		run_to_block(11);
+		System::assert_last_event(crate::Event::SoftCapMissed { listing_id: 0, sold_token: 40 }.into());
+		assert_eq!(OngoingObjectListing::<Test>::get(0).is_none(), true);
+	})
+}
+
+#[test]
+fn soft_cap_is_ignored_once_sold_out() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			Some(crate::SoftCap { minimum_sold: Percent::from_percent(50), deadline: 10 }),
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 80));
-		assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 20));
-		assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), true);
-		assert_ok!(NftMarketplace::lawyer_claim_property(
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), true);
+		run_to_block(10);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_some(), true);
+		assert_eq!(SoftCaps::<Test>::get(0), None);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).is_some(), true);
+	})
+}
+
+#[test]
+fn list_object_with_invalid_soft_cap_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		for soft_cap in [
+			crate::SoftCap { minimum_sold: Percent::from_percent(50), deadline: 1 },
+			crate::SoftCap { minimum_sold: Percent::from_percent(50), deadline: 32 },
+			crate::SoftCap { minimum_sold: Percent::from_percent(0), deadline: 10 },
+		] {
+			assert_noop!(
+				NftMarketplace::list_object(
+					RuntimeOrigin::signed([0; 32].into()),
+					0,
+					bvec![10, 10],
+					10_000,
+					100,
+					bvec![22, 22],
+					1,
+					Some(soft_cap),
+				),
+				Error::<Test>::InvalidSoftCap
+			);
+		}
+	})
+}
+
+// buy_token function
+#[test]
+fn buy_token_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_eq!(ListedToken::<Test>::get(0).unwrap(), 70);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 30);
+		assert_eq!(TokenBuyer::<Test>::get(0).len(), 1);
+		assert_eq!(Balances::free_balance(&([1; 32].into())), 15_000_000);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_188_000);
+	})
+}
+
+
+
+#[test]
+fn buy_token_doesnt_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([0; 32].into()), 1, 1, u32::MAX),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+#[test]
+fn buy_token_doesnt_work_2() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 101, u32::MAX),
+			Error::<Test>::NotEnoughTokenAvailable
+		);
+	})
+}
+
+#[test]
+fn listing_and_selling_multiple_objects() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([3; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([2; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 80, u32::MAX));
+		assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 20, u32::MAX));
+		assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), true);
+		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			1,
 			crate::LegalProperty::RealEstateDeveloperSide,
@@ -282,24 +815,30 @@ fn listing_and_selling_multiple_objects() {
 			RuntimeOrigin::signed([10; 32].into()),
 			1,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			1,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 2, 10));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 2, 10));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 2, 30));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 2, 10, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 2, 10, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 2, 30, u32::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([3; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 33));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 33, u32::MAX));
 		assert_eq!(ListedToken::<Test>::get(0).unwrap(), 67);
 		assert_eq!(ListedToken::<Test>::get(2).unwrap(), 50);
 		assert_eq!(ListedToken::<Test>::get(3).unwrap(), 100);
@@ -318,9 +857,11 @@ fn claim_property_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -328,9 +869,11 @@ fn claim_property_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -354,9 +897,11 @@ fn claim_property_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -364,16 +909,18 @@ fn claim_property_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 99));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 99, u32::MAX));
 		assert_noop!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		), Error::<Test>::InvalidIndex);
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 1));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 1, u32::MAX));
 		assert_noop!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([9; 32].into()),
 			0,
@@ -410,10 +957,13 @@ fn remove_from_case_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([12; 32].into()), [12; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [12; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -421,9 +971,11 @@ fn remove_from_case_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -460,21 +1012,24 @@ fn remove_from_case_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_noop!(NftMarketplace::remove_from_case(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 		), Error::<Test>::NoPermission);
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
 		assert_noop!(NftMarketplace::remove_from_case(
 			RuntimeOrigin::signed([10; 32].into()),
@@ -490,6 +1045,8 @@ fn remove_from_case_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_noop!(NftMarketplace::remove_from_case(
 			RuntimeOrigin::signed([10; 32].into()),
@@ -505,9 +1062,11 @@ fn distributes_nfts_and_funds() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -515,9 +1074,11 @@ fn distributes_nfts_and_funds() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -536,12 +1097,16 @@ fn distributes_nfts_and_funds() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
 		assert_eq!(Assets::balance(1, &[0; 32].into()), 20_990_000);
@@ -563,9 +1128,11 @@ fn reject_contract_and_refund() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -573,9 +1140,11 @@ fn reject_contract_and_refund() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -594,6 +1163,8 @@ fn reject_contract_and_refund() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
 		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
 		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
@@ -601,6 +1172,8 @@ fn reject_contract_and_refund() {
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
 		assert_eq!(PropertyLawyer::<Test>::get(1).is_some(), false);
 		assert_eq!(Assets::balance(1, &[0; 32].into()), 20_000_000);
@@ -616,14 +1189,16 @@ fn reject_contract_and_refund() {
 }
 
 #[test]
-fn second_attempt_works() {
+fn review_timeout_removes_lawyer() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -631,80 +1206,166 @@ fn second_attempt_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_deadline, Some(21));
+		assert_eq!(ReviewDeadlines::<Test>::get(21).len(), 1);
+		run_to_block(20);
 		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
+		run_to_block(21);
+		System::assert_last_event(
+			crate::Event::LawyerReviewTimedOut {
+				lawyer: [10; 32].into(),
+				listing_id: 0,
+				legal_side: crate::LegalProperty::RealEstateDeveloperSide,
+			}
+			.into(),
+		);
+		let property_lawyer_details = PropertyLawyer::<Test>::get(0).unwrap();
+		assert_eq!(property_lawyer_details.real_estate_developer_lawyer, None);
+		assert_eq!(property_lawyer_details.real_estate_developer_deadline, None);
+		assert_eq!(property_lawyer_details.review_timeouts, 1);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 0);
+		assert_eq!(ReviewDeadlines::<Test>::get(21).len(), 0);
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			crate::LegalProperty::SpvSide,
+			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
-		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_deadline, Some(41));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([10; 32].into()),
+			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
-		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
-		assert_ok!(NftMarketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([11; 32].into()),
+		run_to_block(41);
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([11; 32].into()));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().review_timeouts, 1);
+	})
+}
+
+#[test]
+fn review_deadline_moves_to_next_free_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
 			0,
-			false,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
-		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		let full_block: Vec<(u32, crate::LegalProperty)> =
+			(100..200).map(|listing_id| (listing_id, crate::LegalProperty::SpvSide)).collect();
+		ReviewDeadlines::<Test>::insert(21, BoundedVec::try_from(full_block).unwrap());
+		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
-			false,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
 		));
-		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
-		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
-		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_deadline, Some(22));
+		assert_eq!(ReviewDeadlines::<Test>::get(22).len(), 1);
+		run_to_block(21);
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
+		run_to_block(22);
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, None);
+	})
+}
+
+#[test]
+fn repeated_review_timeouts_refund_investors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			true,
+			crate::LegalProperty::SpvSide,
+			4_000,
 		));
-		assert_eq!(Assets::balance(1, &[0; 32].into()), 20_000_000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 6000);
+		run_to_block(21);
+		System::assert_last_event(crate::Event::LegalProcessTimedOut { listing_id: 0 }.into());
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_none(), true);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 10_000);
 		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_490_000);
-		assert_eq!(Assets::balance(1, &[11; 32].into()), 4_000);
+		assert_eq!(Assets::balance(1, &[11; 32].into()), 0);
 		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).is_none(), true);
-		assert_eq!(ListedToken::<Test>::get(0), None);
-		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 0);
 		assert_eq!(TokenBuyer::<Test>::get(0).len(), 0);
-		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 0);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([11; 32].into()).unwrap().active_cases, 0);
 	})
 }
 
 #[test]
-fn lawyer_confirm_documents_fails() {
+fn second_attempt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [12; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -719,39 +1380,78 @@ fn lawyer_confirm_documents_fails() {
 			4_000,
 		));
 		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
-		assert_noop!(NftMarketplace::lawyer_confirm_documents(
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
-			1,
-			false,
-		), Error::<Test>::InvalidIndex);
-		assert_noop!(NftMarketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([12; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			false,
-		), Error::<Test>::NoPermission);
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
+		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_noop!(NftMarketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([10; 32].into()),
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
+		let documents = NftMarketplace::legal_documents(0);
+		assert_eq!(documents.len(), 3);
+		assert_eq!(documents[0].round, 1);
+		assert_eq!(documents[0].approved, true);
+		assert_eq!(documents[2].legal_side, crate::LegalProperty::RealEstateDeveloperSide);
+		assert_eq!(documents[2].round, 2);
+		assert_eq!(documents[2].approved, false);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
-		), Error::<Test>::AlreadyConfirmed);
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_eq!(Arbitrations::<Test>::get(0), Some(31));
+		assert_eq!(ArbitrationsExpiring::<Test>::get(31).into_inner(), vec![0]);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::resolve_arbitration(
+			RuntimeOrigin::root(),
+			0,
+			crate::ArbitrationDecision::Refund,
+		));
+		assert_eq!(Arbitrations::<Test>::get(0), None);
+		assert_eq!(Assets::balance(1, &[0; 32].into()), 20_000_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 6000);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_490_000);
+		assert_eq!(Assets::balance(1, &[11; 32].into()), 4_000);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).is_none(), true);
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 0);
+		assert_eq!(TokenBuyer::<Test>::get(0).len(), 0);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
 	})
 }
 
-// list_token function
 #[test]
-fn relist_a_nft() {
+fn resolve_arbitration_executes_deal() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -759,84 +1459,88 @@ fn relist_a_nft() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			true,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
 			0,
-			1000,
-			1
-		));
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_eq!(TokenListings::<Test>::get(1).unwrap().item_id, 0);
-		assert_eq!(Assets::balance(0, NftMarketplace::account_id()), 1);
-		assert_eq!(Assets::balance(0, &[1; 32].into()), 99);
-	})
-}
-
-#[test]
-fn relist_nfts_not_created_with_marketplace_fails() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(Uniques::create(
-			RuntimeOrigin::signed([0; 32].into()),
-			sp_runtime::MultiAddress::Id([0; 32].into()),
-			Default::default()
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_ok!(Uniques::mint(
-			RuntimeOrigin::signed([0; 32].into()),
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
 			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::resolve_arbitration(
+			RuntimeOrigin::root(),
 			0,
-			sp_runtime::MultiAddress::Id([0; 32].into()),
-			None
+			crate::ArbitrationDecision::Execute,
 		));
-		assert_noop!(
-			NftMarketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 0, 1000, 1),
-			Error::<Test>::RegionUnknown
-		);
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
-		assert_noop!(
-			NftMarketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 0, 1000, 1),
-			Error::<Test>::NftNotFound
+		System::assert_last_event(
+			crate::Event::ArbitrationResolved {
+				listing_id: 0,
+				decision: crate::ArbitrationDecision::Execute,
+			}
+			.into(),
 		);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_none(), true);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 100);
+		assert_eq!(RealEstateLawyer::<Test>::get::<AccountId>([10; 32].into()).unwrap().active_cases, 0);
 	})
 }
 
 #[test]
-fn relist_a_nft_fails() {
+fn resolve_arbitration_orders_review() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -844,129 +1548,98 @@ fn relist_a_nft_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			true,
-		));
-		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
-		assert_noop!(
-			NftMarketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 0, 1000, 1),
-			Error::<Test>::NotEnoughFunds
-		);
-	})
-}
-
-// buy_relisted_token function
-#[test]
-fn buy_relisted_token_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
-		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
-		assert_ok!(NftMarketplace::list_object(
-			RuntimeOrigin::signed([0; 32].into()),
-			0,
-			bvec![10, 10],
-			10_000,
-			100,
-			bvec![22, 22]
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
-		assert_ok!(NftMarketplace::lawyer_claim_property(
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
-			crate::LegalProperty::RealEstateDeveloperSide,
-			4_000,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_ok!(NftMarketplace::lawyer_claim_property(
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			crate::LegalProperty::SpvSide,
-			4_000,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::resolve_arbitration(
+			RuntimeOrigin::root(),
+			0,
+			crate::ArbitrationDecision::Review,
 		));
+		let property_lawyer_details = PropertyLawyer::<Test>::get(0).unwrap();
+		assert_eq!(property_lawyer_details.real_estate_developer_status, crate::DocumentStatus::Pending);
+		assert_eq!(property_lawyer_details.spv_status, crate::DocumentStatus::Pending);
+		assert_eq!(property_lawyer_details.spv_deadline, Some(21));
+		assert_eq!(Arbitrations::<Test>::get(0), None);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_eq!(Assets::balance(1, &([0; 32].into())), 20990000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 12000);
-		assert_eq!(Assets::balance(1, &([1; 32].into())), 460_000);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_none(), true);
 		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-			0,
-			1000,
-			3
-		));
-		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 2));
-		assert_eq!(Assets::balance(1, &([3; 32].into())), 3_000);
-		assert_eq!(Assets::balance(0, &[3; 32].into()), 2);
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1));
-		assert_eq!(Assets::balance(1, &([3; 32].into())), 2_000);
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), false);
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-			0,
-			500,
-			1
-		));
-		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 2, 1));
-		assert_eq!(TokenListings::<Test>::get(0).is_some(), false);
-		assert_eq!(PropertyOwner::<Test>::get(0).len(), 2);
-		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()), 96);
-		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()), 4);
-		assert_eq!(Assets::balance(1, &([1; 32].into())), 463_465);
-		assert_eq!(Assets::balance(1, &([3; 32].into())), 1_500);
-		assert_eq!(Assets::balance(0, &[1; 32].into()), 96);
-		assert_eq!(Assets::balance(0, &[3; 32].into()), 4);
 	})
 }
 
 #[test]
-fn buy_relisted_token_fails() {
+fn repeated_arbitration_reviews_keep_all_rounds() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -974,9 +1647,11 @@ fn buy_relisted_token_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -989,37 +1664,75 @@ fn buy_relisted_token_fails() {
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().review_round, 1);
+		for round in 1..=3u8 {
+			assert_ok!(NftMarketplace::lawyer_confirm_documents(
+				RuntimeOrigin::signed([10; 32].into()),
+				0,
+				true,
+				bvec![H256::repeat_byte(round)],
+				None,
+			));
+			assert_ok!(NftMarketplace::lawyer_confirm_documents(
+				RuntimeOrigin::signed([11; 32].into()),
+				0,
+				false,
+				bvec![H256::repeat_byte(round)],
+				Some(crate::RejectionReason::InvalidDocuments),
+			));
+			if round > 1 {
+				assert_ok!(NftMarketplace::resolve_arbitration(
+					RuntimeOrigin::root(),
+					0,
+					crate::ArbitrationDecision::Review,
+				));
+			}
+			assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().review_round, round + 1);
+		}
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(4)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(4)],
+			None,
 		));
-		assert_eq!(Assets::balance(1, &([0; 32].into())), 20990000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 12_000);
-		assert_eq!(Assets::balance(1, &([1; 32].into())), 460_000);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_none(), true);
 		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
-		assert_noop!(
-			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1),
-			Error::<Test>::TokenNotForSale
+		let documents = NftMarketplace::legal_documents(0);
+		assert_eq!(documents.len(), 8);
+		for (index, info) in documents.iter().enumerate() {
+			let round = index as u8 / 2 + 1;
+			assert_eq!(info.round, round);
+			assert_eq!(info.document_hashes, vec![H256::repeat_byte(round)]);
+			assert_eq!(info.approved, index % 2 == 0 || round == 4);
+		}
+		assert_eq!(documents[0].legal_side, crate::LegalProperty::RealEstateDeveloperSide);
+		assert_eq!(documents[1].legal_side, crate::LegalProperty::SpvSide);
+		assert_eq!(
+			LegalDocuments::<Test>::get((0, crate::LegalProperty::SpvSide, 2)).unwrap().approved,
+			false
 		);
 	})
 }
 
-// make_offer function
 #[test]
-fn make_offer_works() {
+fn arbitration_expires_with_refund() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1027,56 +1740,83 @@ fn make_offer_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			true,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
 			0,
-			500,
-			1
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1));
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 2000);
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		run_to_block(30);
+		assert_eq!(Arbitrations::<Test>::get(0), Some(31));
+		run_to_block(31);
+		System::assert_last_event(crate::Event::ArbitrationExpired { listing_id: 0 }.into());
+		assert_eq!(Arbitrations::<Test>::get(0), None);
+		assert_eq!(PropertyLawyer::<Test>::get(0).is_none(), true);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 6000);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_490_000);
+		assert_eq!(Assets::balance(1, &[11; 32].into()), 4_000);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).is_none(), true);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), true);
 	})
 }
 
 #[test]
-fn make_offer_fails() {
+fn resolve_arbitration_fails() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1084,148 +1824,189 @@ fn make_offer_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Approved);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			true,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_noop!(
-			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1),
-			Error::<Test>::TokenNotForSale
-		);
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().second_attempt, true);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
 			0,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
+		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_status, crate::DocumentStatus::Rejected);
+		assert_eq!(pallet_nfts::Item::<Test>::get(0, 0).is_none(), false);
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			500,
-			1
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_noop!(
-			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 2),
-			Error::<Test>::NotEnoughTokenAvailable
+			NftMarketplace::resolve_arbitration(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				crate::ArbitrationDecision::Execute,
+			),
+			BadOrigin
 		);
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1));
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 300, 1));
 		assert_noop!(
-			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 400, 1),
-			Error::<Test>::OnlyOneOfferPerUser
+			NftMarketplace::resolve_arbitration(
+				RuntimeOrigin::root(),
+				1,
+				crate::ArbitrationDecision::Execute,
+			),
+			Error::<Test>::ArbitrationNotFound
+		);
+		assert_noop!(
+			NftMarketplace::lawyer_confirm_documents(
+				RuntimeOrigin::signed([10; 32].into()),
+				0,
+				true,
+				bvec![H256::repeat_byte(1)],
+				None,
+			),
+			Error::<Test>::AlreadyConfirmed
 		);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).unwrap().token_price, 200);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [3; 32].into()).unwrap().token_price, 300);
 	})
 }
 
-// handle_offer function
 #[test]
-fn handle_offer_works() {
+fn lawyer_confirm_documents_fails() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([12; 32].into()), [12; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [12; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().real_estate_developer_lawyer, Some([10; 32].into()));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
-		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+		assert_eq!(PropertyLawyer::<Test>::get(0).unwrap().spv_lawyer, Some([11; 32].into()));
+		assert_noop!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			1,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
+		), Error::<Test>::InvalidIndex);
+		assert_noop!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([12; 32].into()),
+			0,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
+		), Error::<Test>::NoPermission);
+		assert_noop!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			false,
+			BoundedVec::new(),
+			Some(crate::RejectionReason::InvalidDocuments),
+		), Error::<Test>::NoDocumentHashes);
+		assert_noop!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			false,
+			bvec![H256::repeat_byte(1)],
+			None,
+		), Error::<Test>::InvalidRejectionReason);
+		assert_noop!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
-		));
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
+		), Error::<Test>::InvalidRejectionReason);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
-			RuntimeOrigin::signed([11; 32].into()),
+			RuntimeOrigin::signed([10; 32].into()),
 			0,
-			true,
+			false,
+			bvec![H256::repeat_byte(1)],
+			Some(crate::RejectionReason::InvalidDocuments),
 		));
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
+		assert_noop!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
 			0,
-			5000,
-			20
-		));
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1));
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 150, 1));
-		assert_ok!(NftMarketplace::handle_offer(
-			RuntimeOrigin::signed([1; 32].into()),
-			1,
-			[2; 32].into(),
-			crate::Offer::Reject
-		));
-		assert_ok!(NftMarketplace::cancel_offer(RuntimeOrigin::signed([3; 32].into()), 1));
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_150_000);
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 10));
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_130_000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 20000);
-		assert_ok!(NftMarketplace::handle_offer(
-			RuntimeOrigin::signed([1; 32].into()),
-			1,
-			[2; 32].into(),
-			crate::Offer::Accept
-		));
-		assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 10);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
-		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 0);
-		assert_eq!(Assets::balance(0, &([1; 32].into())), 80);
-		assert_eq!(Assets::balance(0, &([2; 32].into())), 10);
-		assert_eq!(Assets::balance(0, &NftMarketplace::account_id()), 10);
-		assert_eq!(Assets::balance(1, &([1; 32].into())), 479_800);
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_130_000);
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		), Error::<Test>::AlreadyConfirmed);
 	})
 }
 
 #[test]
-fn handle_offer_fails() {
+fn lawyer_confirm_documents_stores_document_hashes() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1233,9 +2014,11 @@ fn handle_offer_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1252,61 +2035,53 @@ fn handle_offer_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1), H256::repeat_byte(2)],
+			None,
 		));
+		assert_eq!(NftMarketplace::legal_documents(0).len(), 1);
+		run_to_block(3);
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
-			true,
-		));
-		assert_noop!(
-			NftMarketplace::handle_offer(
-				RuntimeOrigin::signed([1; 32].into()),
-				1,
-				[2; 32].into(),
-				crate::Offer::Reject
-			),
-			Error::<Test>::TokenNotForSale
-		);
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-			0,
-			5000,
-			2
+			false,
+			bvec![H256::repeat_byte(3)],
+			Some(crate::RejectionReason::TitleDefect),
 		));
-		assert_noop!(
-			NftMarketplace::handle_offer(
-				RuntimeOrigin::signed([1; 32].into()),
-				1,
-				[2; 32].into(),
-				crate::Offer::Reject
-			),
-			Error::<Test>::InvalidIndex
-		);
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1));
-		assert_noop!(
-			NftMarketplace::handle_offer(
-				RuntimeOrigin::signed([2; 32].into()),
-				1,
-				[2; 32].into(),
-				crate::Offer::Accept
-			),
-			Error::<Test>::NoPermission
+		let developer_review =
+			LegalDocuments::<Test>::get((0, crate::LegalProperty::RealEstateDeveloperSide, 1)).unwrap();
+		assert_eq!(developer_review.lawyer, [10; 32].into());
+		assert_eq!(
+			developer_review.document_hashes.into_inner(),
+			vec![H256::repeat_byte(1), H256::repeat_byte(2)]
 		);
+		assert_eq!(developer_review.approved, true);
+		assert_eq!(developer_review.reason, None);
+		assert_eq!(developer_review.reviewed_at, 1);
+		let documents = NftMarketplace::legal_documents(0);
+		assert_eq!(documents.len(), 2);
+		assert_eq!(documents[1].legal_side, crate::LegalProperty::SpvSide);
+		assert_eq!(documents[1].round, 1);
+		assert_eq!(documents[1].lawyer, [11; 32].into());
+		assert_eq!(documents[1].document_hashes, vec![H256::repeat_byte(3)]);
+		assert_eq!(documents[1].approved, false);
+		assert_eq!(documents[1].reason, Some(crate::RejectionReason::TitleDefect));
+		assert_eq!(documents[1].reviewed_at, 3);
+		assert_eq!(NftMarketplace::legal_documents(1).len(), 0);
 	})
 }
 
-// cancel_offer function
-
+// list_token function
 #[test]
-fn cancel_offer_works() {
+fn relist_a_nft() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1314,9 +2089,11 @@ fn cancel_offer_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1333,41 +2110,72 @@ fn cancel_offer_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
 		assert_ok!(NftMarketplace::relist_token(
 			RuntimeOrigin::signed([1; 32].into()),
 			0,
 			0,
-			500,
+			1000,
+			1,
 			1
 		));
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1));
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 2000);
-		assert_ok!(NftMarketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1));
 		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), false);
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_150_000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 0);
+		assert_eq!(TokenListings::<Test>::get(1).unwrap().item_id, 0);
+		assert_eq!(Assets::balance(0, NftMarketplace::account_id()), 1);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 99);
 	})
 }
 
 #[test]
-fn cancel_offer_fails() {
+fn relist_nfts_not_created_with_marketplace_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(Uniques::create(
+			RuntimeOrigin::signed([0; 32].into()),
+			sp_runtime::MultiAddress::Id([0; 32].into()),
+			Default::default()
+		));
+		assert_ok!(Uniques::mint(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			sp_runtime::MultiAddress::Id([0; 32].into()),
+			None
+		));
+		assert_noop!(
+			NftMarketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 0, 1000, 1, 1),
+			Error::<Test>::RegionUnknown
+		);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_noop!(
+			NftMarketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 0, 1000, 1, 1),
+			Error::<Test>::NftNotFound
+		);
+	})
+}
+
+#[test]
+fn relist_a_nft_fails() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1375,9 +2183,11 @@ fn cancel_offer_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1394,45 +2204,37 @@ fn cancel_offer_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-			0,
-			500,
-			1
-		));
-		assert_noop!(
-			NftMarketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1),
-			Error::<Test>::InvalidIndex
-		);
-		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1));
-		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
-		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 2000);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
 		assert_noop!(
-			NftMarketplace::cancel_offer(RuntimeOrigin::signed([1; 32].into()), 1),
-			Error::<Test>::InvalidIndex
+			NftMarketplace::relist_token(RuntimeOrigin::signed([0; 32].into()), 0, 0, 1000, 1, 1),
+			Error::<Test>::NotEnoughFunds
 		);
 	})
 }
 
-// upgrade_listing function
+// buy_relisted_token function
 #[test]
-fn upgrade_price_works() {
+fn buy_relisted_token_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1440,9 +2242,11 @@ fn upgrade_price_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1459,34 +2263,67 @@ fn upgrade_price_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_eq!(Assets::balance(1, &([0; 32].into())), 20990000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 12000);
+		assert_eq!(Assets::balance(1, &([1; 32].into())), 460_000);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
 		assert_ok!(NftMarketplace::relist_token(
 			RuntimeOrigin::signed([1; 32].into()),
 			0,
 			0,
 			1000,
+			3,
 			1
 		));
-		assert_ok!(NftMarketplace::upgrade_listing(RuntimeOrigin::signed([1; 32].into()), 1, 300));
-		assert_eq!(TokenListings::<Test>::get(1).unwrap().token_price, 300);
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 2, u32::MAX));
+		assert_eq!(Assets::balance(1, &([3; 32].into())), 3_000);
+		assert_eq!(Assets::balance(0, &[3; 32].into()), 2);
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, u32::MAX));
+		assert_eq!(Assets::balance(1, &([3; 32].into())), 2_000);
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), false);
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			1,
+			1
+		));
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 2, 1, u32::MAX));
+		assert_eq!(TokenListings::<Test>::get(0).is_some(), false);
+		assert_eq!(PropertyOwner::<Test>::get(0).len(), 2);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()), 96);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()), 4);
+		assert_eq!(Assets::balance(1, &([1; 32].into())), 463_465);
+		assert_eq!(Assets::balance(1, &([3; 32].into())), 1_500);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 96);
+		assert_eq!(Assets::balance(0, &[3; 32].into()), 4);
 	})
 }
 
 #[test]
-fn upgrade_price_fails_if_not_owner() {
+fn buy_relisted_token_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1494,9 +2331,11 @@ fn upgrade_price_fails_if_not_owner() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1513,57 +2352,39 @@ fn upgrade_price_fails_if_not_owner() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([1; 32].into()),
-			0,
-			0,
-			1000,
-			1
-		));
+		assert_eq!(Assets::balance(1, &([0; 32].into())), 20990000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 12_000);
+		assert_eq!(Assets::balance(1, &([1; 32].into())), 460_000);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
 		assert_noop!(
-			NftMarketplace::upgrade_listing(RuntimeOrigin::signed([4; 32].into()), 1, 300),
-			Error::<Test>::NoPermission
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, u32::MAX),
+			Error::<Test>::TokenNotForSale
 		);
 	})
 }
 
-// upgrade_object function
-#[test]
-fn upgrade_object_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
-		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(NftMarketplace::list_object(
-			RuntimeOrigin::signed([0; 32].into()),
-			0,
-			bvec![10, 10],
-			10_000,
-			100,
-			bvec![22, 22]
-		));
-		assert_ok!(NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 30000));
-		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().token_price, 30000);
-	})
-}
-
+// make_offer function
 #[test]
-fn upgrade_object_and_distribute_works() {
+fn make_offer_works() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1571,15 +2392,11 @@ fn upgrade_object_and_distribute_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
-		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 50));
-		assert_ok!(NftMarketplace::upgrade_object(
-			RuntimeOrigin::signed([0; 32].into()),
-			0,
-			20_000
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 50));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1596,62 +2413,56 @@ fn upgrade_object_and_distribute_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_eq!(Assets::balance(1, &([0; 32].into())), 21485000);
-		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 22000);
-		assert_eq!(Assets::balance(1, &([1; 32].into())), 980_000);
-		assert_eq!(Assets::balance(1, &([2; 32].into())), 110_000);
-
-		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
-		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			1,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 2000);
 	})
 }
 
 #[test]
-fn upgrade_single_nft_from_listed_object_fails() {
+fn make_offer_fails() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_noop!(
-			NftMarketplace::upgrade_listing(RuntimeOrigin::signed([0; 32].into()), 0, 300),
-			Error::<Test>::TokenNotForSale
-		);
-	})
-}
-
-#[test]
-fn upgrade_object_for_relisted_nft_fails() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
-		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
-		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
-		assert_ok!(NftMarketplace::list_object(
-			RuntimeOrigin::signed([0; 32].into()),
-			0,
-			bvec![10, 10],
-			10_000,
-			100,
-			bvec![22, 22]
-		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([0; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1668,51 +2479,56 @@ fn upgrade_object_for_relisted_nft_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_noop!(
+			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, None),
+			Error::<Test>::TokenNotForSale
+		);
 		assert_ok!(NftMarketplace::relist_token(
-			RuntimeOrigin::signed([0; 32].into()),
+			RuntimeOrigin::signed([1; 32].into()),
 			0,
 			0,
-			1000,
+			500,
+			1,
 			1
 		));
 		assert_noop!(
-			NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 300),
-			Error::<Test>::TokenNotForSale
+			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 2, None),
+			Error::<Test>::NotEnoughTokenAvailable
 		);
-	})
-}
-
-#[test]
-fn upgrade_unknown_collection_fails() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
-		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, None));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 300, 1, None));
 		assert_noop!(
-			NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 300),
-			Error::<Test>::TokenNotForSale
+			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 400, 1, None),
+			Error::<Test>::OnlyOneOfferPerUser
 		);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).unwrap().token_price, 200);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [3; 32].into()).unwrap().token_price, 300);
 	})
 }
 
-// delist_token function
+// handle_offer function
 #[test]
-fn delist_single_token_works() {
+fn handle_offer_works() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1720,9 +2536,11 @@ fn delist_single_token_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1739,45 +2557,67 @@ fn delist_single_token_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::relist_token(
 			RuntimeOrigin::signed([1; 32].into()),
 			0,
 			0,
-			1000,
+			5000,
+			20,
 			1
 		));
-		assert_ok!(NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 1));
-		assert_eq!(TokenListings::<Test>::get(0), None);
-		assert_ok!(NftMarketplace::relist_token(
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, None));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 150, 1, None));
+		assert_ok!(NftMarketplace::handle_offer(
 			RuntimeOrigin::signed([1; 32].into()),
-			0,
-			0,
-			1000,
-			3
+			1,
+			[2; 32].into(),
+			crate::Offer::Reject
 		));
-		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 2, 2));
-		assert_ok!(NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 2));
-		assert_eq!(Assets::balance(0, &[2; 32].into()), 2);
-		assert_eq!(Assets::balance(0, &[1; 32].into()), 98);
+		assert_ok!(NftMarketplace::cancel_offer(RuntimeOrigin::signed([3; 32].into()), 1));
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_150_000);
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 10, None));
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_130_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 20000);
+		assert_ok!(NftMarketplace::handle_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			1,
+			[2; 32].into(),
+			crate::Offer::Accept
+		));
+		assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 10);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 0);
+		assert_eq!(Assets::balance(0, &([1; 32].into())), 80);
+		assert_eq!(Assets::balance(0, &([2; 32].into())), 10);
+		assert_eq!(Assets::balance(0, &NftMarketplace::account_id()), 10);
+		assert_eq!(Assets::balance(1, &([1; 32].into())), 479_800);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_130_000);
 	})
 }
- 
+
 #[test]
-fn delist_fails() {
+fn handle_offer_fails() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1785,9 +2625,11 @@ fn delist_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1804,44 +2646,68 @@ fn delist_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
+		assert_noop!(
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([1; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::Reject
+			),
+			Error::<Test>::TokenNotForSale
+		);
 		assert_ok!(NftMarketplace::relist_token(
 			RuntimeOrigin::signed([1; 32].into()),
 			0,
 			0,
-			1000,
+			5000,
+			2,
 			1
 		));
 		assert_noop!(
-			NftMarketplace::delist_token(RuntimeOrigin::signed([4; 32].into()), 1),
-			Error::<Test>::NoPermission
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([1; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::Reject
+			),
+			Error::<Test>::InvalidIndex
 		);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 200, 1, None));
 		assert_noop!(
-			NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 2),
-			Error::<Test>::TokenNotForSale
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([2; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::Accept
+			),
+			Error::<Test>::NoPermission
 		);
 	})
 }
 
+// cancel_offer function
+
 #[test]
-fn listing_objects_in_different_regions() {
+fn cancel_offer_works() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
-		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 1, bvec![10, 10]));
-		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 2, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1849,86 +2715,4064 @@ fn listing_objects_in_different_regions() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::list_object(
-			RuntimeOrigin::signed([0; 32].into()),
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
 			1,
-			bvec![10, 10],
-			10_000,
-			100,
-			bvec![22, 22]
+			1
 		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 2000);
+		assert_ok!(NftMarketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1));
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), false);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_150_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 0);
+	})
+}
+
+#[test]
+fn cancel_offer_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
-			2,
+			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
-			1,
+			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
-			1,
+			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
-			1,
+			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
-			1,
+			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			1,
+			1
+		));
+		assert_noop!(
+			NftMarketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_eq!(TokenListings::<Test>::get(1).is_some(), true);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 2000);
+		assert_noop!(
+			NftMarketplace::cancel_offer(RuntimeOrigin::signed([1; 32].into()), 1),
+			Error::<Test>::InvalidIndex
+		);
+	})
+}
+
+// upgrade_listing function
+#[test]
+fn upgrade_price_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 2, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
-			2,
+			0,
 			crate::LegalProperty::RealEstateDeveloperSide,
 			4_000,
 		));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([11; 32].into()),
-			2,
+			0,
 			crate::LegalProperty::SpvSide,
 			4_000,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([10; 32].into()),
-			2,
+			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
-			2,
+			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
-		assert_eq!(RegisteredNftDetails::<Test>::get(1, 0).unwrap().spv_created, true);
-		assert_eq!(RegisteredNftDetails::<Test>::get(2, 0).unwrap().spv_created, true);
 		assert_ok!(NftMarketplace::relist_token(
 			RuntimeOrigin::signed([1; 32].into()),
-			1,
+			0,
 			0,
 			1000,
-			100
+			1,
+			1
 		));
-		assert_ok!(NftMarketplace::buy_relisted_token(
-			RuntimeOrigin::signed([2; 32].into()),
-			3,
-			100
+		assert_ok!(NftMarketplace::upgrade_listing(RuntimeOrigin::signed([1; 32].into()), 1, 300));
+		assert_eq!(TokenListings::<Test>::get(1).unwrap().token_price, 300);
+	})
+}
+
+#[test]
+fn upgrade_price_fails_if_not_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [4; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			1000,
+			1,
+			1
+		));
+		assert_noop!(
+			NftMarketplace::upgrade_listing(RuntimeOrigin::signed([4; 32].into()), 1, 300),
+			Error::<Test>::NoPermission
+		);
+	})
+}
+
+// upgrade_object function
+#[test]
+fn upgrade_object_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 30000));
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().token_price, 30000);
+	})
+}
+
+#[test]
+fn upgrade_object_and_distribute_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 50, u32::MAX));
+		assert_ok!(NftMarketplace::upgrade_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			20_000
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 50, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_eq!(Assets::balance(1, &([0; 32].into())), 21485000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 22000);
+		assert_eq!(Assets::balance(1, &([1; 32].into())), 980_000);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 110_000);
+
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0).unwrap().spv_created, true);
+		assert_eq!(ListedToken::<Test>::get(0), None);
+	})
+}
+
+#[test]
+fn upgrade_single_nft_from_listed_object_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::upgrade_listing(RuntimeOrigin::signed([0; 32].into()), 0, 300),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+#[test]
+fn upgrade_object_for_relisted_nft_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([0; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			1000,
+			1,
+			1
+		));
+		assert_noop!(
+			NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 1, 300),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+#[test]
+fn upgrade_unknown_collection_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_noop!(
+			NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 300),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+// delist_token function
+#[test]
+fn delist_single_token_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			1000,
+			1,
+			1
+		));
+		assert_ok!(NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 1));
+		assert_eq!(TokenListings::<Test>::get(0), None);
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			1000,
+			3,
+			1
+		));
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 2, 2, u32::MAX));
+		assert_ok!(NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 2));
+		assert_eq!(Assets::balance(0, &[2; 32].into()), 2);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 98);
+	})
+}
+ 
+#[test]
+fn delist_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [4; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			1000,
+			1,
+			1
+		));
+		assert_noop!(
+			NftMarketplace::delist_token(RuntimeOrigin::signed([4; 32].into()), 1),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 2),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+#[test]
+fn enforce_compliance_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			1,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_148_000);
+		assert_ok!(XcavateWhitelist::remove_from_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		System::assert_has_event(
+			crate::Event::ComplianceCheckRequired { account: [1; 32].into() }.into(),
+		);
+		assert_ok!(XcavateWhitelist::remove_from_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(NftMarketplace::enforce_compliance(
+			RuntimeOrigin::signed([0; 32].into()),
+			[2; 32].into(),
+			bvec![1]
+		));
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(Assets::balance(1, &([2; 32].into())), 1_150_000);
+		System::assert_last_event(
+			crate::Event::ComplianceEnforced {
+				account: [2; 32].into(),
+				returned_listings: 0,
+				refunded_offers: 1,
+				refunded_bids: 0,
+				failed_refunds: 0,
+				refunded_purchases: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 99);
+		assert_ok!(NftMarketplace::enforce_compliance(
+			RuntimeOrigin::signed([0; 32].into()),
+			[1; 32].into(),
+			bvec![1]
+		));
+		assert_eq!(TokenListings::<Test>::get(1), None);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 100);
+		System::assert_last_event(
+			crate::Event::ComplianceEnforced {
+				account: [1; 32].into(),
+				returned_listings: 1,
+				refunded_offers: 0,
+				refunded_bids: 0,
+				failed_refunds: 0,
+				refunded_purchases: 0,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn enforce_compliance_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			1,
+			1
+		));
+		assert_noop!(
+			NftMarketplace::enforce_compliance(
+				RuntimeOrigin::signed([0; 32].into()),
+				[1; 32].into(),
+				bvec![1]
+			),
+			Error::<Test>::AccountWhitelisted
+		);
+		assert_ok!(XcavateWhitelist::remove_from_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_noop!(
+			NftMarketplace::enforce_compliance(
+				RuntimeOrigin::signed([0; 32].into()),
+				[1; 32].into(),
+				bvec![0, 2]
+			),
+			Error::<Test>::NothingToEnforce
+		);
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 1, u32::MAX),
+			Error::<Test>::SellerNotWhitelisted
+		);
+		assert_ok!(NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 1));
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 100);
+	})
+}
+
+#[test]
+fn enforce_compliance_refunds_bids_and_purchases() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 30, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_000, 20, None));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 10, u32::MAX));
+		assert_eq!(ListedToken::<Test>::get(1), Some(90));
+		assert_ok!(XcavateWhitelist::remove_from_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(NftMarketplace::enforce_compliance(
+			RuntimeOrigin::signed([0; 32].into()),
+			[1; 32].into(),
+			bvec![0, 1]
+		));
+		System::assert_last_event(
+			crate::Event::ComplianceEnforced {
+				account: [1; 32].into(),
+				returned_listings: 0,
+				refunded_offers: 0,
+				refunded_bids: 1,
+				failed_refunds: 0,
+				refunded_purchases: 1,
+			}
+			.into(),
+		);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_500_000);
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 1);
+		assert_eq!(ListedToken::<Test>::get(1), Some(100));
+		assert_eq!(TokenBuyer::<Test>::get(1).len(), 0);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 1).token_amount, 0);
+		assert_eq!(OngoingObjectListing::<Test>::get(1).unwrap().collected_funds, 0);
+		assert_noop!(
+			NftMarketplace::enforce_compliance(
+				RuntimeOrigin::signed([0; 32].into()),
+				[1; 32].into(),
+				bvec![0, 1]
+			),
+			Error::<Test>::NothingToEnforce
+		);
+		run_to_block(11);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 20 }.into());
+	})
+}
+
+#[test]
+fn auction_skips_bids_of_blocked_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 30, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_000, 20, None));
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [1; 32].into()));
+		run_to_block(11);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 20 }.into());
+		System::assert_has_event(
+			crate::Event::FundsQuarantined {
+				account: [1; 32].into(),
+				payment_asset: 1,
+				amount: 31_200,
+			}
+			.into(),
+		);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 0);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_468_800);
+		assert_eq!(QuarantinedFunds::<Test>::get::<AccountId, u32>([1; 32].into(), 1), 31_200);
+		assert_noop!(
+			NftMarketplace::release_quarantined_funds(RuntimeOrigin::root(), [1; 32].into(), 1),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_ok!(XcavateWhitelist::unblock_account(RuntimeOrigin::root(), [1; 32].into()));
+		assert_noop!(
+			NftMarketplace::release_quarantined_funds(RuntimeOrigin::signed([1; 32].into()), [1; 32].into(), 1),
+			BadOrigin
+		);
+		assert_ok!(NftMarketplace::release_quarantined_funds(RuntimeOrigin::root(), [1; 32].into(), 1));
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_500_000);
+		assert_eq!(QuarantinedFunds::<Test>::get::<AccountId, u32>([1; 32].into(), 1), 0);
+		assert_noop!(
+			NftMarketplace::release_quarantined_funds(RuntimeOrigin::root(), [1; 32].into(), 1),
+			Error::<Test>::NoQuarantinedFunds
+		);
+	})
+}
+
+#[test]
+fn blocked_accounts_can_not_trade() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			1,
+			1
+		));
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [2; 32].into()));
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 1, u32::MAX),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_noop!(
+			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_ok!(XcavateWhitelist::unblock_account(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [1; 32].into()));
+		System::assert_has_event(
+			crate::Event::ComplianceCheckRequired { account: [1; 32].into() }.into(),
+		);
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 1, u32::MAX),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_noop!(
+			NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 1),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_ok!(NftMarketplace::enforce_compliance(
+			RuntimeOrigin::signed([0; 32].into()),
+			[1; 32].into(),
+			bvec![1]
+		));
+		assert_eq!(TokenListings::<Test>::get(1), None);
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 100);
+	})
+}
+
+#[test]
+fn transfer_property_token_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::transfer_property_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			[2; 32].into(),
+			30,
+			1
+		));
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 70);
+		assert_eq!(Assets::balance(0, &[2; 32].into()), 30);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()), 70);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()), 30);
+		assert_eq!(PropertyOwner::<Test>::get(0).len(), 2);
+		assert_ok!(NftMarketplace::set_transfer_fee(RuntimeOrigin::root(), 1, 100));
+		assert_eq!(TransferFee::<Test>::get(1), 100);
+		let treasury_balance = Assets::balance(1, &NftMarketplace::treasury_account_id());
+		assert_ok!(NftMarketplace::transfer_property_token(
+			RuntimeOrigin::signed([2; 32].into()),
+			0,
+			[1; 32].into(),
+			30,
+			1
+		));
+		assert_eq!(Assets::balance(0, &[1; 32].into()), 100);
+		assert_eq!(Assets::balance(0, &[2; 32].into()), 0);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_149_900);
+		assert_eq!(
+			Assets::balance(1, &NftMarketplace::treasury_account_id()),
+			treasury_balance + 100
+		);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [1; 32].into()), 100);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()), 0);
+		assert_eq!(PropertyOwner::<Test>::get(0).len(), 1);
+		System::assert_last_event(
+			crate::Event::PropertyTokenTransferred {
+				asset_id: 0,
+				from: [2; 32].into(),
+				to: [1; 32].into(),
+				amount: 30,
+				payment_asset: 1,
+				fee: 100,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn transfer_property_token_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([3; 32].into()),
+				0,
+				[2; 32].into(),
+				30,
+				1
+			),
+			Error::<Test>::UserNotWhitelisted
+		);
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				[3; 32].into(),
+				30,
+				1
+			),
+			Error::<Test>::ReceiverNotWhitelisted
+		);
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				[1; 32].into(),
+				30,
+				1
+			),
+			Error::<Test>::InvalidTransfer
+		);
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				[2; 32].into(),
+				0,
+				1
+			),
+			Error::<Test>::InvalidTransfer
+		);
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				5,
+				[2; 32].into(),
+				30,
+				1
+			),
+			Error::<Test>::AssetNotFound
+		);
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				[2; 32].into(),
+				101,
+				1
+			),
+			Error::<Test>::NotEnoughTokenAvailable
+		);
+		assert_noop!(
+			NftMarketplace::set_transfer_fee(RuntimeOrigin::signed([1; 32].into()), 1, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			NftMarketplace::set_transfer_fee(RuntimeOrigin::root(), 5, 100),
+			Error::<Test>::PaymentAssetNotSupported
+		);
+		assert_ok!(NftMarketplace::set_transfer_fee(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				[2; 32].into(),
+				30,
+				5
+			),
+			Error::<Test>::PaymentAssetNotSupported
+		);
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [2; 32].into()));
+		assert_noop!(
+			NftMarketplace::transfer_property_token(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				[2; 32].into(),
+				30,
+				1
+			),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+	})
+}
+
+#[test]
+fn listing_objects_in_different_regions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 1, bvec![10, 10]));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 2, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0, 1, 2]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0, 1, 2]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			1,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			2,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			1,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			1,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			1,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			1,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 2, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			2,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			2,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			2,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			2,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_eq!(RegisteredNftDetails::<Test>::get(1, 0).unwrap().spv_created, true);
+		assert_eq!(RegisteredNftDetails::<Test>::get(2, 0).unwrap().spv_created, true);
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			1,
+			0,
+			1000,
+			100,
+			1
+		));
+		assert_ok!(NftMarketplace::buy_relisted_token(
+			RuntimeOrigin::signed([2; 32].into()),
+			3,
+			100,
+			u32::MAX
+		));
+		assert_eq!(Assets::balance(2, &[2; 32].into()), 100);
+		assert_eq!(Assets::balance(3, &[2; 32].into()), 100);
+	})
+}
+ 
+
+// set_fee_schedule function
+#[test]
+fn set_fee_schedule_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let fee_details = FeeDetails {
+			buyer_fee: Percent::from_percent(2),
+			tax: Percent::from_percent(5),
+			treasury_fee: Percent::from_percent(3),
+		};
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Primary,
+			fee_details.clone()
+		));
+		assert_eq!(FeeSchedule::<Test>::get().primary, fee_details);
+		assert_eq!(FeeSchedule::<Test>::get().secondary.treasury_fee, Percent::from_percent(1));
+		System::assert_last_event(
+			crate::Event::FeeScheduleUpdated { sale_type: SaleType::Primary, fee_details }.into(),
+		);
+	})
+}
+
+#[test]
+fn set_fee_schedule_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let fee_details = FeeDetails {
+			buyer_fee: Percent::from_percent(2),
+			tax: Percent::from_percent(5),
+			treasury_fee: Percent::from_percent(3),
+		};
+		assert_noop!(
+			NftMarketplace::set_fee_schedule(
+				RuntimeOrigin::signed([0; 32].into()),
+				SaleType::Secondary,
+				fee_details
+			),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn buy_token_uses_fee_schedule() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Primary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(5),
+				treasury_fee: Percent::from_percent(3),
+			}
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().collected_fees, 6_000);
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().collected_tax, 15_000);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_179_000);
+	})
+}
+
+// add_payment_asset function
+#[test]
+fn add_payment_asset_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2.into(), [0; 32].into(), true, 1));
+		assert_eq!(PaymentAssets::<Test>::get(1), true);
+		assert_eq!(PaymentAssets::<Test>::get(2), false);
+		assert_ok!(NftMarketplace::add_payment_asset(RuntimeOrigin::root(), 2));
+		assert_eq!(PaymentAssets::<Test>::get(2), true);
+		System::assert_last_event(crate::Event::PaymentAssetAdded { asset_id: 2 }.into());
+	})
+}
+
+#[test]
+fn add_payment_asset_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2.into(), [0; 32].into(), true, 1));
+		assert_noop!(
+			NftMarketplace::add_payment_asset(RuntimeOrigin::signed([0; 32].into()), 2),
+			BadOrigin
+		);
+		assert_noop!(
+			NftMarketplace::add_payment_asset(RuntimeOrigin::root(), 1),
+			Error::<Test>::PaymentAssetAlreadySupported
+		);
+		assert_noop!(
+			NftMarketplace::add_payment_asset(RuntimeOrigin::root(), 3),
+			Error::<Test>::AssetNotFound
+		);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::add_payment_asset(RuntimeOrigin::root(), 0),
+			Error::<Test>::PropertyTokenNotAllowed
+		);
+	})
+}
+
+// remove_payment_asset function
+#[test]
+fn remove_payment_asset_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::remove_payment_asset(RuntimeOrigin::root(), 1));
+		assert_eq!(PaymentAssets::<Test>::get(1), false);
+		System::assert_last_event(crate::Event::PaymentAssetRemoved { asset_id: 1 }.into());
+		assert_noop!(
+			NftMarketplace::list_object(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				bvec![10, 10],
+				10_000,
+				100,
+				bvec![22, 22],
+				1,
+				None
+			),
+			Error::<Test>::PaymentAssetNotSupported
+		);
+	})
+}
+
+#[test]
+fn remove_payment_asset_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			NftMarketplace::remove_payment_asset(RuntimeOrigin::signed([0; 32].into()), 1),
+			BadOrigin
+		);
+		assert_noop!(
+			NftMarketplace::remove_payment_asset(RuntimeOrigin::root(), 2),
+			Error::<Test>::PaymentAssetNotSupported
+		);
+	})
+}
+
+#[test]
+fn buy_token_with_other_payment_asset_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 2.into(), [0; 32].into(), true, 1));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed([0; 32].into()),
+			2.into(),
+			sp_runtime::MultiAddress::Id([1; 32].into()),
+			1_000_000
+		));
+		assert_ok!(NftMarketplace::add_payment_asset(RuntimeOrigin::root(), 2));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			2,
+			None
+		));
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().payment_asset, 2);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_eq!(Assets::balance(2, &[1; 32].into()), 688_000);
+		assert_eq!(Assets::balance(2, &NftMarketplace::account_id()), 312_000);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_500_000);
+	})
+}
+
+// expire_listing function
+#[test]
+fn expire_listing_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry, 31);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_188_000);
+		System::set_block_number(32);
+		assert_ok!(NftMarketplace::expire_listing(RuntimeOrigin::signed([2; 32].into()), 0));
+		System::assert_last_event(crate::Event::ListingExpired { listing_id: 0 }.into());
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_497_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 3_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 0);
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0), None);
+		assert_eq!(TokenBuyer::<Test>::get(0).len(), 0);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 0);
+	})
+}
+
+#[test]
+fn expire_listing_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_noop!(
+			NftMarketplace::expire_listing(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::TokenNotForSale
+		);
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		System::set_block_number(31);
+		assert_noop!(
+			NftMarketplace::expire_listing(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::ListingNotExpired
+		);
+		System::set_block_number(32);
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX),
+			Error::<Test>::ListingExpired
+		);
+	})
+}
+
+// cancel_object_listing function
+#[test]
+fn cancel_object_listing_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 20, u32::MAX));
+		assert_ok!(NftMarketplace::cancel_object_listing(RuntimeOrigin::signed([0; 32].into()), 0));
+		System::assert_last_event(crate::Event::ObjectListingCancelled { listing_id: 0 }.into());
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_500_000);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_150_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::treasury_account_id()), 0);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 0);
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0), None);
+		assert_eq!(AssetIdDetails::<Test>::get(0), None);
+		assert_eq!(TokenBuyer::<Test>::get(0).len(), 0);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([2; 32].into(), 0).token_amount, 0);
+	})
+}
+
+#[test]
+fn cancel_object_listing_by_admin_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::cancel_object_listing(RuntimeOrigin::root(), 0));
+		assert_eq!(OngoingObjectListing::<Test>::get(0), None);
+		assert_eq!(RegisteredNftDetails::<Test>::get(0, 0), None);
+	})
+}
+
+#[test]
+fn cancel_object_listing_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::cancel_object_listing(RuntimeOrigin::signed([1; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_noop!(
+			NftMarketplace::cancel_object_listing(RuntimeOrigin::signed([0; 32].into()), 0),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+// offer expiry
+#[test]
+fn offer_expires_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).unwrap().expiry, 21);
+		assert_eq!(OffersExpiring::<Test>::get(21).len(), 1);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_148_000);
+		run_to_block(20);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
+		run_to_block(21);
+		System::assert_last_event(
+			crate::Event::OfferExpired { listing_id: 1, account_id: [2; 32].into() }.into(),
+		);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(OffersExpiring::<Test>::get(21).len(), 0);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_150_000);
+	})
+}
+
+#[test]
+fn offer_expiry_moves_to_next_block_when_block_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 300, 1, None));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([5; 32].into()), 1, 100, 1, None));
+		assert_eq!(OffersExpiring::<Test>::get(21).len(), 2);
+		assert_eq!(OffersExpiring::<Test>::get(22).len(), 1);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [3; 32].into()).unwrap().expiry, 21);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [5; 32].into()).unwrap().expiry, 22);
+		run_to_block(21);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [3; 32].into()).is_none(), true);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [5; 32].into()).is_some(), true);
+		run_to_block(22);
+		System::assert_last_event(
+			crate::Event::OfferExpired { listing_id: 1, account_id: [5; 32].into() }.into(),
+		);
+		assert_eq!(OngoingOffers::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_150_000);
+		assert_eq!(Assets::balance(1, &[3; 32].into()), 5_000);
+		assert_eq!(Assets::balance(1, &[5; 32].into()), 500);
+	})
+}
+
+#[test]
+fn make_offer_respects_max_total_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 300, 1, Some(300)));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Secondary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(0),
+				treasury_fee: Percent::from_percent(1),
+			}
+		));
+		assert_noop!(
+			NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, Some(2_000)),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, Some(2_040)));
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_147_960);
+	})
+}
+
+#[test]
+fn failed_offer_refund_can_be_claimed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed([0; 32].into()), 1));
+		run_to_block(21);
+		System::assert_last_event(
+			crate::Event::OfferRefundFailed { listing_id: 1, account_id: [2; 32].into() }.into(),
+		);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(FailedOfferRefunds::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_some(), true);
+		assert_eq!(OffersExpiring::<Test>::get(21).len(), 0);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_148_000);
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed([0; 32].into()), 1));
+		assert_ok!(NftMarketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1));
+		assert_eq!(FailedOfferRefunds::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_150_000);
+	})
+}
+
+#[test]
+fn offer_expiry_skips_cancelled_offer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_ok!(NftMarketplace::cancel_offer(RuntimeOrigin::signed([2; 32].into()), 1));
+		run_to_block(5);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 1000, 1, None));
+		run_to_block(21);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).unwrap().expiry, 25);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_149_000);
+		run_to_block(25);
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_150_000);
+	})
+}
+
+#[test]
+fn closing_listing_refunds_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 1, None));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 300, 1, None));
+		run_to_block(2);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([5; 32].into()), 1, 100, 1, None));
+		assert_eq!(Assets::balance(1, &[5; 32].into()), 400);
+		assert_ok!(NftMarketplace::delist_token(RuntimeOrigin::signed([1; 32].into()), 1));
+		assert_eq!(ClosedListings::<Test>::get(1), true);
+		run_to_block(3);
+		assert_eq!(OngoingOffers::<Test>::iter_prefix(1).count(), 1);
+		assert_eq!(ClosedListings::<Test>::get(1), true);
+		run_to_block(4);
+		assert_eq!(OngoingOffers::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(ClosedListings::<Test>::get(1), false);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_150_000);
+		assert_eq!(Assets::balance(1, &[3; 32].into()), 5_000);
+		assert_eq!(Assets::balance(1, &[5; 32].into()), 500);
+	})
+}
+
+// counter offers
+#[test]
+fn counter_offer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Secondary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(0),
+				treasury_fee: Percent::from_percent(1),
+			}
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 2, None));
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_145_920);
+		assert_ok!(NftMarketplace::handle_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			1,
+			[2; 32].into(),
+			crate::Offer::Counter(1500)
+		));
+		System::assert_last_event(
+			crate::Event::OfferCountered { listing_id: 1, offeror: [2; 32].into(), token_price: 1500 }
+				.into(),
+		);
+		assert_eq!(
+			OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).unwrap().state,
+			crate::OfferState::Countered { token_price: 1500 }
+		);
+		assert_ok!(NftMarketplace::accept_counter_offer(RuntimeOrigin::signed([2; 32].into()), 1, u32::MAX));
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(TokenListings::<Test>::get(1).is_none(), true);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()), 2);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_146_940);
+	})
+}
+
+#[test]
+fn counter_offer_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 2, None));
+		assert_noop!(
+			NftMarketplace::accept_counter_offer(RuntimeOrigin::signed([2; 32].into()), 1, u32::MAX),
+			Error::<Test>::NoCounterOffer
+		);
+		assert_noop!(
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([2; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::Counter(1500)
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			NftMarketplace::accept_counter_offer(RuntimeOrigin::signed([3; 32].into()), 1, u32::MAX),
+			Error::<Test>::InvalidIndex
+		);
+	})
+}
+
+#[test]
+fn partial_accept_offer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Secondary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(0),
+				treasury_fee: Percent::from_percent(1),
+			}
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 2, None));
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_145_920);
+		let seller_balance = Assets::balance(1, &[1; 32].into());
+		let treasury_balance = Assets::balance(1, &NftMarketplace::treasury_account_id());
+		assert_ok!(NftMarketplace::handle_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			1,
+			[2; 32].into(),
+			crate::Offer::PartialAccept(1)
+		));
+		assert_eq!(OngoingOffers::<Test>::get::<u32, AccountId>(1, [2; 32].into()).is_none(), true);
+		assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 1);
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()), 1);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_147_960);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), seller_balance + 1_980);
+		assert_eq!(
+			Assets::balance(1, &NftMarketplace::treasury_account_id()),
+			treasury_balance + 60
+		);
+	})
+}
+
+#[test]
+fn partial_accept_offer_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 2000, 2, None));
+		assert_noop!(
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([1; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::PartialAccept(2)
+			),
+			Error::<Test>::InvalidPartialAmount
+		);
+		assert_noop!(
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([1; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::PartialAccept(0)
+			),
+			Error::<Test>::InvalidPartialAmount
+		);
+	})
+}
+
+// relist_token_dutch_auction function
+#[test]
+fn relist_token_dutch_auction_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token_dutch_auction(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			1000,
+			500,
+			10,
+			10,
+			1
+		));
+		System::assert_last_event(
+			crate::Event::DutchAuctionListed {
+				listing_id: 1,
+				asset_id: 0,
+				start_price: 1000,
+				floor_price: 500,
+				end_block: 11,
+				seller: [1; 32].into(),
+			}
+			.into(),
+		);
+		assert_eq!(NftMarketplace::current_token_price(&TokenListings::<Test>::get(1).unwrap()), Ok(1000));
+		run_to_block(6);
+		assert_eq!(NftMarketplace::current_token_price(&TokenListings::<Test>::get(1).unwrap()), Ok(750));
+		let seller_balance = Assets::balance(1, &[1; 32].into());
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 2, u32::MAX));
+		System::assert_has_event(
+			crate::Event::TokenBought { asset_id: 0, buyer: [2; 32].into(), price: 750 }.into(),
+		);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_148_500);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), seller_balance + 1_485);
+		assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 8);
+		run_to_block(20);
+		assert_eq!(NftMarketplace::current_token_price(&TokenListings::<Test>::get(1).unwrap()), Ok(500));
+	})
+}
+
+#[test]
+fn relist_token_dutch_auction_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_noop!(
+			NftMarketplace::relist_token_dutch_auction(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				0,
+				500,
+				500,
+				10,
+				10,
+				1
+			),
+			Error::<Test>::InvalidAuctionParameters
+		);
+		assert_noop!(
+			NftMarketplace::relist_token_dutch_auction(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				0,
+				1000,
+				500,
+				0,
+				10,
+				1
+			),
+			Error::<Test>::InvalidAuctionParameters
+		);
+		assert_ok!(NftMarketplace::relist_token_dutch_auction(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			1000,
+			500,
+			10,
+			10,
+			1
+		));
+		assert_noop!(
+			NftMarketplace::upgrade_listing(RuntimeOrigin::signed([1; 32].into()), 1, 800),
+			Error::<Test>::PriceSetByAuction
+		);
+	})
+}
+
+// list_object_for_auction function
+#[test]
+fn auction_allocates_token_to_highest_bids() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		System::assert_last_event(
+			crate::Event::AuctionStarted {
+				listing_id: 0,
+				reserve_price: 1_000,
+				end_block: 11,
+				seller: [0; 32].into(),
+			}
+			.into(),
+		);
+		assert_eq!(ObjectAuctions::<Test>::get(0), Some(11));
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().listing_expiry, 41);
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([3; 32].into()), 0, 1_000, 4, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_100, 60, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_200, 60, None));
+		System::assert_last_event(
+			crate::Event::BidPlaced {
+				listing_id: 0,
+				bidder: [1; 32].into(),
+				token_price: 1_200,
+				amount: 60,
+			}
+			.into(),
+		);
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 3);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_425_120);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_081_360);
+		assert_eq!(Assets::balance(1, &[3; 32].into()), 840);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 147_680);
+		run_to_block(11);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 100 }.into());
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_425_120);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_104_240);
+		assert_eq!(Assets::balance(1, &[3; 32].into()), 5_000);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 120_640);
+		assert_eq!(ObjectAuctions::<Test>::get(0), None);
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 0);
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert!(PropertyLawyer::<Test>::get(0).is_some());
+		assert_eq!(TokenBuyer::<Test>::get(0).len(), 2);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 60);
+		let token_owner = TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([2; 32].into(), 0);
+		assert_eq!(token_owner.token_amount, 40);
+		assert_eq!(token_owner.paid_funds, 44_000);
+		assert_eq!(token_owner.paid_tax, 1_320);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([3; 32].into(), 0).token_amount, 0);
+		let nft_details = OngoingObjectListing::<Test>::get(0).unwrap();
+		assert_eq!(nft_details.collected_funds, 116_000);
+		assert_eq!(nft_details.collected_fees, 1_160);
+		assert_eq!(nft_details.collected_tax, 3_480);
+	})
+}
+
+#[test]
+fn auction_keeps_unsold_token_listed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 30, None));
+		run_to_block(11);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 30 }.into());
+		assert_eq!(ListedToken::<Test>::get(0), Some(70));
+		assert_eq!(PropertyLawyer::<Test>::get(0), None);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_468_800);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 70, u32::MAX));
+		assert_eq!(ListedToken::<Test>::get(0), None);
+		assert!(PropertyLawyer::<Test>::get(0).is_some());
+		assert_eq!(OngoingObjectListing::<Test>::get(0).unwrap().collected_funds, 100_000);
+	})
+}
+
+#[test]
+fn place_bid_respects_max_total_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_000, 10, Some(10_400)));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Primary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(3),
+				treasury_fee: Percent::from_percent(1),
+			}
+		));
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 30, Some(31_200)),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 30, Some(31_500)));
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_468_500);
+	})
+}
+
+#[test]
+fn auction_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([4; 32].into()), 0, 1_000, 10, None),
+			Error::<Test>::UserNotWhitelisted
+		);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 1, 1_000, 10, None),
+			Error::<Test>::AuctionNotFound
+		);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 999, 10, None),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 0, None),
+			Error::<Test>::InvalidBidAmount
+		);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 101, None),
+			Error::<Test>::InvalidBidAmount
+		);
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 10, None));
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 10, None),
+			Error::<Test>::BidNotRaised
+		);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_100, 9, None),
+			Error::<Test>::BidNotRaised
+		);
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 10, u32::MAX),
+			Error::<Test>::ListingInAuction
+		);
+		assert_noop!(
+			NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 2_000),
+			Error::<Test>::ListingInAuction
+		);
+		assert_noop!(
+			NftMarketplace::cancel_object_listing(RuntimeOrigin::signed([0; 32].into()), 0),
+			Error::<Test>::ListingInAuction
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([0; 32].into()), 0, 1_000, 10, None),
+			Error::<Test>::AuctionEnded
+		);
+	})
+}
+
+#[test]
+fn place_bid_raises_previous_bid() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 60, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_100, 60, None));
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_437_600);
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_200, 60, None));
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 2);
+		assert_eq!(AuctionBids::<Test>::get(0)[1].token_price, 1_200);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_425_120);
+		assert_eq!(Assets::balance(1, &NftMarketplace::account_id()), 143_520);
+		run_to_block(11);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 100 }.into());
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 60);
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([2; 32].into(), 0).token_amount, 40);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_425_120);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_104_240);
+	})
+}
+
+#[test]
+fn place_bid_outbids_lowest_bid_of_full_auction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		for i in 20..120u8 {
+			assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [i; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed([0; 32].into()), 1, [i; 32].into(), 1_040));
+			assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([i; 32].into()), 0, 1_000, 1, None));
+		}
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 100);
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 1, None),
+			Error::<Test>::TooManyBids
+		);
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_100, 1, None));
+		System::assert_has_event(
+			crate::Event::BidOutbid { listing_id: 0, bidder: [119; 32].into() }.into(),
+		);
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 100);
+		assert_eq!(AuctionBids::<Test>::get(0)[99].bidder, [1; 32].into());
+		assert_eq!(Assets::balance(1, &[119; 32].into()), 1_040);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_498_856);
+	})
+}
+
+#[test]
+fn enforce_compliance_counts_failed_bid_refunds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 30, None));
+		assert_ok!(XcavateWhitelist::remove_from_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed([0; 32].into()), 1));
+		assert_ok!(NftMarketplace::enforce_compliance(
+			RuntimeOrigin::signed([0; 32].into()),
+			[1; 32].into(),
+			bvec![0]
+		));
+		System::assert_has_event(
+			crate::Event::BidRefundFailed { listing_id: 0, bidder: [1; 32].into() }.into(),
+		);
+		System::assert_last_event(
+			crate::Event::ComplianceEnforced {
+				account: [1; 32].into(),
+				returned_listings: 0,
+				refunded_offers: 0,
+				refunded_bids: 0,
+				failed_refunds: 1,
+				refunded_purchases: 0,
+			}
+			.into(),
+		);
+		assert_eq!(AuctionBids::<Test>::get(0).len(), 0);
+		assert_eq!(FailedBidRefunds::<Test>::get::<AccountId, u32>([1; 32].into(), 1), 31_200);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_468_800);
+	})
+}
+
+#[test]
+fn failed_bid_refund_can_be_claimed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 60, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_100, 60, None));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed([0; 32].into()), 1));
+		run_to_block(11);
+		System::assert_has_event(
+			crate::Event::BidRefundFailed { listing_id: 0, bidder: [1; 32].into() }.into(),
+		);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 100 }.into());
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 40);
+		assert_eq!(FailedBidRefunds::<Test>::get::<AccountId, u32>([1; 32].into(), 1), 20_800);
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed([0; 32].into()), 1));
+		assert_ok!(NftMarketplace::claim_bid_refund(RuntimeOrigin::signed([1; 32].into()), 1));
+		System::assert_last_event(
+			crate::Event::BidRefundClaimed { bidder: [1; 32].into(), payment_asset: 1, amount: 20_800 }
+				.into(),
+		);
+		assert_eq!(FailedBidRefunds::<Test>::get::<AccountId, u32>([1; 32].into(), 1), 0);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_458_400);
+		assert_noop!(
+			NftMarketplace::claim_bid_refund(RuntimeOrigin::signed([1; 32].into()), 1),
+			Error::<Test>::NoBidRefund
+		);
+	})
+}
+
+// buy_batch function
+#[test]
+fn buy_batch_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_batch(
+			RuntimeOrigin::signed([2; 32].into()),
+			bvec![(2, 10, 104_000), (1, 2, 1_000)]
+		));
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_045_000);
+		assert_eq!(ListedToken::<Test>::get(2), Some(90));
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([2; 32].into(), 2).token_amount, 10);
+		assert_eq!(TokenListings::<Test>::get(1), None);
+		assert_eq!(Assets::balance(0, &[2; 32].into()), 2);
+	})
+}
+
+#[test]
+fn buy_batch_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::buy_batch(RuntimeOrigin::signed([4; 32].into()), bvec![(2, 10, 104_000)]),
+			Error::<Test>::UserNotWhitelisted
+		);
+		assert_noop!(
+			NftMarketplace::buy_batch(RuntimeOrigin::signed([2; 32].into()), bvec![(5, 10, 104_000)]),
+			Error::<Test>::TokenNotForSale
+		);
+		assert_noop!(
+			NftMarketplace::buy_batch(
+				RuntimeOrigin::signed([2; 32].into()),
+				bvec![(1, 2, 1_000), (2, 10, 103_999)]
+			),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_noop!(
+			NftMarketplace::buy_batch(
+				RuntimeOrigin::signed([2; 32].into()),
+				bvec![(2, 10, 104_000), (1, 3, 1_500)]
+			),
+			Error::<Test>::NotEnoughTokenAvailable
+		);
+		assert_eq!(TokenListings::<Test>::get(1).unwrap().amount, 2);
+		assert_eq!(ListedToken::<Test>::get(2), Some(100));
+		// the dispatchable reverts its events together with the batch, the batch logic reports
+		// the failing purchase with the original error.
+		assert_eq!(
+			NftMarketplace::do_buy_batch([2; 32].into(), bvec![(2, 10, 104_000), (1, 3, 1_500)]),
+			Err(Error::<Test>::NotEnoughTokenAvailable.into())
+		);
+		System::assert_last_event(
+			crate::Event::BatchItemFailed {
+				index: 1,
+				listing_id: 1,
+				error: Error::<Test>::NotEnoughTokenAvailable.into(),
+			}
+			.into(),
+		);
+	})
+}
+
+// max_total_price of the purchase calls
+#[test]
+fn buy_token_respects_max_total_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::upgrade_object(RuntimeOrigin::signed([0; 32].into()), 0, 12_000));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 10, 104_000),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 10, 124_800));
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_375_200);
+	})
+}
+
+#[test]
+fn buy_relisted_token_respects_max_total_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Secondary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(0),
+				treasury_fee: Percent::from_percent(1),
+			}
+		));
+		assert_ok!(NftMarketplace::upgrade_listing(RuntimeOrigin::signed([1; 32].into()), 1, 600));
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 2, 1_020),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 2, 1_223),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([2; 32].into()), 1, 2, 1_224));
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_148_776);
+	})
+}
+
+#[test]
+fn accept_counter_offer_respects_max_total_price() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		assert_ok!(NftMarketplace::set_fee_schedule(
+			RuntimeOrigin::root(),
+			SaleType::Secondary,
+			FeeDetails {
+				buyer_fee: Percent::from_percent(2),
+				tax: Percent::from_percent(0),
+				treasury_fee: Percent::from_percent(1),
+			}
+		));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 400, 2, None));
+		assert_ok!(NftMarketplace::handle_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			1,
+			[2; 32].into(),
+			crate::Offer::Counter(450)
+		));
+		assert_noop!(
+			NftMarketplace::accept_counter_offer(RuntimeOrigin::signed([2; 32].into()), 1, 917),
+			Error::<Test>::PriceLimitExceeded
+		);
+		assert_ok!(NftMarketplace::accept_counter_offer(RuntimeOrigin::signed([2; 32].into()), 1, 918));
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [2; 32].into()), 2);
+		assert_eq!(Assets::balance(1, &[2; 32].into()), 1_149_082);
+	})
+}
+
+// runtime api queries
+#[test]
+fn runtime_api_queries_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
 		));
-		assert_eq!(Assets::balance(2, &[2; 32].into()), 100);
-		assert_eq!(Assets::balance(3, &[2; 32].into()), 100);
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 2, 10, u32::MAX));
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([3; 32].into()), 1, 400, 1, None));
+		let page = NftMarketplace::listings(0, None, 0, 10);
+		assert_eq!(page.listings.iter().map(|listing| listing.listing_id).collect::<Vec<_>>(), vec![1, 2]);
+		assert_eq!(page.next, None);
+		let page = NftMarketplace::listings(0, None, 0, 1);
+		assert_eq!(page.listings[0].listing_id, 1);
+		assert_eq!(page.next, Some(2));
+		assert_eq!(NftMarketplace::listings(0, None, 2, 10).listings[0].listing_id, 2);
+		assert_eq!(NftMarketplace::listings(0, Some(vec![10, 10]), 0, 10).listings.len(), 2);
+		assert_eq!(NftMarketplace::listings(0, Some(vec![20, 20]), 0, 10).listings.len(), 0);
+		assert_eq!(NftMarketplace::listings(1, None, 0, 10).listings.len(), 0);
+		let listing = NftMarketplace::listing(1).unwrap();
+		assert_eq!(listing.listing_type, ListingType::Relisted);
+		assert_eq!(listing.seller, [1; 32].into());
+		assert_eq!(listing.token_price, 500);
+		assert_eq!(listing.remaining_token, 2);
+		let listing = NftMarketplace::listing(2).unwrap();
+		assert_eq!(listing.listing_type, ListingType::Object);
+		assert_eq!(listing.asset_id, 1);
+		assert_eq!(listing.remaining_token, 90);
+		assert_eq!(listing.expiry, Some(31));
+		assert_eq!(NftMarketplace::listing(0), None);
+		let offers = NftMarketplace::offers(1);
+		assert_eq!(offers.len(), 1);
+		assert_eq!(offers[0].offeror, [3; 32].into());
+		assert_eq!(offers[0].token_price, 400);
+		assert_eq!(offers[0].counter_price, None);
+		assert_eq!(NftMarketplace::offers(2).len(), 0);
+		let holdings = NftMarketplace::holdings([1; 32].into());
+		assert_eq!(holdings.len(), 1);
+		assert_eq!(holdings[0].asset_id, 0);
+		assert_eq!(holdings[0].status, HoldingStatus::Settled);
+		let holdings = NftMarketplace::holdings([2; 32].into());
+		assert_eq!(holdings.len(), 1);
+		assert_eq!(holdings[0].asset_id, 1);
+		assert_eq!(holdings[0].token_amount, 10);
+		assert_eq!(
+			holdings[0].status,
+			HoldingStatus::Pending { listing_id: 2, paid_funds: 100_000, paid_tax: 3_000 }
+		);
+	})
+}
+
+// set_investor_limit function
+#[test]
+fn set_investor_limit_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::set_investor_limit(RuntimeOrigin::signed([0; 32].into()), 0, Some(30)));
+		assert_eq!(InvestorLimits::<Test>::get(0), Some(30));
+		System::assert_last_event(
+			crate::Event::InvestorLimitSet { listing_id: 0, asset_id: 0, max_token: Some(30) }.into(),
+		);
+		assert_ok!(NftMarketplace::set_investor_limit(RuntimeOrigin::signed([0; 32].into()), 0, None));
+		assert_eq!(InvestorLimits::<Test>::get(0), None);
+	})
+}
+
+#[test]
+fn set_investor_limit_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::set_investor_limit(RuntimeOrigin::signed([1; 32].into()), 0, Some(30)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			NftMarketplace::set_investor_limit(RuntimeOrigin::signed([0; 32].into()), 0, Some(0)),
+			Error::<Test>::InvalidInvestorLimit
+		);
+		assert_noop!(
+			NftMarketplace::set_investor_limit(RuntimeOrigin::signed([0; 32].into()), 1, Some(30)),
+			Error::<Test>::TokenNotForSale
+		);
+	})
+}
+
+#[test]
+fn buy_token_respects_investor_limit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::set_investor_limit(RuntimeOrigin::signed([0; 32].into()), 0, Some(30)));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20, u32::MAX));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 11, u32::MAX),
+			Error::<Test>::InvestorLimitExceeded
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 10, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 30, u32::MAX));
+		assert_eq!(ListedToken::<Test>::get(0), Some(40));
+	})
+}
+
+#[test]
+fn buy_token_respects_concentration_cap() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		MaxOwnershipShare::set(&Percent::from_percent(25));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 26, u32::MAX),
+			Error::<Test>::ConcentrationCapExceeded
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 25, u32::MAX));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 1, u32::MAX),
+			Error::<Test>::ConcentrationCapExceeded
+		);
+	})
+}
+
+#[test]
+fn concentration_cap_allows_one_token_of_small_listings() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			3,
+			bvec![22, 22],
+			1,
+			None
+		));
+		MaxOwnershipShare::set(&Percent::from_percent(25));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 2, u32::MAX),
+			Error::<Test>::ConcentrationCapExceeded
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 1, u32::MAX));
+		assert_eq!(ListedToken::<Test>::get(0), Some(2));
+	})
+}
+
+#[test]
+fn secondary_purchases_respect_concentration_cap() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [5; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			crate::LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			crate::LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::relist_token(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			0,
+			500,
+			2,
+			1
+		));
+		MaxOwnershipShare::set(&Percent::from_percent(1));
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 2, u32::MAX),
+			Error::<Test>::ConcentrationCapExceeded
+		);
+		assert_ok!(NftMarketplace::make_offer(RuntimeOrigin::signed([2; 32].into()), 1, 500, 2, None));
+		assert_noop!(
+			NftMarketplace::handle_offer(
+				RuntimeOrigin::signed([1; 32].into()),
+				1,
+				[2; 32].into(),
+				crate::Offer::Accept
+			),
+			Error::<Test>::ConcentrationCapExceeded
+		);
+		assert_ok!(NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, u32::MAX));
+		assert_eq!(PropertyOwnerToken::<Test>::get::<u32, AccountId>(0, [3; 32].into()), 1);
+		assert_noop!(
+			NftMarketplace::buy_relisted_token(RuntimeOrigin::signed([3; 32].into()), 1, 1, u32::MAX),
+			Error::<Test>::ConcentrationCapExceeded
+		);
+	})
+}
+
+#[test]
+fn buy_token_respects_kyc_tier_limits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(
+			RuntimeOrigin::root(),
+			[2; 32].into(),
+			KycTier::Institutional,
+			*b"GB",
+			u64::MAX
+		));
+		assert_ok!(XcavateWhitelist::set_tier_limits(
+			RuntimeOrigin::root(),
+			KycTier::Retail,
+			Some(pallet_xcavate_whitelist::InvestmentLimits {
+				per_transaction: 50_000,
+				annual: 80_000
+			})
+		));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 6, u32::MAX),
+			pallet_xcavate_whitelist::Error::<Test>::TransactionLimitExceeded
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 5, u32::MAX));
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 4, u32::MAX),
+			pallet_xcavate_whitelist::Error::<Test>::AnnualLimitExceeded
+		);
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 3, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 20, u32::MAX));
+		assert_eq!(ListedToken::<Test>::get(0), Some(72));
+	})
+}
+
+#[test]
+fn auction_respects_kyc_tier_limits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(
+			RuntimeOrigin::root(),
+			[2; 32].into(),
+			KycTier::Institutional,
+			*b"GB",
+			u64::MAX
+		));
+		assert_ok!(XcavateWhitelist::set_tier_limits(
+			RuntimeOrigin::root(),
+			KycTier::Retail,
+			Some(pallet_xcavate_whitelist::InvestmentLimits {
+				per_transaction: 50_000,
+				annual: 80_000
+			})
+		));
+		assert_ok!(NftMarketplace::list_object_for_auction(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1
+		));
+		assert_noop!(
+			NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 60, None),
+			pallet_xcavate_whitelist::Error::<Test>::TransactionLimitExceeded
+		);
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([1; 32].into()), 0, 1_000, 50, None));
+		assert_ok!(NftMarketplace::place_bid(RuntimeOrigin::signed([2; 32].into()), 0, 1_000, 30, None));
+		assert_eq!(pallet_xcavate_whitelist::AnnualInvestments::<Test>::get(AccountId::from([1; 32])).invested, 0);
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			1_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 40, u32::MAX));
+		run_to_block(11);
+		System::assert_last_event(crate::Event::AuctionFinished { listing_id: 0, sold_token: 30 }.into());
+		assert_eq!(ListedToken::<Test>::get(0), Some(70));
+		assert_eq!(TokenOwner::<Test>::get::<AccountId, ItemId<Test>>([1; 32].into(), 0).token_amount, 0);
+		assert_eq!(pallet_xcavate_whitelist::AnnualInvestments::<Test>::get(AccountId::from([1; 32])).invested, 40_000);
+		assert_eq!(Assets::balance(1, &[1; 32].into()), 1_458_400);
+	})
+}
+
+#[test]
+fn buy_token_fails_with_expired_kyc() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", 5));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 10, u32::MAX));
+		run_to_block(5);
+		assert_noop!(
+			NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 10, u32::MAX),
+			Error::<Test>::UserNotWhitelisted
+		);
+	})
+}
+
+// migrations
+#[test]
+fn migrate_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		StorageVersion::new(0).put::<NftMarketplace>();
+		PaymentAssets::<Test>::remove(1);
+		let old_listing = v1::OldNftListingDetails {
+			real_estate_developer: AccountId::from([0; 32]),
+			token_price: 1_000u32,
+			collected_funds: 2_000,
+			collected_tax: 60,
+			collected_fees: 20,
+			asset_id: 0,
+			item_id: 0u32,
+			collection_id: 0u32,
+			token_amount: 100,
+		};
+		unhashed::put(&OngoingObjectListing::<Test>::hashed_key_for(0), &old_listing);
+		let old_token_owner = v1::OldTokenOwnerDetails { token_amount: 1, paid_funds: 1_000u32, paid_tax: 30 };
+		unhashed::put(
+			&TokenOwner::<Test>::hashed_key_for(AccountId::from([3; 32]), 0),
+			&old_token_owner,
+		);
+		let old_token_listing = v1::OldTokenListingDetails {
+			seller: AccountId::from([1; 32]),
+			token_price: 500u32,
+			asset_id: 0,
+			item_id: 0u32,
+			collection_id: 0u32,
+			amount: 10,
+		};
+		unhashed::put(&TokenListings::<Test>::hashed_key_for(1), &old_token_listing);
+		let old_offer = v1::OldOfferDetails {
+			buyer: AccountId::from([2; 32]),
+			token_price: 400u32,
+			amount: 5,
+		};
+		unhashed::put(
+			&OngoingOffers::<Test>::hashed_key_for(1, AccountId::from([2; 32])),
+			&old_offer,
+		);
+
+		let old_property_lawyer = v1::OldPropertyLawyerDetails {
+			real_estate_developer_lawyer: Some(AccountId::from([10; 32])),
+			spv_lawyer: None,
+			real_estate_developer_status: crate::DocumentStatus::Approved,
+			spv_status: crate::DocumentStatus::Pending,
+			real_estate_developer_lawyer_costs: 10u32,
+			spv_lawyer_costs: 0,
+			second_attempt: false,
+		};
+		unhashed::put(&PropertyLawyer::<Test>::hashed_key_for(0), &old_property_lawyer);
+		unhashed::put(&RealEstateLawyer::<Test>::hashed_key_for(AccountId::from([10; 32])), &true);
+		unhashed::put(&RealEstateLawyer::<Test>::hashed_key_for(AccountId::from([11; 32])), &true);
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(NftMarketplace::on_chain_storage_version(), 1);
+		assert_eq!(PaymentAssets::<Test>::get(1), true);
+		let listing = OngoingObjectListing::<Test>::get(0).unwrap();
+		assert_eq!(listing.collected_funds, 2_000);
+		assert_eq!(listing.payment_asset, 1);
+		assert_eq!(listing.listing_expiry, 35);
+		let token_owner = TokenOwner::<Test>::get(AccountId::from([3; 32]), 0);
+		assert_eq!(token_owner.paid_tax, 30);
+		assert_eq!(token_owner.paid_fee, 10);
+		let token_listing = TokenListings::<Test>::get(1).unwrap();
+		assert_eq!(token_listing.amount, 10);
+		assert_eq!(token_listing.payment_asset, 1);
+		assert_eq!(token_listing.dutch_auction, None);
+		let offer = OngoingOffers::<Test>::get(1, AccountId::from([2; 32])).unwrap();
+		assert_eq!(offer.get_escrowed_amount().unwrap(), 2_000);
+		assert_eq!(offer.expiry, 25);
+		assert_eq!(OffersExpiring::<Test>::get(25).len(), 1);
+		let property_lawyer = PropertyLawyer::<Test>::get(0).unwrap();
+		assert_eq!(property_lawyer.real_estate_developer_lawyer, Some([10; 32].into()));
+		assert_eq!(property_lawyer.real_estate_developer_lawyer_costs, 10);
+		assert_eq!(property_lawyer.real_estate_developer_deadline, None);
+		assert_eq!(property_lawyer.review_round, 1);
+		let lawyer = RealEstateLawyer::<Test>::get(AccountId::from([10; 32])).unwrap();
+		assert_eq!(lawyer.regions.into_inner(), vec![0]);
+		assert_eq!(lawyer.bond, 0);
+		assert_eq!(lawyer.active_cases, 1);
+		assert_eq!(RealEstateLawyer::<Test>::get(AccountId::from([11; 32])).unwrap().active_cases, 0);
+
+		// the migration only runs once.
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(OffersExpiring::<Test>::get(25).len(), 1);
 	})
 }
- 
\ No newline at end of file
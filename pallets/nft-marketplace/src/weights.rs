@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `recrafter-Legion-5-16IRX9`, CPU: `Intel(R) Core(TM) i7-14650HX`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The weights in the section marked as not benchmarked have not been generated by the
+//! benchmark CLI. They are estimates that get replaced by the next benchmark run.
 
 // Executed Command:
 // ./target/release/node-template
@@ -45,6 +48,16 @@ pub trait WeightInfo {
 	fn delist_token() -> Weight;
 	fn create_new_location() -> Weight;
 	fn create_new_region() -> Weight;
+	fn expire_offer() -> Weight;
+	fn refund_offer() -> Weight;
+	fn claim_bid_refund() -> Weight;
+	fn review_timeout(b: u32, ) -> Weight;
+	fn expire_arbitration(b: u32, ) -> Weight;
+	fn finish_soft_cap(b: u32, ) -> Weight;
+	fn lawyer_confirm_documents(b: u32, ) -> Weight;
+	fn release_quarantined_funds() -> Weight;
+	fn set_transfer_fee() -> Weight;
+	fn transfer_property_token() -> Weight;
 }
 
 /// Weight functions for `pallet_nft_marketplace`.
@@ -344,4 +357,158 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
+
+	// NOT BENCHMARKED: the weights below are estimated from the storage accesses of their
+	// benchmarks and the measured weights of calls doing the same work.
+
+	/// Estimated from `cancel_offer`.
+	/// Storage: `NftMarketplace::OngoingOffers` (r:1 w:1)
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1 w:0)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	fn expire_offer() -> Weight {
+		Weight::from_parts(92_758_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Estimated from `cancel_offer`.
+	/// Storage: `NftMarketplace::OngoingOffers` (r:1 w:1)
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1 w:0)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	fn refund_offer() -> Weight {
+		Weight::from_parts(92_758_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Estimated from `cancel_offer`.
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1 w:0)
+	/// Storage: `NftMarketplace::FailedBidRefunds` (r:1 w:1)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	fn claim_bid_refund() -> Weight {
+		Weight::from_parts(92_758_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Estimated from `buy_token` and from `cancel_offer` per refunded investor.
+	/// Storage: `NftMarketplace::PropertyLawyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::OngoingObjectListing` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenBuyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenOwner` (r:b w:b)
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1+b w:0)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:2+b w:2+b)
+	/// The range of component `b` is `[1, 100]`.
+	fn review_timeout(b: u32, ) -> Weight {
+		Weight::from_parts(277_998_000, 0)
+			.saturating_add(Weight::from_parts(0, 11487))
+			.saturating_add(Weight::from_parts(92_758_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(14))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(b.into()))
+	}
+	/// Estimated from `buy_token` and from `cancel_offer` per refunded investor.
+	/// Storage: `NftMarketplace::PropertyLawyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::OngoingObjectListing` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenBuyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenOwner` (r:b w:b)
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1+b w:0)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:2+b w:2+b)
+	/// The range of component `b` is `[1, 100]`.
+	fn expire_arbitration(b: u32, ) -> Weight {
+		Weight::from_parts(277_998_000, 0)
+			.saturating_add(Weight::from_parts(0, 11487))
+			.saturating_add(Weight::from_parts(92_758_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(14))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(b.into()))
+	}
+	/// Estimated from `buy_token` and from `cancel_offer` per refunded investor.
+	/// Storage: `NftMarketplace::PropertyLawyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::OngoingObjectListing` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenBuyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenOwner` (r:b w:b)
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1+b w:0)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:2+b w:2+b)
+	/// The range of component `b` is `[1, 100]`.
+	fn finish_soft_cap(b: u32, ) -> Weight {
+		Weight::from_parts(277_998_000, 0)
+			.saturating_add(Weight::from_parts(0, 11487))
+			.saturating_add(Weight::from_parts(92_758_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(14))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(b.into()))
+	}
+	/// Estimated from `buy_token` and from `cancel_offer` per investor that receives its token.
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:4+b w:0)
+	/// Storage: `NftMarketplace::PropertyLawyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::LegalDocuments` (r:0 w:1)
+	/// Storage: `NftMarketplace::RealEstateLawyer` (r:2 w:2)
+	/// Storage: `NftMarketplace::OngoingObjectListing` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenBuyer` (r:1 w:1)
+	/// Storage: `NftMarketplace::TokenOwner` (r:b w:b)
+	/// Storage: `NftMarketplace::PropertyOwner` (r:1 w:1)
+	/// Storage: `NftMarketplace::PropertyOwnerToken` (r:0 w:b)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:6+b w:6+b)
+	/// The range of component `b` is `[1, 100]`.
+	fn lawyer_confirm_documents(b: u32, ) -> Weight {
+		Weight::from_parts(463_514_000, 0)
+			.saturating_add(Weight::from_parts(0, 17695))
+			.saturating_add(Weight::from_parts(92_758_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(b.into()))
+	}
+	/// Estimated from `cancel_offer`.
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:1 w:0)
+	/// Storage: `NftMarketplace::QuarantinedFunds` (r:1 w:1)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	fn release_quarantined_funds() -> Weight {
+		Weight::from_parts(92_758_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Estimated from `create_new_location`.
+	/// Storage: `NftMarketplace::PaymentAssets` (r:1 w:0)
+	/// Storage: `NftMarketplace::TransferFee` (r:0 w:1)
+	fn set_transfer_fee() -> Weight {
+		Weight::from_parts(26_397_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated from `buy_relisted_token`.
+	/// Storage: `XcavateWhitelist::BlockedAccounts` (r:2 w:0)
+	/// Storage: `XcavateWhitelist::WhitelistedAccounts` (r:2 w:0)
+	/// Storage: `NftMarketplace::AssetIdDetails` (r:1 w:0)
+	/// Storage: `NftMarketplace::PropertyOwnerToken` (r:2 w:2)
+	/// Storage: `NftMarketplace::InvestorLimits` (r:1 w:0)
+	/// Storage: `NftMarketplace::PaymentAssets` (r:1 w:0)
+	/// Storage: `NftMarketplace::TransferFee` (r:1 w:0)
+	/// Storage: `NftMarketplace::PropertyOwner` (r:1 w:1)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Storage: `Assets::Account` (r:4 w:4)
+	fn transfer_property_token() -> Weight {
+		Weight::from_parts(184_037_000, 0)
+			.saturating_add(Weight::from_parts(0, 11487))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
 }
@@ -18,7 +18,7 @@ type DepositBalanceOf1<T> = <<T as pallet_property_management::Config>::Currency
 type DepositBalanceOf2<T> = <<T as pallet::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
-use pallet_xcavate_whitelist::Pallet as Whitelist;
+use pallet_xcavate_whitelist::{KycTier, Pallet as Whitelist};
 use pallet_property_management::Pallet as PropertyManagement;
 use frame_support::{traits::Get, assert_ok};
 use frame_support::BoundedVec;
@@ -47,7 +47,13 @@ fn setup_real_estate_object<T: Config>() -> T::AccountId {
 	assert_ok!(NftMarketplace::<T>::create_new_location(RawOrigin::Root.into(), 0, location.clone()));
 
 	// Add caller to whitelist
-	assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
+	assert_ok!(Whitelist::<T>::add_to_whitelist(
+		RawOrigin::Root.into(),
+		caller.clone(),
+		KycTier::Retail,
+		*b"GB",
+		Bounded::max_value()
+	));
 	let user_lookup = <T::Lookup as StaticLookup>::unlookup(caller.clone());
 	let asset_id = <T as pallet::Config>::Helper::to_asset(1);
 	
@@ -56,8 +62,8 @@ fn setup_real_estate_object<T: Config>() -> T::AccountId {
 	assert_ok!(Assets::<T, Instance1>::mint(RawOrigin::Signed(caller.clone()).into(), asset_id.clone().into(), user_lookup, 1_000_000_000u32.into()));
 
 	// List and buy object
-	assert_ok!(NftMarketplace::<T>::list_object(RawOrigin::Signed(caller.clone()).into(), 0, location.clone(), value.into(), 100, vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize].try_into().unwrap()));
-	assert_ok!(NftMarketplace::<T>::buy_token(RawOrigin::Signed(caller.clone()).into(), 0, 100));
+	assert_ok!(NftMarketplace::<T>::list_object(RawOrigin::Signed(caller.clone()).into(), 0, location.clone(), value.into(), 100, vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize].try_into().unwrap(), 1, None));
+	assert_ok!(NftMarketplace::<T>::buy_token(RawOrigin::Signed(caller.clone()).into(), 0, 100, Bounded::max_value()));
 
 	// Setup the letting agent with sufficient balance
 	let letting_agent: T::AccountId = whitelisted_caller();
@@ -71,6 +71,29 @@ pub mod pallet {
 		pub proposal_info: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
 	}
 
+	/// Infos regarding an open proposal returned by the runtime api.
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(
+			serialize = "AccountId: serde::Serialize, Balance: std::fmt::Display, \
+				BlockNumber: serde::Serialize",
+			deserialize = "AccountId: serde::Deserialize<'de>, Balance: std::str::FromStr, \
+				BlockNumber: serde::Deserialize<'de>"
+		))
+	)]
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ProposalInfo<AccountId, Balance, BlockNumber> {
+		pub proposal_id: ProposalIndex,
+		pub proposer: AccountId,
+		#[cfg_attr(feature = "std", serde(with = "pallet_nft_marketplace::serde_balance"))]
+		pub amount: Balance,
+		pub created_at: BlockNumber,
+		pub proposal_info: sp_std::vec::Vec<u8>,
+		pub yes_voting_power: u32,
+		pub no_voting_power: u32,
+	}
+
 	/// Sell proposal with the proposal Details.
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -328,6 +351,8 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// The user is not a property owner and has no permission to propose.
 		NoPermission,
+		/// The user is not whitelisted.
+		UserNotWhitelisted,
 		/// There are already too many proposals in the ending block.
 		TooManyProposals,
 		/// The proposal is not ongoing.
@@ -348,18 +373,23 @@ pub mod pallet {
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
 			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			// the whitelist status, the vote and the token of every voter get checked.
+			let voters_weight =
+				T::DbWeight::get().reads(4).saturating_mul(T::MaxVoter::get().into());
 
 			let ended_votings = ProposalRoundsExpiring::<T>::take(n);
 			// checks if there is a voting for a proposal ending in this block.
 			ended_votings.iter().for_each(|item| {
-				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));	
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+				weight = weight.saturating_add(voters_weight);
 				let _ = Self::finish_proposal(*item);					
 			});
 
 			let ended_challenge_votings = ChallengeRoundsExpiring::<T>::take(n);
 			// checks if there is a voting for an challenge ending in this block.
 			ended_challenge_votings.iter().for_each(|item| {
-				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+				weight = weight.saturating_add(voters_weight);
 				let _ = Self::finish_challenge(*item);
 			});
 			weight
@@ -388,6 +418,7 @@ pub mod pallet {
 			data: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(
 				pallet_property_management::LettingStorage::<T>::get(asset_id)
 					.ok_or(Error::<T>::NoLettingAgentFound)?
@@ -443,6 +474,7 @@ pub mod pallet {
 			asset_id: u32,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			let owner_list = pallet_nft_marketplace::PropertyOwner::<T>::get(asset_id);
 			ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
 			ensure!(pallet_property_management::LettingStorage::<T>::get(asset_id).is_some(), Error::<T>::NoLettingAgentFound);
@@ -483,6 +515,11 @@ pub mod pallet {
 			vote: Vote,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
 			let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::NotOngoing)?;
 			let owner_list = pallet_nft_marketplace::PropertyOwner::<T>::get(proposal.asset_id);
 			ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
@@ -532,6 +569,11 @@ pub mod pallet {
 			vote: Vote,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
+			ensure!(
+				pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(signer.clone()),
+				Error::<T>::UserNotWhitelisted
+			);
 			let challenge = Challenges::<T>::get(challenge_id).ok_or(Error::<T>::NotOngoing)?;
 			let owner_list = pallet_nft_marketplace::PropertyOwner::<T>::get(challenge.asset_id);
 			ensure!(owner_list.contains(&signer), Error::<T>::NoPermission);
@@ -570,6 +612,29 @@ pub mod pallet {
 			<T as pallet::Config>::PalletId::get().into_account_truncating()
 		}
 
+		/// Returns the proposals of a property that are still open for voting.
+		pub fn open_proposals(
+			asset_id: u32,
+		) -> sp_std::vec::Vec<ProposalInfo<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>> {
+			let mut proposals: sp_std::vec::Vec<_> = Proposals::<T>::iter()
+				.filter(|(_, proposal)| proposal.asset_id == asset_id)
+				.filter_map(|(proposal_id, proposal)| {
+					let vote_stats = OngoingVotes::<T>::get(proposal_id)?;
+					Some(ProposalInfo {
+						proposal_id,
+						proposer: proposal.proposer,
+						amount: proposal.amount,
+						created_at: proposal.created_at,
+						proposal_info: proposal.proposal_info.into_inner(),
+						yes_voting_power: vote_stats.yes_voting_power,
+						no_voting_power: vote_stats.no_voting_power,
+					})
+				})
+				.collect();
+			proposals.sort_by_key(|proposal| proposal.proposal_id);
+			proposals
+		}
+
 		// Slashes the letting agent.
 		fn slash_letting_agent(challenge_id: ChallengeIndex) -> DispatchResult {
 			let mut challenge = Challenges::<T>::take(challenge_id).ok_or(Error::<T>::NotOngoing)?;
@@ -609,6 +674,12 @@ pub mod pallet {
 				let proposals = <Proposals<T>>::take(proposal_id);
 				if let Some(proposal) = proposals {
 					if let Some(voting_result) = voting_results {
+						let voting_result = Self::discount_ineligible_votes(
+							proposal.asset_id,
+							voting_result,
+							ProposalVoter::<T>::get(proposal_id),
+							|voter| UserProposalVote::<T>::get(proposal_id, voter),
+						);
 						let required_threshold =
 							if proposal.amount >= <T as Config>::HighProposal::get() {
 								<T as Config>::HighThreshold::get()
@@ -658,6 +729,12 @@ pub mod pallet {
 				else {
 					let voting_results = <OngoingChallengeVotes<T>>::take(challenge_id, challenge.state.clone());
 					if let Some(voting_result) = voting_results {
+						let voting_result = Self::discount_ineligible_votes(
+							challenge.asset_id,
+							voting_result,
+							ChallengeVoter::<T>::get(challenge_id, challenge.state.clone()),
+							|voter| UserChallengeVote::<T>::get(challenge_id, voter),
+						);
 						let asset_details = pallet_nft_marketplace::AssetIdDetails::<T>::get(challenge.asset_id);
 						if let Some(asset_details) = asset_details {
 							let yes_votes_percentage = Percent::from_rational(voting_result.yes_voting_power, asset_details.token_amount);
@@ -699,6 +776,34 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Removes the votes of the voters that are no longer whitelisted or that have been
+		/// blocked since they voted, so that they do not count in the result.
+		fn discount_ineligible_votes(
+			asset_id: u32,
+			mut vote_stats: VoteStats,
+			voters: BoundedVec<AccountIdOf<T>, T::MaxVoter>,
+			vote_of: impl Fn(&AccountIdOf<T>) -> Option<Vote>,
+		) -> VoteStats {
+			let mut voters = voters.into_inner();
+			voters.sort();
+			voters.dedup();
+			for voter in voters {
+				if pallet_xcavate_whitelist::Pallet::<T>::whitelisted_accounts(voter.clone()) &&
+					!pallet_xcavate_whitelist::Pallet::<T>::is_blocked(&voter)
+				{
+					continue;
+				}
+				let voting_power =
+					pallet_nft_marketplace::PropertyOwnerToken::<T>::get(asset_id, voter.clone());
+				match vote_of(&voter) {
+					Some(Vote::Yes) => vote_stats.yes_voting_power.saturating_reduce(voting_power),
+					Some(Vote::No) => vote_stats.no_voting_power.saturating_reduce(voting_power),
+					None => {},
+				}
+			}
+			vote_stats
+		}
+
 		/// Executes a proposal once it passes.
 		fn execute_proposal(proposal: Proposal<T>) -> DispatchResult {
 			let letting_agent =
@@ -777,10 +882,19 @@ pub mod pallet {
 }
 
 sp_api::decl_runtime_apis! {
-    pub trait PropertyGovernanceApi<AccountId> 
+	/// Version 2 adds the query for the open proposals of a property.
+	#[api_version(2)]
+    pub trait PropertyGovernanceApi<AccountId, Balance, BlockNumber> 
 	where
-		AccountId: Codec
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
 	{
         fn get_governance_account_id() -> AccountId;
+
+		/// Returns the proposals of a property that are still open for voting.
+		fn get_open_proposals(
+			asset_id: u32,
+		) -> sp_std::vec::Vec<ProposalInfo<AccountId, Balance, BlockNumber>>;
     }
 }
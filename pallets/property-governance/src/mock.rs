@@ -189,13 +189,22 @@ impl pallet_nft_fractionalization::Config for Test {
 
 parameter_types! {
 	pub const MaxWhitelistUsers: u32 = 1000000;
+	pub const InvestmentPeriod: u64 = 1000;
+	pub const InvestmentDecimals: u8 = 0;
+	pub const MaxWhitelistBatch: u32 = 100;
 }
 
 impl pallet_xcavate_whitelist::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_xcavate_whitelist::weights::SubstrateWeight<Test>;
 	type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ComplianceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type MaxUsersInWhitelist = MaxWhitelistUsers;
+	type InvestmentBalance = u32;
+	type InvestmentPeriod = InvestmentPeriod;
+	type InvestmentDecimals = InvestmentDecimals;
+	type MaxWhitelistBatch = MaxWhitelistBatch;
+	type OnRemoval = (NftMarketplace, PropertyManagement);
 }
 
 parameter_types! {
@@ -205,6 +214,24 @@ parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const CommunityProjectPalletId: PalletId = PalletId(*b"py/cmprj");
 	pub const Postcode: u32 = 10;
+	pub const ListingDuration: BlockNumber = 30;
+	pub const OfferDuration: BlockNumber = 20;
+	pub const MaxOffersForBlock: u32 = 100;
+	pub const AuctionDuration: BlockNumber = 10;
+	pub const MaxAuctionsForBlock: u32 = 100;
+	pub const MaxBatchPurchases: u32 = 10;
+	pub const LawyerBond: u32 = 1_000;
+	pub const LawyerUnbondingPeriod: u64 = 10;
+	pub const MaxLawyerRegions: u32 = 10;
+	pub const LawyerReviewPeriod: u64 = 20;
+	pub const MaxReviewTimeouts: u32 = 2;
+	pub const MaxReviewDeadlinesForBlock: u32 = 100;
+	pub const MaxDocumentHashes: u32 = 10;
+	pub const ArbitrationPeriod: u64 = 30;
+	pub const MaxArbitrationsForBlock: u32 = 100;
+	pub const MaxSoftCapsForBlock: u32 = 100;
+	pub const MaxOwnershipShare: Percent = Percent::from_percent(100);
+	pub const MaxComplianceListings: u32 = 10;
 }
 
 /// Configure the pallet-xcavate-staking in pallets/xcavate-staking.
@@ -224,6 +251,27 @@ impl pallet_nft_marketplace::Config for Test {
 	type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
 	type AssetId2 = u32;
 	type PostcodeLimit = Postcode;
+	type FeeScheduleOrigin = EnsureRoot<Self::AccountId>;
+	type PaymentAssetOrigin = EnsureRoot<Self::AccountId>;
+	type ArbiterOrigin = EnsureRoot<Self::AccountId>;
+	type ListingDuration = ListingDuration;
+	type OfferDuration = OfferDuration;
+	type MaxOffersForBlock = MaxOffersForBlock;
+	type AuctionDuration = AuctionDuration;
+	type MaxAuctionsForBlock = MaxAuctionsForBlock;
+	type MaxBatchPurchases = MaxBatchPurchases;
+	type LawyerBond = LawyerBond;
+	type LawyerUnbondingPeriod = LawyerUnbondingPeriod;
+	type MaxLawyerRegions = MaxLawyerRegions;
+	type LawyerReviewPeriod = LawyerReviewPeriod;
+	type MaxReviewTimeouts = MaxReviewTimeouts;
+	type MaxReviewDeadlinesForBlock = MaxReviewDeadlinesForBlock;
+	type MaxDocumentHashes = MaxDocumentHashes;
+	type ArbitrationPeriod = ArbitrationPeriod;
+	type MaxArbitrationsForBlock = MaxArbitrationsForBlock;
+	type MaxSoftCapsForBlock = MaxSoftCapsForBlock;
+	type MaxOwnershipShare = MaxOwnershipShare;
+	type MaxComplianceListings = MaxComplianceListings;
 }
 
 parameter_types! {
@@ -291,6 +339,9 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			([2; 32].into(), 1_150_000),
 			([3; 32].into(), 1_005_000),
 			([4; 32].into(), 5_000),
+			([10; 32].into(), 10_000),
+			([11; 32].into(), 10_000),
+			([12; 32].into(), 10_000),
 			((NftMarketplace::account_id()), 20_000_000),
 			((PropertyGovernance::account_id()), 500_000),
 			((PropertyManagement::account_id()), 1),
@@ -314,5 +365,12 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	.assimilate_storage(&mut test)
 	.unwrap(); 
 
+	pallet_nft_marketplace::GenesisConfig::<Test> {
+		payment_assets: vec![1],
+		..Default::default()
+	}
+	.assimilate_storage(&mut test)
+	.unwrap();
+
 	test.into()
 }
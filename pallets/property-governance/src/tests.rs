@@ -4,6 +4,7 @@ use frame_support::{
 	traits::{OnFinalize, OnInitialize},
 	BoundedVec, sp_runtime::Percent
 };
+use sp_core::H256;
 
 use crate::{Proposals, Challenges, ChallengeRoundsExpiring, OngoingChallengeVotes, OngoingVotes};
 
@@ -13,6 +14,7 @@ use pallet_property_management::{
 };
 
 use pallet_nft_marketplace::LegalProperty;
+use pallet_xcavate_whitelist::KycTier;
 
 macro_rules! bvec {
 	($( $x:tt )*) => {
@@ -39,17 +41,19 @@ fn propose_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
 			0,
@@ -84,18 +88,20 @@ fn proposal_with_low_amount_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
 			0,
@@ -129,17 +135,19 @@ fn propose_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_noop!(
 			PropertyGovernance::propose(
 				RuntimeOrigin::signed([2; 32].into()),
@@ -178,9 +186,11 @@ fn challenge_against_letting_agent_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -188,9 +198,11 @@ fn challenge_against_letting_agent_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -207,11 +219,15 @@ fn challenge_against_letting_agent_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -239,9 +255,11 @@ fn challenge_against_letting_agent_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -249,9 +267,11 @@ fn challenge_against_letting_agent_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 				assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -268,11 +288,15 @@ fn challenge_against_letting_agent_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_noop!(PropertyGovernance::challenge_against_letting_agent(
 			RuntimeOrigin::signed([1; 32].into()),
@@ -302,11 +326,13 @@ fn vote_on_proposal_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -314,12 +340,14 @@ fn vote_on_proposal_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 10));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 40));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 10, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 40, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -336,11 +364,15 @@ fn vote_on_proposal_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -395,9 +427,11 @@ fn proposal_pass() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -405,9 +439,11 @@ fn proposal_pass() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -424,11 +460,15 @@ fn proposal_pass() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -470,15 +510,102 @@ fn proposal_pass() {
 	});
 }
 
+#[test]
+fn proposal_ignores_votes_of_removed_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(PropertyManagement::add_letting_agent(
+			RuntimeOrigin::root(),
+			0,
+			bvec![10, 10],
+			[0; 32].into(),
+		));
+		assert_ok!(PropertyManagement::letting_agent_deposit(RuntimeOrigin::signed(
+			[0; 32].into()
+		)));
+		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0));
+		assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [0; 32].into());
+		assert_ok!(PropertyManagement::distribute_income(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			1000
+		));
+		assert_ok!(PropertyGovernance::propose(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			1000,
+			bvec![10, 10]
+		));
+		assert_ok!(PropertyGovernance::vote_on_proposal(
+			RuntimeOrigin::signed([1; 32].into()),
+			1,
+			crate::Vote::Yes
+		));
+		assert_ok!(XcavateWhitelist::remove_from_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		run_to_block(31);
+		System::assert_has_event(Event::ProposalRejected { proposal_id: 1 }.into());
+		assert_eq!(PropertyReserve::<Test>::get(0), 1000);
+		assert_eq!(Proposals::<Test>::get(1).is_none(), true);
+		assert_eq!(OngoingVotes::<Test>::get(1).is_none(), true);
+	});
+}
+
 #[test]
 fn proposal_pass_2() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -486,9 +613,11 @@ fn proposal_pass_2() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -505,11 +634,15 @@ fn proposal_pass_2() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -571,9 +704,11 @@ fn proposal_not_pass() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -581,9 +716,11 @@ fn proposal_not_pass() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -600,11 +737,15 @@ fn proposal_not_pass() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -652,10 +793,12 @@ fn proposal_not_pass_2() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -663,10 +806,12 @@ fn proposal_not_pass_2() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 60));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 40));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 60, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 40, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -683,11 +828,15 @@ fn proposal_not_pass_2() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -736,9 +885,12 @@ fn vote_on_proposal_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -746,9 +898,11 @@ fn vote_on_proposal_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -765,11 +919,15 @@ fn vote_on_proposal_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -806,6 +964,14 @@ fn vote_on_proposal_fails() {
 			1,
 			crate::Vote::Yes
 		));
+		assert_noop!(
+			PropertyGovernance::vote_on_proposal(
+				RuntimeOrigin::signed([3; 32].into()),
+				1,
+				crate::Vote::Yes
+			),
+			Error::<Test>::UserNotWhitelisted
+		);
 		assert_noop!(
 			PropertyGovernance::vote_on_proposal(
 				RuntimeOrigin::signed([2; 32].into()),
@@ -814,6 +980,15 @@ fn vote_on_proposal_fails() {
 			),
 			Error::<Test>::NoPermission
 		);
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [1; 32].into()));
+		assert_noop!(
+			PropertyGovernance::vote_on_proposal(
+				RuntimeOrigin::signed([1; 32].into()),
+				1,
+				crate::Vote::No
+			),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
 	});
 }
 
@@ -823,11 +998,13 @@ fn vote_on_challenge_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -835,12 +1012,14 @@ fn vote_on_challenge_works() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 30));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 10));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 40));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 30, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 10, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 40, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -857,11 +1036,15 @@ fn vote_on_challenge_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -908,10 +1091,12 @@ fn challenge_pass() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -937,10 +1122,12 @@ fn challenge_pass() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 70));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 70, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -957,11 +1144,15 @@ fn challenge_pass() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0));
 		assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [0; 32].into());
@@ -1059,10 +1250,12 @@ fn challenge_does_not_pass() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -1088,10 +1281,12 @@ fn challenge_does_not_pass() {
 			bvec![10, 10],
 			4_000,
 			250,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 75));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 175));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 75, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 175, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1108,11 +1303,15 @@ fn challenge_does_not_pass() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0));
 		assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [0; 32].into());
@@ -1165,10 +1364,12 @@ fn challenge_pass_only_one_agent() {
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![9, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -1194,10 +1395,12 @@ fn challenge_pass_only_one_agent() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 70));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 30, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 70, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1214,11 +1417,15 @@ fn challenge_pass_only_one_agent() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0));
 		assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [0; 32].into());
@@ -1295,9 +1502,11 @@ fn challenge_not_pass() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1305,9 +1514,11 @@ fn challenge_not_pass() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1324,11 +1535,15 @@ fn challenge_not_pass() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_noop!(PropertyGovernance::challenge_against_letting_agent(
 			RuntimeOrigin::signed([1; 32].into()),
@@ -1366,9 +1581,12 @@ fn vote_on_challenge_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1376,9 +1594,11 @@ fn vote_on_challenge_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1395,11 +1615,15 @@ fn vote_on_challenge_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_noop!(
 			PropertyGovernance::vote_on_letting_agent_challenge(
@@ -1428,6 +1652,14 @@ fn vote_on_challenge_fails() {
 			1,
 			crate::Vote::Yes
 		));
+		assert_noop!(
+			PropertyGovernance::vote_on_letting_agent_challenge(
+				RuntimeOrigin::signed([3; 32].into()),
+				1,
+				crate::Vote::Yes
+			),
+			Error::<Test>::UserNotWhitelisted
+		);
 		assert_noop!(
 			PropertyGovernance::vote_on_letting_agent_challenge(
 				RuntimeOrigin::signed([2; 32].into()),
@@ -1445,11 +1677,13 @@ fn different_proposals() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1457,11 +1691,13 @@ fn different_proposals() {
 			bvec![10, 10],
 			5_000,
 			200,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 60));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 60));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 80));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 60, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 60, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 80, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -1478,11 +1714,15 @@ fn different_proposals() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -1600,3 +1840,56 @@ fn different_proposals() {
 		assert_eq!(PropertyReserve::<Test>::get(0), 500);
 	});
 }
+
+#[test]
+fn open_proposals_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			10_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(PropertyManagement::add_letting_agent(
+			RuntimeOrigin::root(),
+			0,
+			bvec![10, 10],
+			[2; 32].into(),
+		));
+		assert_ok!(PropertyManagement::letting_agent_deposit(RuntimeOrigin::signed(
+			[2; 32].into()
+		)));
+		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([2; 32].into()), 0));
+		assert_ok!(PropertyManagement::distribute_income(
+			RuntimeOrigin::signed([2; 32].into()),
+			0,
+			1000
+		));
+		assert_eq!(PropertyGovernance::open_proposals(0).len(), 0);
+		assert_ok!(PropertyGovernance::propose(
+			RuntimeOrigin::signed([2; 32].into()),
+			0,
+			1000,
+			bvec![10, 10]
+		));
+		let proposals = PropertyGovernance::open_proposals(0);
+		assert_eq!(proposals.len(), 1);
+		assert_eq!(proposals[0].proposal_id, 1);
+		assert_eq!(proposals[0].proposer, [2; 32].into());
+		assert_eq!(proposals[0].amount, 1000);
+		assert_eq!(proposals[0].proposal_info, vec![10, 10]);
+		assert_eq!(PropertyGovernance::open_proposals(1).len(), 0);
+		run_to_block(31);
+		assert_eq!(PropertyGovernance::open_proposals(0).len(), 0);
+	});
+}
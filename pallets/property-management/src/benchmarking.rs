@@ -17,7 +17,7 @@ type DepositBalanceOf1<T> =
 use frame_support::sp_runtime::traits::StaticLookup;
 use frame_support::BoundedVec;
 use frame_support::{assert_ok, traits::Get};
-use pallet_xcavate_whitelist::Pallet as Whitelist;
+use pallet_xcavate_whitelist::{KycTier, Pallet as Whitelist};
 
 use pallet_assets::Pallet as Assets;
 
@@ -38,7 +38,13 @@ fn setup_real_estate_object<T: Config>(
 		0,
 		location.clone()
 	));
-	assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
+	assert_ok!(Whitelist::<T>::add_to_whitelist(
+		RawOrigin::Root.into(),
+		caller.clone(),
+		KycTier::Retail,
+		*b"GB",
+		Bounded::max_value()
+	));
 	let user_lookup = <T::Lookup as StaticLookup>::unlookup(caller.clone());
 	let asset_id = <T as pallet::Config>::Helper::to_asset(1);
 	assert_ok!(Assets::<T, Instance1>::create(
@@ -62,8 +68,10 @@ fn setup_real_estate_object<T: Config>(
 		vec![0; <T as pallet_nfts::Config>::StringLimit::get() as usize]
 			.try_into()
 			.unwrap(),
+			1,
+			None,
 	));
-	assert_ok!(NftMarketplace::<T>::buy_token(RawOrigin::Signed(caller.clone()).into(), 0, 100));
+	assert_ok!(NftMarketplace::<T>::buy_token(RawOrigin::Signed(caller.clone()).into(), 0, 100, Bounded::max_value()));
 	location
 }
 
@@ -261,5 +269,17 @@ mod benchmarks {
 		assert_eq!(PropertyManagement::<T>::stored_funds(caller), 0u32.into());
 	}
 
+	#[benchmark]
+	fn release_quarantined_funds() {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: BalanceOf<T> = 1u32.into();
+		QuarantinedFunds::<T>::insert(caller.clone(), amount);
+		#[extrinsic_call]
+		release_quarantined_funds(RawOrigin::Root, caller.clone());
+
+		assert_eq!(QuarantinedFunds::<T>::get(caller.clone()), 0u32.into());
+		assert_eq!(StoredFunds::<T>::get(caller), amount);
+	}
+
 	impl_benchmark_test_suite!(PropertyManagement, crate::mock::new_test_ext(), crate::mock::Test);
 }
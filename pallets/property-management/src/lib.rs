@@ -147,6 +147,11 @@ pub mod pallet {
 	pub type StoredFunds<T> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
 
+	/// Mapping from a blocked account to the funds that are held in quarantine.
+	#[pallet::storage]
+	pub type QuarantinedFunds<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
+
 	/// Mapping of asset id to the stored balance for a property.
 	#[pallet::storage]
 	pub type PropertyReserve<T> =
@@ -189,6 +194,10 @@ pub mod pallet {
 		IncomeDistributed { asset_id: u32, amount: BalanceOf<T> },
 		/// A user withdrew funds.
 		WithdrawFunds { who: T::AccountId, amount: BalanceOf<T> },
+		/// Funds of a blocked account have been put into quarantine.
+		FundsQuarantined { who: T::AccountId, amount: BalanceOf<T> },
+		/// Quarantined funds have been released to an account.
+		QuarantinedFundsReleased { who: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -239,6 +248,8 @@ pub mod pallet {
 		AssetNotFound,
 		/// This letting agent has no location.
 		NoLoactions,
+		/// The account has no funds in quarantine.
+		NoQuarantinedFunds,
 	}
 
 	#[pallet::call]
@@ -302,6 +313,7 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::letting_agent_deposit())]
 		pub fn letting_agent_deposit(origin: OriginFor<T>) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			LettingInfo::<T>::try_mutate(signer.clone(), |maybe_letting_info|{
 				let letting_info = maybe_letting_info.as_mut().ok_or(Error::<T>::NoPermission)?;
 				ensure!(!letting_info.deposited, Error::<T>::AlreadyDeposited);
@@ -399,6 +411,7 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_letting_agent())]
 		pub fn set_letting_agent(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			ensure!(pallet_nft_marketplace::AssetIdDetails::<T>::get(asset_id).is_some(), Error::<T>::NoObjectFound);
 			ensure!(LettingStorage::<T>::get(asset_id).is_none(), Error::<T>::LettingAgentAlreadySet);
 			LettingInfo::<T>::try_mutate(signer.clone(), |maybe_letting_info|{
@@ -434,6 +447,7 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			let letting_agent = LettingStorage::<T>::get(asset_id).ok_or(Error::<T>::NoLettingAgentFound)?;
 			ensure!(letting_agent == signer, Error::<T>::NoPermission);
 		
@@ -530,6 +544,19 @@ pub mod pallet {
 					.ok_or(Error::<T>::MultiplyError)?
 					.checked_div(&Self::u64_to_balance_option(total_token.into())?)
 					.ok_or(Error::<T>::DivisionError)?;
+				if pallet_xcavate_whitelist::Pallet::<T>::is_blocked(&owner) {
+					QuarantinedFunds::<T>::try_mutate(owner.clone(), |old_funds| {
+						*old_funds = old_funds
+						.checked_add(&amount_for_owner)
+						.ok_or(Error::<T>::ArithmeticOverflow)?;
+						Ok::<(), DispatchError>(())
+					})?;
+					Self::deposit_event(Event::<T>::FundsQuarantined {
+						who: owner,
+						amount: amount_for_owner,
+					});
+					continue;
+				}
 				StoredFunds::<T>::try_mutate(owner.clone(), |old_funds| {
 					*old_funds = old_funds
 					.checked_add(&amount_for_owner)
@@ -554,6 +581,7 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_funds())]
 		pub fn withdraw_funds(origin: OriginFor<T>) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&signer)?;
 			let amount = StoredFunds::<T>::take(signer.clone());
 			ensure!(
 				!amount.is_zero(),
@@ -571,6 +599,35 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::WithdrawFunds { who: signer, amount });
 			Ok(())
 		}
+
+		/// Releases the quarantined funds of an account that is no longer blocked.
+		/// The funds are added to the stored funds of the account and can be withdrawn afterwards.
+		///
+		/// The origin must be the ComplianceOrigin.
+		///
+		/// Parameters:
+		/// - `account`: The account whose funds get released.
+		///
+		/// Emits `QuarantinedFundsReleased` event when succesfful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::release_quarantined_funds())]
+		pub fn release_quarantined_funds(
+			origin: OriginFor<T>,
+			account: AccountIdOf<T>,
+		) -> DispatchResult {
+			<T as pallet_xcavate_whitelist::Config>::ComplianceOrigin::ensure_origin(origin)?;
+			pallet_xcavate_whitelist::Pallet::<T>::ensure_not_blocked(&account)?;
+			let amount = QuarantinedFunds::<T>::take(account.clone());
+			ensure!(!amount.is_zero(), Error::<T>::NoQuarantinedFunds);
+			StoredFunds::<T>::try_mutate(account.clone(), |old_funds| {
+				*old_funds = old_funds
+				.checked_add(&amount)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+				Ok::<(), DispatchError>(())
+			})?;
+			Self::deposit_event(Event::<T>::QuarantinedFundsReleased { who: account, amount });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -616,12 +673,36 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config> pallet_xcavate_whitelist::OnWhitelistRemoval<AccountIdOf<T>> for Pallet<T> {
+	/// The stored funds of a blocked account are moved into quarantine.
+	fn on_removal(account: &AccountIdOf<T>) {
+		if !pallet_xcavate_whitelist::Pallet::<T>::is_blocked(account) {
+			return;
+		}
+		let amount = StoredFunds::<T>::take(account);
+		if amount.is_zero() {
+			return;
+		}
+		QuarantinedFunds::<T>::mutate(account, |funds| *funds = funds.saturating_add(amount));
+		Self::deposit_event(Event::<T>::FundsQuarantined { who: account.clone(), amount });
+	}
+}
+
 sp_api::decl_runtime_apis! {
-    pub trait PropertyManagementApi<AccountId> 
+	/// Version 2 adds the queries for the reserve and the debts of a property.
+	#[api_version(2)]
+    pub trait PropertyManagementApi<AccountId, Balance> 
 	where
-		AccountId: Codec
+		AccountId: Codec,
+		Balance: Codec,
 	{
         fn get_management_account_id() -> AccountId;
+
+		/// Returns the funds that are held in reserve for a property.
+		fn get_property_reserve(asset_id: u32) -> Balance;
+
+		/// Returns the debts of a property.
+		fn get_property_debts(asset_id: u32) -> Balance;
     }
 }
 
@@ -1,13 +1,16 @@
 use crate::{mock::*, Error};
 use frame_support::traits::Currency;
 use frame_support::BoundedVec;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, sp_runtime::traits::BadOrigin};
+use sp_core::H256;
 
-use crate::{PropertyReserve, LettingStorage, LettingInfo, LettingAgentLocations, StoredFunds};
+use crate::{PropertyReserve, LettingStorage, LettingInfo, LettingAgentLocations, StoredFunds,
+	QuarantinedFunds};
 
 use pallet_balances::Error as BalancesError;
 
 use pallet_nft_marketplace::LegalProperty;
+use pallet_xcavate_whitelist::KycTier;
 
 macro_rules! bvec {
 	($( $x:tt )*) => {
@@ -297,35 +300,41 @@ fn set_letting_agent_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			1_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 1, 100, u32::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			1_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 2, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 2, 100, u32::MAX));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
 			0,
@@ -362,9 +371,11 @@ fn set_letting_agent_works() {
 			bvec![10, 10],
 			1_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 3, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 3, 100, u32::MAX));
 		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([2; 32].into()), 4));
 		assert_eq!(LettingStorage::<Test>::get(0).unwrap(), [2; 32].into());
 		assert_eq!(LettingStorage::<Test>::get(2).unwrap(), [3; 32].into());
@@ -408,8 +419,8 @@ fn set_letting_agent_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
 			0,
@@ -430,9 +441,11 @@ fn set_letting_agent_fails() {
 			bvec![10, 10],
 			100,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0));
 		assert_noop!(
 			PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0),
@@ -445,9 +458,11 @@ fn set_letting_agent_fails() {
 				bvec![10, 10],
 				1_000,
 				100,
-				bvec![22, 22]
+				bvec![22, 22],
+				1,
+				None
 			));
-			assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [(x); 32].into()));
+			assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [(x); 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 			Balances::make_free_balance_be(&[x; 32].into(), 100_000);
 			assert_ok!(Assets::mint(
 				RuntimeOrigin::signed([0; 32].into()),
@@ -458,7 +473,8 @@ fn set_letting_agent_fails() {
 			assert_ok!(NftMarketplace::buy_token(
 				RuntimeOrigin::signed([x; 32].into()),
 				(x as u32 - 1).into(),
-				100
+				100,
+				u32::MAX
 			));
 			assert_ok!(PropertyManagement::set_letting_agent(
 				RuntimeOrigin::signed([0; 32].into()),
@@ -471,9 +487,11 @@ fn set_letting_agent_fails() {
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 100, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 100, 100, u32::MAX));
 		assert_noop!(
 			PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 101),
 			Error::<Test>::TooManyAssignedProperties
@@ -487,17 +505,19 @@ fn set_letting_agent_no_letting_agent() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20, u32::MAX));
 		assert_noop!(
 			PropertyManagement::set_letting_agent(RuntimeOrigin::signed([0; 32].into()), 0),
 			Error::<Test>::AgentNotFound
@@ -511,11 +531,13 @@ fn distribute_income_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [2; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [3; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -523,11 +545,13 @@ fn distribute_income_works() {
 			bvec![10, 10],
 			9_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 30));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 50));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 20, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([2; 32].into()), 0, 30, u32::MAX));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([3; 32].into()), 0, 50, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -544,11 +568,15 @@ fn distribute_income_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -579,17 +607,19 @@ fn distribute_income_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			bvec![10, 10],
 			10_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_noop!(
 			PropertyManagement::distribute_income(RuntimeOrigin::signed([5; 32].into()), 0, 200),
 			Error::<Test>::NoLettingAgentFound
@@ -622,9 +652,11 @@ fn withdraw_funds_works() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -632,9 +664,11 @@ fn withdraw_funds_works() {
 			bvec![10, 10],
 			9_000,
 			100,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -651,11 +685,15 @@ fn withdraw_funds_works() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(PropertyManagement::add_letting_agent(
 			RuntimeOrigin::root(),
@@ -690,9 +728,11 @@ fn withdraw_funds_fails() {
 		System::set_block_number(1);
 		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
 		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into()));
-		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
 		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
 		assert_ok!(NftMarketplace::list_object(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -700,9 +740,11 @@ fn withdraw_funds_fails() {
 			bvec![10, 10],
 			900,
 			1000,
-			bvec![22, 22]
+			bvec![22, 22],
+			1,
+			None
 		));
-		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 1000));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 1000, u32::MAX));
 		assert_ok!(NftMarketplace::lawyer_claim_property(
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
@@ -719,11 +761,15 @@ fn withdraw_funds_fails() {
 			RuntimeOrigin::signed([10; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_ok!(NftMarketplace::lawyer_confirm_documents(
 			RuntimeOrigin::signed([11; 32].into()),
 			0,
 			true,
+			bvec![H256::repeat_byte(1)],
+			None,
 		));
 		assert_eq!(Assets::total_supply(0), 1000);
 		assert_ok!(PropertyManagement::add_letting_agent(
@@ -748,3 +794,112 @@ fn withdraw_funds_fails() {
 		);
 	});
 }
+
+#[test]
+fn distribute_income_quarantines_funds_of_blocked_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NftMarketplace::create_new_region(RuntimeOrigin::root()));
+		assert_ok!(NftMarketplace::create_new_location(RuntimeOrigin::root(), 0, bvec![10, 10]));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [0; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(XcavateWhitelist::add_to_whitelist(RuntimeOrigin::root(), [1; 32].into(), KycTier::Retail, *b"GB", u64::MAX));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([10; 32].into()), [10; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [10; 32].into()));
+		assert_ok!(NftMarketplace::apply_as_lawyer(RuntimeOrigin::signed([11; 32].into()), [11; 32].into(), bvec![0]));
+		assert_ok!(NftMarketplace::register_lawyer(RuntimeOrigin::root(), [11; 32].into()));
+		assert_ok!(NftMarketplace::list_object(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			bvec![10, 10],
+			9_000,
+			100,
+			bvec![22, 22],
+			1,
+			None
+		));
+		assert_ok!(NftMarketplace::buy_token(RuntimeOrigin::signed([1; 32].into()), 0, 100, u32::MAX));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			LegalProperty::RealEstateDeveloperSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_claim_property(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			LegalProperty::SpvSide,
+			4_000,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([10; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(NftMarketplace::lawyer_confirm_documents(
+			RuntimeOrigin::signed([11; 32].into()),
+			0,
+			true,
+			bvec![H256::repeat_byte(1)],
+			None,
+		));
+		assert_ok!(PropertyManagement::add_letting_agent(
+			RuntimeOrigin::root(),
+			0,
+			bvec![10, 10],
+			[4; 32].into(),
+		));
+		assert_ok!(PropertyManagement::letting_agent_deposit(RuntimeOrigin::signed(
+			[4; 32].into()
+		)));
+		assert_ok!(PropertyManagement::set_letting_agent(RuntimeOrigin::signed([4; 32].into()), 0));
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(PropertyManagement::distribute_income(
+			RuntimeOrigin::signed([4; 32].into()),
+			0,
+			3200
+		));
+		assert_eq!(StoredFunds::<Test>::get::<AccountId>([1; 32].into()), 0);
+		assert_eq!(QuarantinedFunds::<Test>::get::<AccountId>([1; 32].into()), 200);
+		assert_noop!(
+			PropertyManagement::withdraw_funds(RuntimeOrigin::signed([1; 32].into())),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_noop!(
+			PropertyManagement::release_quarantined_funds(
+				RuntimeOrigin::signed([1; 32].into()),
+				[1; 32].into()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			PropertyManagement::release_quarantined_funds(RuntimeOrigin::root(), [1; 32].into()),
+			pallet_xcavate_whitelist::Error::<Test>::AccountBlocked
+		);
+		assert_ok!(XcavateWhitelist::unblock_account(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(PropertyManagement::release_quarantined_funds(
+			RuntimeOrigin::root(),
+			[1; 32].into()
+		));
+		assert_eq!(QuarantinedFunds::<Test>::get::<AccountId>([1; 32].into()), 0);
+		assert_eq!(StoredFunds::<Test>::get::<AccountId>([1; 32].into()), 200);
+		assert_noop!(
+			PropertyManagement::release_quarantined_funds(RuntimeOrigin::root(), [1; 32].into()),
+			Error::<Test>::NoQuarantinedFunds
+		);
+		assert_ok!(XcavateWhitelist::block_account(RuntimeOrigin::root(), [1; 32].into()));
+		System::assert_has_event(
+			crate::Event::FundsQuarantined { who: [1; 32].into(), amount: 200 }.into(),
+		);
+		assert_eq!(StoredFunds::<Test>::get::<AccountId>([1; 32].into()), 0);
+		assert_eq!(QuarantinedFunds::<Test>::get::<AccountId>([1; 32].into()), 200);
+		assert_ok!(XcavateWhitelist::unblock_account(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(PropertyManagement::release_quarantined_funds(
+			RuntimeOrigin::root(),
+			[1; 32].into()
+		));
+		assert_ok!(PropertyManagement::withdraw_funds(RuntimeOrigin::signed([1; 32].into())));
+		assert_eq!(Balances::free_balance(&([1; 32].into())), 15_000_200);
+	});
+}
@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `recrafter-Legion-5-16IRX9`, CPU: `Intel(R) Core(TM) i7-14650HX`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The weights in the section marked as not benchmarked have not been generated by the
+//! benchmark CLI. They are estimates that get replaced by the next benchmark run.
 
 // Executed Command:
 // ./target/release/node-template
@@ -39,6 +42,7 @@ pub trait WeightInfo {
 	fn set_letting_agent() -> Weight;
 	fn distribute_income() -> Weight;
 	fn withdraw_funds() -> Weight;
+	fn release_quarantined_funds() -> Weight;
 }
 
 /// Weight functions for `pallet_property_management`.
@@ -148,4 +152,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+
+	// NOT BENCHMARKED: the weights below are estimated from the storage accesses of their
+	// benchmarks and the measured weights of calls doing the same work.
+
+	/// Estimated from the last measured `withdraw_funds`.
+	/// Storage: `Whitelist::BlockedAccounts` (r:1 w:0)
+	/// Storage: `PropertyManagement::QuarantinedFunds` (r:1 w:1)
+	/// Storage: `PropertyManagement::StoredFunds` (r:1 w:1)
+	fn release_quarantined_funds() -> Weight {
+		Weight::from_parts(46_695_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
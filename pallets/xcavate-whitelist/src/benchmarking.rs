@@ -5,8 +5,12 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Whitelist;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
-use frame_system::RawOrigin;
+use frame_support::{assert_ok, sp_runtime::traits::Bounded, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_core::crypto::KeyTypeId;
+use sp_std::vec::Vec;
+
+const KYC_KEY_TYPE: KeyTypeId = KeyTypeId(*b"kyc!");
 
 #[benchmarks]
 mod benchmarks {
@@ -16,7 +20,13 @@ mod benchmarks {
 	fn add_to_whitelist() {
 		let caller: T::AccountId = whitelisted_caller();
 		#[extrinsic_call]
-		add_to_whitelist(RawOrigin::Root, caller.clone());
+		add_to_whitelist(
+			RawOrigin::Root,
+			caller.clone(),
+			KycTier::Institutional,
+			*b"GB",
+			BlockNumberFor::<T>::max_value(),
+		);
 
 		assert_eq!(Whitelist::<T>::whitelisted_accounts(caller), true);
 	}
@@ -24,7 +34,13 @@ mod benchmarks {
 	#[benchmark]
 	fn remove_from_whitelist() {
 		let caller: T::AccountId = whitelisted_caller();
-		assert_ok!(Whitelist::<T>::add_to_whitelist(RawOrigin::Root.into(), caller.clone()));
+		assert_ok!(Whitelist::<T>::add_to_whitelist(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			KycTier::Institutional,
+			*b"GB",
+			BlockNumberFor::<T>::max_value(),
+		));
 		assert_eq!(Whitelist::<T>::whitelisted_accounts(caller.clone()), true);
 		#[extrinsic_call]
 		remove_from_whitelist(RawOrigin::Root, caller.clone());
@@ -32,5 +48,205 @@ mod benchmarks {
 		assert_eq!(Whitelist::<T>::whitelisted_accounts(caller), false);
 	}
 
+	#[benchmark]
+	fn set_tier_limits() {
+		let limits = InvestmentLimits {
+			per_transaction: 1_000u32.into(),
+			annual: 10_000u32.into(),
+		};
+		#[extrinsic_call]
+		set_tier_limits(RawOrigin::Root, KycTier::Retail, Some(limits.clone()));
+
+		assert_eq!(TierLimits::<T>::get(KycTier::Retail), Some(limits));
+	}
+
+	#[benchmark]
+	fn register_kyc_provider() {
+		let provider: T::AccountId = account("provider", 0, 0);
+		let signing_key = sp_io::crypto::sr25519_generate(KYC_KEY_TYPE, None);
+		#[extrinsic_call]
+		register_kyc_provider(RawOrigin::Root, provider.clone(), signing_key);
+
+		assert!(KycProviders::<T>::get(provider).is_some());
+	}
+
+	#[benchmark]
+	fn revoke_kyc_provider(n: Linear<0, 1000>) {
+		let provider: T::AccountId = account("provider", 0, 0);
+		let signing_key = sp_io::crypto::sr25519_generate(KYC_KEY_TYPE, None);
+		assert_ok!(Whitelist::<T>::register_kyc_provider(
+			RawOrigin::Root.into(),
+			provider.clone(),
+			signing_key
+		));
+		for i in 0..n {
+			assert_ok!(Whitelist::<T>::provider_add_to_whitelist(
+				RawOrigin::Signed(provider.clone()).into(),
+				account("user", i, 0),
+				KycTier::Retail,
+				*b"GB",
+				BlockNumberFor::<T>::max_value(),
+			));
+		}
+		#[extrinsic_call]
+		revoke_kyc_provider(RawOrigin::Root, provider.clone(), true, n);
+
+		assert!(KycProviders::<T>::get(provider).is_none());
+	}
+
+	#[benchmark]
+	fn provider_add_to_whitelist() {
+		let provider: T::AccountId = account("provider", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let signing_key = sp_io::crypto::sr25519_generate(KYC_KEY_TYPE, None);
+		assert_ok!(Whitelist::<T>::register_kyc_provider(
+			RawOrigin::Root.into(),
+			provider.clone(),
+			signing_key
+		));
+		#[extrinsic_call]
+		provider_add_to_whitelist(
+			RawOrigin::Signed(provider.clone()),
+			caller.clone(),
+			KycTier::Retail,
+			*b"GB",
+			BlockNumberFor::<T>::max_value(),
+		);
+
+		assert_eq!(VouchedBy::<T>::get(caller), Some(provider));
+	}
+
+	#[benchmark]
+	fn submit_kyc_attestation() {
+		let provider: T::AccountId = account("provider", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let signing_key = sp_io::crypto::sr25519_generate(KYC_KEY_TYPE, None);
+		assert_ok!(Whitelist::<T>::register_kyc_provider(
+			RawOrigin::Root.into(),
+			provider.clone(),
+			signing_key
+		));
+		let attestation = KycAttestation {
+			provider: provider.clone(),
+			user: caller.clone(),
+			tier: KycTier::Retail,
+			jurisdiction: *b"GB",
+			expiry: BlockNumberFor::<T>::max_value(),
+			issued_at: frame_system::Pallet::<T>::block_number(),
+			valid_until: BlockNumberFor::<T>::max_value(),
+		};
+		let signature = sp_io::crypto::sr25519_sign(
+			KYC_KEY_TYPE,
+			&signing_key,
+			&Whitelist::<T>::attestation_payload(&attestation),
+		)
+		.unwrap();
+		#[extrinsic_call]
+		submit_kyc_attestation(RawOrigin::Signed(caller.clone()), attestation, signature);
+
+		assert_eq!(VouchedBy::<T>::get(caller), Some(provider));
+	}
+
+	#[benchmark]
+	fn add_many_to_whitelist(n: Linear<0, 100>) {
+		let users: BoundedVec<WhitelistEntry<T>, T::MaxWhitelistBatch> = (0..n)
+			.map(|i| {
+				(account("user", i, 0), KycTier::Retail, *b"GB", BlockNumberFor::<T>::max_value())
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		#[extrinsic_call]
+		add_many_to_whitelist(RawOrigin::Root, users);
+
+		assert_eq!(WhitelistedCount::<T>::get(), n);
+	}
+
+	#[benchmark]
+	fn remove_many_from_whitelist(n: Linear<0, 100>) {
+		let provider: T::AccountId = account("provider", 0, 0);
+		let signing_key = sp_io::crypto::sr25519_generate(KYC_KEY_TYPE, None);
+		assert_ok!(Whitelist::<T>::register_kyc_provider(
+			RawOrigin::Root.into(),
+			provider.clone(),
+			signing_key
+		));
+		let mut users = Vec::new();
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			assert_ok!(Whitelist::<T>::provider_add_to_whitelist(
+				RawOrigin::Signed(provider.clone()).into(),
+				user.clone(),
+				KycTier::Retail,
+				*b"GB",
+				BlockNumberFor::<T>::max_value(),
+			));
+			users.push(user);
+		}
+		let users: BoundedVec<T::AccountId, T::MaxWhitelistBatch> = users.try_into().unwrap();
+		#[extrinsic_call]
+		remove_many_from_whitelist(RawOrigin::Root, users);
+
+		assert_eq!(WhitelistedCount::<T>::get(), 0);
+	}
+
+	#[benchmark]
+	fn remove_expired_accounts(n: Linear<0, 100>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let provider: T::AccountId = account("provider", 0, 0);
+		let signing_key = sp_io::crypto::sr25519_generate(KYC_KEY_TYPE, None);
+		assert_ok!(Whitelist::<T>::register_kyc_provider(
+			RawOrigin::Root.into(),
+			provider.clone(),
+			signing_key
+		));
+		let mut users = Vec::new();
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			assert_ok!(Whitelist::<T>::provider_add_to_whitelist(
+				RawOrigin::Signed(provider.clone()).into(),
+				user.clone(),
+				KycTier::Retail,
+				*b"GB",
+				expiry,
+			));
+			users.push(user);
+		}
+		frame_system::Pallet::<T>::set_block_number(expiry);
+		let users: BoundedVec<T::AccountId, T::MaxWhitelistBatch> = users.try_into().unwrap();
+		#[extrinsic_call]
+		remove_expired_accounts(RawOrigin::Signed(caller), users);
+
+		assert_eq!(WhitelistedCount::<T>::get(), 0);
+	}
+
+	#[benchmark]
+	fn block_account() {
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		block_account(RawOrigin::Root, caller.clone());
+
+		assert_eq!(Whitelist::<T>::is_blocked(&caller), true);
+	}
+
+	#[benchmark]
+	fn unblock_account() {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(Whitelist::<T>::block_account(RawOrigin::Root.into(), caller.clone()));
+		#[extrinsic_call]
+		unblock_account(RawOrigin::Root, caller.clone());
+
+		assert_eq!(Whitelist::<T>::is_blocked(&caller), false);
+	}
+
+	#[benchmark]
+	fn set_jurisdiction_restriction() {
+		#[extrinsic_call]
+		set_jurisdiction_restriction(RawOrigin::Root, *b"GB", true);
+
+		assert_eq!(RestrictedJurisdictions::<T>::contains_key(*b"GB"), true);
+	}
+
 	impl_benchmark_test_suite!(Whitelist, crate::mock::new_test_ext(), crate::mock::Test);
 }
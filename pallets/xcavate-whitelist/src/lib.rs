@@ -10,20 +10,128 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
+use codec::Codec;
+
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+/// Handler that gets called when an account is removed from the whitelist or blocked.
+/// Implementations must be cheap, unbounded cleanup work has to be done in separate calls.
+pub trait OnWhitelistRemoval<AccountId> {
+	fn on_removal(account: &AccountId);
+}
+
+impl<AccountId> OnWhitelistRemoval<AccountId> for () {
+	fn on_removal(_account: &AccountId) {}
+}
+
+impl<AccountId, A, B> OnWhitelistRemoval<AccountId> for (A, B)
+where
+	A: OnWhitelistRemoval<AccountId>,
+	B: OnWhitelistRemoval<AccountId>,
+{
+	fn on_removal(account: &AccountId) {
+		A::on_removal(account);
+		B::on_removal(account);
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use sp_core::sr25519;
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, Hash, Saturating, Verify, Zero},
+		SaturatedConversion,
+	};
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// The domain tag that is prefixed to the signed payload of a KYC attestation.
+	pub const ATTESTATION_DOMAIN: &[u8] = b"XCAVATE_KYC_ATTESTATION";
+
+	/// ISO 3166-1 alpha-2 code of the jurisdiction of an account.
+	pub type JurisdictionCode = [u8; 2];
+
+	/// The KYC level of a whitelisted account.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub enum KycTier {
+		Retail,
+		Sophisticated,
+		Institutional,
+	}
+
+	/// Infos regarding the KYC of a whitelisted account.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct KycDetails<BlockNumber> {
+		pub tier: KycTier,
+		pub jurisdiction: JurisdictionCode,
+		pub expiry: BlockNumber,
+	}
+
+	/// Infos regarding a registered KYC provider.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct KycProviderDetails {
+		pub signing_key: sr25519::Public,
+		pub vouched_accounts: u32,
+	}
+
+	/// A KYC attestation that a provider signs off-chain and a user submits on-chain.
+	/// The provider signs the payload returned by `attestation_payload`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct KycAttestation<AccountId, BlockNumber> {
+		pub provider: AccountId,
+		pub user: AccountId,
+		pub tier: KycTier,
+		pub jurisdiction: JurisdictionCode,
+		pub expiry: BlockNumber,
+		pub issued_at: BlockNumber,
+		pub valid_until: BlockNumber,
+	}
+
+	pub type KycAttestationOf<T> = KycAttestation<AccountIdOf<T>, BlockNumberFor<T>>;
+
+	/// A user that gets added to the whitelist with its KYC tier, jurisdiction and expiry.
+	pub type WhitelistEntry<T> = (AccountIdOf<T>, KycTier, JurisdictionCode, BlockNumberFor<T>);
+
+	/// Infos regarding a whitelisted account returned by the runtime api.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct WhitelistInfo<AccountId, BlockNumber> {
+		pub account: AccountId,
+		pub tier: KycTier,
+		pub jurisdiction: JurisdictionCode,
+		pub expiry: BlockNumber,
+		pub vouched_by: Option<AccountId>,
+	}
+
+	pub type WhitelistInfoOf<T> = WhitelistInfo<AccountIdOf<T>, BlockNumberFor<T>>;
+
+	/// The investment limits of a KYC tier.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+	pub struct InvestmentLimits<Balance> {
+		pub per_transaction: Balance,
+		pub annual: Balance,
+	}
+
+	/// The amount an account invested in the current investment period.
+	#[derive(
+		Encode, Decode, Clone, PartialEq, Eq, Default, MaxEncodedLen, RuntimeDebug, TypeInfo,
+	)]
+	pub struct InvestmentRecord<Balance, BlockNumber> {
+		pub period_start: BlockNumber,
+		pub invested: Balance,
+	}
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -33,24 +141,129 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// Origin who can add and remove users to the whitelist.
 		type WhitelistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin who can add and remove accounts to the sanctions blocklist.
+		type ComplianceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Max users allowed in the whitelist.
 		type MaxUsersInWhitelist: Get<u32>;
+
+		/// The balance type in which the investment limits are denominated.
+		type InvestmentBalance: Parameter
+			+ Member
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaxEncodedLen;
+
+		/// The period after which the annual investment limit resets.
+		#[pallet::constant]
+		type InvestmentPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The decimals in which the investment limits are denominated. Investments in assets
+		/// with other decimals get converted before they are checked against the limits.
+		#[pallet::constant]
+		type InvestmentDecimals: Get<u8>;
+
+		/// Max users that can be added or removed in a single call.
+		#[pallet::constant]
+		type MaxWhitelistBatch: Get<u32>;
+
+		/// Handler for the removal of an account from the whitelist and for blocked accounts.
+		type OnRemoval: OnWhitelistRemoval<Self::AccountId>;
 	}
 
-	/// Mapping of an account to a bool.
+	/// Number of accounts in the whitelist.
 	#[pallet::storage]
-	#[pallet::getter(fn whitelisted_accounts)]
+	pub type WhitelistedCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Mapping of a whitelisted account to its KYC details.
+	#[pallet::storage]
+	#[pallet::getter(fn kyc_details)]
 	pub type WhitelistedAccounts<T: Config> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, bool, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, KycDetails<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Mapping of a KYC provider to its details.
+	#[pallet::storage]
+	pub type KycProviders<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, KycProviderDetails, OptionQuery>;
+
+	/// Mapping of a whitelisted account to the KYC provider that vouched for it.
+	#[pallet::storage]
+	pub type VouchedBy<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, AccountIdOf<T>, OptionQuery>;
+
+	/// Double mapping of a KYC provider and the accounts it vouched for.
+	#[pallet::storage]
+	pub type VouchedAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	/// Mapping of a KYC tier to its investment limits.
+	#[pallet::storage]
+	pub type TierLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, KycTier, InvestmentLimits<T::InvestmentBalance>, OptionQuery>;
+
+	/// Mapping of an account to the amount it invested in the current investment period.
+	#[pallet::storage]
+	pub type AnnualInvestments<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		InvestmentRecord<T::InvestmentBalance, BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
+	/// The jurisdictions whose accounts can not be whitelisted and can not invest.
+	#[pallet::storage]
+	pub type RestrictedJurisdictions<T: Config> =
+		StorageMap<_, Blake2_128Concat, JurisdictionCode, (), OptionQuery>;
+
+	/// The hashes of the attestation payloads that have already been submitted.
+	#[pallet::storage]
+	pub type UsedAttestations<T: Config> = StorageMap<_, Identity, T::Hash, (), OptionQuery>;
+
+	/// Mapping of an account to the last block in which it got removed from the whitelist or
+	/// blocked. Attestations issued before that block can no longer be submitted.
+	#[pallet::storage]
+	pub type RemovedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+	/// Mapping of a blocked account to the block in which it got blocked.
+	#[pallet::storage]
+	pub type BlockedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BlockNumberFor<T>, OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new user has been successfully whitelisted.
-		NewUserWhitelisted { user: T::AccountId },
+		NewUserWhitelisted {
+			user: T::AccountId,
+			tier: KycTier,
+			jurisdiction: JurisdictionCode,
+			expiry: BlockNumberFor<T>,
+			provider: Option<T::AccountId>,
+		},
 		/// A new user has been successfully removed.
 		UserRemoved { user: T::AccountId },
+		/// The investment limits of a KYC tier have been set.
+		TierLimitsSet { tier: KycTier, limits: Option<InvestmentLimits<T::InvestmentBalance>> },
+		/// A new KYC provider has been registered.
+		KycProviderRegistered { provider: T::AccountId, signing_key: sr25519::Public },
+		/// A KYC provider has been revoked.
+		KycProviderRevoked { provider: T::AccountId, removed_users: u32 },
+		/// An account has been added to the sanctions blocklist.
+		AccountBlocked { account: T::AccountId },
+		/// An account has been removed from the sanctions blocklist.
+		AccountUnblocked { account: T::AccountId },
+		/// A jurisdiction has been restricted or allowed again.
+		JurisdictionRestrictionSet { jurisdiction: JurisdictionCode, restricted: bool },
 	}
 
 	// Errors inform users that something went wrong.
@@ -62,6 +275,42 @@ pub mod pallet {
 		UserNotInWhitelist,
 		/// Too many users are already in the whitelist.
 		TooManyUsers,
+		/// The expiry of the KYC must be in the future.
+		InvalidExpiry,
+		/// The per transaction limit can not be higher than the annual limit.
+		InvalidLimits,
+		/// The investment exceeds the per transaction limit of the KYC tier.
+		TransactionLimitExceeded,
+		/// The investment exceeds the annual limit of the KYC tier.
+		AnnualLimitExceeded,
+		/// The KYC provider is already registered.
+		ProviderAlreadyRegistered,
+		/// The account is not a registered KYC provider.
+		ProviderNotRegistered,
+		/// The signature of the attestation is invalid.
+		InvalidSignature,
+		/// The attestation can no longer be submitted.
+		AttestationExpired,
+		/// The attestation has been issued for another account.
+		NotAttestedUser,
+		/// The attestation has been issued in a future block.
+		AttestationNotYetValid,
+		/// The attestation has already been submitted.
+		AttestationAlreadyUsed,
+		/// The account has been removed from the whitelist after the attestation was issued.
+		AttestationRevoked,
+		/// The witness of vouched accounts is lower than the actual amount.
+		InvalidWitness,
+		/// The account is already on the blocklist.
+		AccountAlreadyBlocked,
+		/// The account is not on the blocklist.
+		AccountNotBlocked,
+		/// The account is on the blocklist.
+		AccountBlocked,
+		/// The jurisdiction of the account is restricted.
+		JurisdictionRestricted,
+		/// The KYC of the account has not expired yet.
+		KycNotExpired,
 	}
 
 	#[pallet::call]
@@ -72,19 +321,22 @@ pub mod pallet {
 		///
 		/// Parameters:
 		/// - `user`: The address of the new account added to the whitelist.
+		/// - `tier`: The KYC tier of the account.
+		/// - `jurisdiction`: The jurisdiction code of the account.
+		/// - `expiry`: The block after which the account is no longer whitelisted.
 		///
 		/// Emits `NewUserWhitelisted` event when succesfful
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_to_whitelist())]
-		pub fn add_to_whitelist(origin: OriginFor<T>, user: AccountIdOf<T>) -> DispatchResult {
+		pub fn add_to_whitelist(
+			origin: OriginFor<T>,
+			user: AccountIdOf<T>,
+			tier: KycTier,
+			jurisdiction: JurisdictionCode,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
 			T::WhitelistOrigin::ensure_origin(origin)?;
-			ensure!(
-				!Self::whitelisted_accounts(user.clone()),
-				Error::<T>::AccountAlreadyWhitelisted
-			);
-			WhitelistedAccounts::<T>::insert(user.clone(), true);
-			Self::deposit_event(Event::<T>::NewUserWhitelisted { user });
-			Ok(())
+			Self::do_add_to_whitelist(user, tier, jurisdiction, expiry, None)
 		}
 
 		/// Removes a user from the whitelist.
@@ -99,10 +351,564 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_from_whitelist())]
 		pub fn remove_from_whitelist(origin: OriginFor<T>, user: AccountIdOf<T>) -> DispatchResult {
 			T::WhitelistOrigin::ensure_origin(origin)?;
-			ensure!(Self::whitelisted_accounts(user.clone()), Error::<T>::UserNotInWhitelist);
-			WhitelistedAccounts::<T>::take(user.clone());
+			ensure!(
+				WhitelistedAccounts::<T>::contains_key(user.clone()),
+				Error::<T>::UserNotInWhitelist
+			);
+			Self::do_remove_from_whitelist(user);
+			Ok(())
+		}
+
+		/// Sets the investment limits of a KYC tier.
+		///
+		/// The origin must be the sudo.
+		///
+		/// Parameters:
+		/// - `tier`: The KYC tier.
+		/// - `limits`: The per transaction and annual limits. `None` removes the limits.
+		///
+		/// Emits `TierLimitsSet` event when succesfful
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_tier_limits())]
+		pub fn set_tier_limits(
+			origin: OriginFor<T>,
+			tier: KycTier,
+			limits: Option<InvestmentLimits<T::InvestmentBalance>>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			if let Some(ref limits) = limits {
+				ensure!(limits.per_transaction <= limits.annual, Error::<T>::InvalidLimits);
+			}
+			TierLimits::<T>::set(tier, limits.clone());
+			Self::deposit_event(Event::<T>::TierLimitsSet { tier, limits });
+			Ok(())
+		}
+
+		/// Registers a KYC provider that can whitelist users.
+		///
+		/// The origin must be the sudo.
+		///
+		/// Parameters:
+		/// - `provider`: The account of the KYC provider.
+		/// - `signing_key`: The sr25519 key that the provider signs attestations with.
+		///
+		/// Emits `KycProviderRegistered` event when succesfful
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_kyc_provider())]
+		pub fn register_kyc_provider(
+			origin: OriginFor<T>,
+			provider: AccountIdOf<T>,
+			signing_key: sr25519::Public,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			ensure!(
+				!KycProviders::<T>::contains_key(provider.clone()),
+				Error::<T>::ProviderAlreadyRegistered
+			);
+			KycProviders::<T>::insert(
+				provider.clone(),
+				KycProviderDetails { signing_key, vouched_accounts: 0 },
+			);
+			Self::deposit_event(Event::<T>::KycProviderRegistered { provider, signing_key });
+			Ok(())
+		}
+
+		/// Revokes a KYC provider.
+		///
+		/// The origin must be the sudo.
+		///
+		/// Parameters:
+		/// - `provider`: The account of the KYC provider.
+		/// - `remove_users`: Whether the accounts the provider vouched for get removed from the
+		/// whitelist.
+		/// - `vouched_accounts_witness`: The amount of accounts the provider vouched for.
+		///
+		/// Emits `KycProviderRevoked` event when succesfful
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_kyc_provider(
+			*vouched_accounts_witness
+		))]
+		pub fn revoke_kyc_provider(
+			origin: OriginFor<T>,
+			provider: AccountIdOf<T>,
+			remove_users: bool,
+			vouched_accounts_witness: u32,
+		) -> DispatchResultWithPostInfo {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			let provider_details =
+				KycProviders::<T>::get(provider.clone()).ok_or(Error::<T>::ProviderNotRegistered)?;
+			ensure!(
+				provider_details.vouched_accounts <= vouched_accounts_witness,
+				Error::<T>::InvalidWitness
+			);
+			KycProviders::<T>::remove(provider.clone());
+			let mut removed_users = 0;
+			for (user, ()) in VouchedAccounts::<T>::drain_prefix(provider.clone()) {
+				VouchedBy::<T>::remove(user.clone());
+				if remove_users && WhitelistedAccounts::<T>::contains_key(user.clone()) {
+					Self::do_remove_from_whitelist(user);
+					removed_users += 1;
+				}
+			}
+			Self::deposit_event(Event::<T>::KycProviderRevoked { provider, removed_users });
+			Ok(Some(<T as pallet::Config>::WeightInfo::revoke_kyc_provider(
+				provider_details.vouched_accounts,
+			))
+			.into())
+		}
+
+		/// Adds a user to the whitelist on behalf of a KYC provider.
+		///
+		/// The origin must be Signed and the sender must be a registered KYC provider.
+		///
+		/// Parameters:
+		/// - `user`: The address of the new account added to the whitelist.
+		/// - `tier`: The KYC tier of the account.
+		/// - `jurisdiction`: The jurisdiction code of the account.
+		/// - `expiry`: The block after which the account is no longer whitelisted.
+		///
+		/// Emits `NewUserWhitelisted` event when succesfful
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::provider_add_to_whitelist())]
+		pub fn provider_add_to_whitelist(
+			origin: OriginFor<T>,
+			user: AccountIdOf<T>,
+			tier: KycTier,
+			jurisdiction: JurisdictionCode,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(
+				KycProviders::<T>::contains_key(signer.clone()),
+				Error::<T>::ProviderNotRegistered
+			);
+			Self::do_add_to_whitelist(user, tier, jurisdiction, expiry, Some(signer))
+		}
+
+		/// Adds the caller to the whitelist with an attestation signed by a KYC provider.
+		///
+		/// The origin must be Signed and the sender must be the user of the attestation.
+		///
+		/// Parameters:
+		/// - `attestation`: The KYC attestation of the provider.
+		/// - `signature`: The sr25519 signature of the provider over the attestation payload.
+		///
+		/// Emits `NewUserWhitelisted` event when succesfful
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_kyc_attestation())]
+		pub fn submit_kyc_attestation(
+			origin: OriginFor<T>,
+			attestation: KycAttestationOf<T>,
+			signature: sr25519::Signature,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(signer == attestation.user, Error::<T>::NotAttestedUser);
+			Self::ensure_not_blocked(&signer)?;
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				attestation.valid_until >= current_block_number,
+				Error::<T>::AttestationExpired
+			);
+			ensure!(
+				attestation.issued_at <= current_block_number,
+				Error::<T>::AttestationNotYetValid
+			);
+			if let Some(removed_at) = RemovedAccounts::<T>::get(signer) {
+				ensure!(attestation.issued_at > removed_at, Error::<T>::AttestationRevoked);
+			}
+			let provider_details = KycProviders::<T>::get(attestation.provider.clone())
+				.ok_or(Error::<T>::ProviderNotRegistered)?;
+			let payload = Self::attestation_payload(&attestation);
+			let payload_hash = T::Hashing::hash(&payload[..]);
+			ensure!(
+				!UsedAttestations::<T>::contains_key(payload_hash),
+				Error::<T>::AttestationAlreadyUsed
+			);
+			ensure!(
+				signature.verify(&payload[..], &provider_details.signing_key),
+				Error::<T>::InvalidSignature
+			);
+			UsedAttestations::<T>::insert(payload_hash, ());
+			Self::do_add_to_whitelist(
+				attestation.user,
+				attestation.tier,
+				attestation.jurisdiction,
+				attestation.expiry,
+				Some(attestation.provider),
+			)
+		}
+
+		/// Adds multiple users to the whitelist.
+		///
+		/// The origin must be the sudo.
+		///
+		/// Parameters:
+		/// - `users`: The accounts with their KYC tier, jurisdiction code and expiry.
+		///
+		/// Emits `NewUserWhitelisted` event for every user when succesfful
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_many_to_whitelist(
+			users.len() as u32
+		))]
+		pub fn add_many_to_whitelist(
+			origin: OriginFor<T>,
+			users: BoundedVec<WhitelistEntry<T>, T::MaxWhitelistBatch>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			for (user, tier, jurisdiction, expiry) in users {
+				Self::do_add_to_whitelist(user, tier, jurisdiction, expiry, None)?;
+			}
+			Ok(())
+		}
+
+		/// Removes multiple users from the whitelist.
+		///
+		/// The origin must be the sudo.
+		///
+		/// Parameters:
+		/// - `users`: The accounts that get removed from the whitelist.
+		///
+		/// Emits `UserRemoved` event for every user when succesfful
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_many_from_whitelist(
+			users.len() as u32
+		))]
+		pub fn remove_many_from_whitelist(
+			origin: OriginFor<T>,
+			users: BoundedVec<AccountIdOf<T>, T::MaxWhitelistBatch>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			for user in users {
+				ensure!(
+					WhitelistedAccounts::<T>::contains_key(user.clone()),
+					Error::<T>::UserNotInWhitelist
+				);
+				Self::do_remove_from_whitelist(user);
+			}
+			Ok(())
+		}
+
+		/// Adds an account to the sanctions blocklist.
+		/// A blocked account can not interact with the marketplace, the property management and
+		/// the governance, regardless of its whitelist status.
+		///
+		/// The origin must be the ComplianceOrigin.
+		///
+		/// Parameters:
+		/// - `account`: The account that gets blocked.
+		///
+		/// Emits `AccountBlocked` event when succesfful
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::block_account())]
+		pub fn block_account(origin: OriginFor<T>, account: AccountIdOf<T>) -> DispatchResult {
+			T::ComplianceOrigin::ensure_origin(origin)?;
+			ensure!(
+				!BlockedAccounts::<T>::contains_key(account.clone()),
+				Error::<T>::AccountAlreadyBlocked
+			);
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			BlockedAccounts::<T>::insert(account.clone(), current_block_number);
+			RemovedAccounts::<T>::insert(account.clone(), current_block_number);
+			T::OnRemoval::on_removal(&account);
+			Self::deposit_event(Event::<T>::AccountBlocked { account });
+			Ok(())
+		}
+
+		/// Removes an account from the sanctions blocklist.
+		///
+		/// The origin must be the ComplianceOrigin.
+		///
+		/// Parameters:
+		/// - `account`: The account that gets unblocked.
+		///
+		/// Emits `AccountUnblocked` event when succesfful
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unblock_account())]
+		pub fn unblock_account(origin: OriginFor<T>, account: AccountIdOf<T>) -> DispatchResult {
+			T::ComplianceOrigin::ensure_origin(origin)?;
+			ensure!(
+				BlockedAccounts::<T>::contains_key(account.clone()),
+				Error::<T>::AccountNotBlocked
+			);
+			BlockedAccounts::<T>::remove(account.clone());
+			Self::deposit_event(Event::<T>::AccountUnblocked { account });
+			Ok(())
+		}
+
+		/// Restricts a jurisdiction or allows it again.
+		/// Accounts of a restricted jurisdiction can not be whitelisted and can not invest, the
+		/// accounts that are already whitelisted keep their token.
+		///
+		/// The origin must be the ComplianceOrigin.
+		///
+		/// Parameters:
+		/// - `jurisdiction`: The jurisdiction code.
+		/// - `restricted`: Whether the jurisdiction is restricted.
+		///
+		/// Emits `JurisdictionRestrictionSet` event when succesfful
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_jurisdiction_restriction())]
+		pub fn set_jurisdiction_restriction(
+			origin: OriginFor<T>,
+			jurisdiction: JurisdictionCode,
+			restricted: bool,
+		) -> DispatchResult {
+			T::ComplianceOrigin::ensure_origin(origin)?;
+			if restricted {
+				RestrictedJurisdictions::<T>::insert(jurisdiction, ());
+			} else {
+				RestrictedJurisdictions::<T>::remove(jurisdiction);
+			}
+			Self::deposit_event(Event::<T>::JurisdictionRestrictionSet { jurisdiction, restricted });
+			Ok(())
+		}
+
+		/// Removes accounts with an expired KYC from the whitelist to free their slots.
+		///
+		/// The origin must be Signed.
+		///
+		/// Parameters:
+		/// - `users`: The accounts whose KYC expired.
+		///
+		/// Emits `UserRemoved` event for every user when succesfful
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_expired_accounts(
+			users.len() as u32
+		))]
+		pub fn remove_expired_accounts(
+			origin: OriginFor<T>,
+			users: BoundedVec<AccountIdOf<T>, T::MaxWhitelistBatch>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			for user in users {
+				let details = WhitelistedAccounts::<T>::get(user.clone())
+					.ok_or(Error::<T>::UserNotInWhitelist)?;
+				ensure!(details.expiry <= current_block_number, Error::<T>::KycNotExpired);
+				Self::do_remove_from_whitelist(user);
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Adds a user to the whitelist and records the KYC provider that vouched for it.
+		fn do_add_to_whitelist(
+			user: AccountIdOf<T>,
+			tier: KycTier,
+			jurisdiction: JurisdictionCode,
+			expiry: BlockNumberFor<T>,
+			provider: Option<AccountIdOf<T>>,
+		) -> DispatchResult {
+			ensure!(
+				!Self::whitelisted_accounts(user.clone()),
+				Error::<T>::AccountAlreadyWhitelisted
+			);
+			ensure!(
+				expiry > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+			ensure!(
+				!RestrictedJurisdictions::<T>::contains_key(jurisdiction),
+				Error::<T>::JurisdictionRestricted
+			);
+			if !WhitelistedAccounts::<T>::contains_key(user.clone()) {
+				WhitelistedCount::<T>::try_mutate(|count| {
+					ensure!(*count < T::MaxUsersInWhitelist::get(), Error::<T>::TooManyUsers);
+					*count = count.saturating_add(1);
+					Ok::<(), DispatchError>(())
+				})?;
+			}
+			Self::remove_vouch(user.clone());
+			if let Some(ref provider) = provider {
+				KycProviders::<T>::try_mutate(provider.clone(), |maybe_provider| {
+					let provider_details =
+						maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotRegistered)?;
+					provider_details.vouched_accounts =
+						provider_details.vouched_accounts.saturating_add(1);
+					Ok::<(), DispatchError>(())
+				})?;
+				VouchedBy::<T>::insert(user.clone(), provider.clone());
+				VouchedAccounts::<T>::insert(provider.clone(), user.clone(), ());
+			}
+			WhitelistedAccounts::<T>::insert(
+				user.clone(),
+				KycDetails { tier, jurisdiction, expiry },
+			);
+			Self::deposit_event(Event::<T>::NewUserWhitelisted {
+				user,
+				tier,
+				jurisdiction,
+				expiry,
+				provider,
+			});
+			Ok(())
+		}
+
+		/// Removes a user from the whitelist. The investments of the user are kept, so that
+		/// the annual limit can not be reset by leaving and rejoining the whitelist.
+		fn do_remove_from_whitelist(user: AccountIdOf<T>) {
+			Self::remove_vouch(user.clone());
+			if WhitelistedAccounts::<T>::take(user.clone()).is_some() {
+				WhitelistedCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			}
+			RemovedAccounts::<T>::insert(user.clone(), <frame_system::Pallet<T>>::block_number());
+			T::OnRemoval::on_removal(&user);
 			Self::deposit_event(Event::<T>::UserRemoved { user });
+		}
+
+		/// Removes the record of the KYC provider that vouched for a user.
+		fn remove_vouch(user: AccountIdOf<T>) {
+			if let Some(provider) = VouchedBy::<T>::take(user.clone()) {
+				VouchedAccounts::<T>::remove(provider.clone(), user);
+				KycProviders::<T>::mutate(provider, |maybe_provider| {
+					if let Some(provider_details) = maybe_provider {
+						provider_details.vouched_accounts =
+							provider_details.vouched_accounts.saturating_sub(1);
+					}
+				});
+			}
+		}
+
+		/// Returns the payload that a KYC provider signs for an attestation. The payload is
+		/// prefixed with a domain tag and the genesis hash, so that a signature can not be
+		/// used for another purpose or on another chain.
+		pub fn attestation_payload(attestation: &KycAttestationOf<T>) -> sp_std::vec::Vec<u8> {
+			let genesis_hash =
+				<frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+			(ATTESTATION_DOMAIN, genesis_hash, attestation).encode()
+		}
+
+		/// Returns up to `limit` whitelisted accounts, starting after the account `start_after`.
+		pub fn whitelisted_users(
+			start_after: Option<AccountIdOf<T>>,
+			limit: u32,
+		) -> sp_std::vec::Vec<WhitelistInfoOf<T>> {
+			let accounts = match start_after {
+				Some(account) => WhitelistedAccounts::<T>::iter_from(
+					WhitelistedAccounts::<T>::hashed_key_for(account),
+				),
+				None => WhitelistedAccounts::<T>::iter(),
+			};
+			accounts
+				.take(limit as usize)
+				.map(|(account, details)| WhitelistInfo {
+					vouched_by: VouchedBy::<T>::get(account.clone()),
+					account,
+					tier: details.tier,
+					jurisdiction: details.jurisdiction,
+					expiry: details.expiry,
+				})
+				.collect()
+		}
+
+		/// Returns true if the account is whitelisted and its KYC has not expired.
+		pub fn whitelisted_accounts(account: AccountIdOf<T>) -> bool {
+			WhitelistedAccounts::<T>::get(account).map_or(false, |details| {
+				details.expiry > <frame_system::Pallet<T>>::block_number()
+			})
+		}
+
+		/// Returns true if the account is on the sanctions blocklist.
+		pub fn is_blocked(account: &AccountIdOf<T>) -> bool {
+			BlockedAccounts::<T>::contains_key(account)
+		}
+
+		/// Ensures that an account is not on the sanctions blocklist.
+		pub fn ensure_not_blocked(account: &AccountIdOf<T>) -> DispatchResult {
+			ensure!(!Self::is_blocked(account), Error::<T>::AccountBlocked);
+			Ok(())
+		}
+
+		/// Converts an amount of an asset with `decimals` decimals into the decimals of the
+		/// investment limits. Amounts with more decimals are rounded up.
+		pub fn normalize_investment(amount: u128, decimals: u8) -> T::InvestmentBalance {
+			let investment_decimals = T::InvestmentDecimals::get();
+			let normalized = if decimals > investment_decimals {
+				let divisor = 10u128.saturating_pow((decimals - investment_decimals).into());
+				let remainder = if amount % divisor > 0 { 1 } else { 0 };
+				(amount / divisor).saturating_add(remainder)
+			} else {
+				amount.saturating_mul(10u128.saturating_pow((investment_decimals - decimals).into()))
+			};
+			normalized.saturated_into()
+		}
+
+		/// Ensures that an account can invest `amount` of an asset with `decimals` decimals
+		/// without exceeding the limits of its KYC tier.
+		pub fn ensure_investment_allowed(
+			account: AccountIdOf<T>,
+			amount: u128,
+			decimals: u8,
+		) -> DispatchResult {
+			Self::checked_investment(account, Self::normalize_investment(amount, decimals))
+				.map(|_| ())
+		}
+
+		/// Checks an investment of `amount` of an asset with `decimals` decimals against the
+		/// limits of the KYC tier of an account and adds it to the amount the account invested
+		/// in the current investment period.
+		pub fn record_investment(
+			account: AccountIdOf<T>,
+			amount: u128,
+			decimals: u8,
+		) -> DispatchResult {
+			let amount = Self::normalize_investment(amount, decimals);
+			if let Some(record) = Self::checked_investment(account.clone(), amount)? {
+				AnnualInvestments::<T>::insert(account, record);
+			}
 			Ok(())
 		}
+
+		/// Checks an investment against the limits of the KYC tier of an account and returns
+		/// the updated investment record, or `None` if the tier has no limits.
+		fn checked_investment(
+			account: AccountIdOf<T>,
+			amount: T::InvestmentBalance,
+		) -> Result<Option<InvestmentRecord<T::InvestmentBalance, BlockNumberFor<T>>>, DispatchError>
+		{
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let details = WhitelistedAccounts::<T>::get(account.clone())
+				.filter(|details| details.expiry > current_block_number)
+				.ok_or(Error::<T>::UserNotInWhitelist)?;
+			ensure!(
+				!RestrictedJurisdictions::<T>::contains_key(details.jurisdiction),
+				Error::<T>::JurisdictionRestricted
+			);
+			let Some(limits) = TierLimits::<T>::get(details.tier) else {
+				return Ok(None);
+			};
+			ensure!(amount <= limits.per_transaction, Error::<T>::TransactionLimitExceeded);
+			let mut record = AnnualInvestments::<T>::get(account);
+			if current_block_number >=
+				record.period_start.saturating_add(T::InvestmentPeriod::get()) ||
+				record.invested.is_zero()
+			{
+				record = InvestmentRecord {
+					period_start: current_block_number,
+					invested: Default::default(),
+				};
+			}
+			record.invested = record.invested.saturating_add(amount);
+			ensure!(record.invested <= limits.annual, Error::<T>::AnnualLimitExceeded);
+			Ok(Some(record))
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait WhitelistApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the number of accounts in the whitelist.
+		fn get_whitelisted_count() -> u32;
+
+		/// Returns up to `limit` whitelisted accounts, starting after the account `start_after`.
+		fn get_whitelisted_accounts(
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> sp_std::vec::Vec<WhitelistInfo<AccountId, BlockNumber>>;
 	}
 }
@@ -0,0 +1,65 @@
+//! Storage migrations for the whitelist pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Bounded, Saturating};
+
+/// Migrates the whitelist from the layout before the KYC details were introduced.
+pub mod v1 {
+	use super::*;
+
+	/// The jurisdiction of the accounts that have been whitelisted before the jurisdictions
+	/// were recorded. `ZZ` is a user-assigned ISO 3166-1 code that no country uses.
+	pub const UNKNOWN_JURISDICTION: JurisdictionCode = *b"ZZ";
+
+	/// Replaces the whitelist flag of the accounts with KYC details. The accounts get the
+	/// retail tier, the unknown jurisdiction and no expiry until they are verified again.
+	/// Initialises the number of whitelisted accounts.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+			let mut translated: u64 = 0;
+			let mut whitelisted_count: u32 = 0;
+			WhitelistedAccounts::<T>::translate::<bool, _>(|_, whitelisted| {
+				translated.saturating_inc();
+				if whitelisted {
+					whitelisted_count.saturating_inc();
+				}
+				whitelisted.then(|| KycDetails {
+					tier: KycTier::Retail,
+					jurisdiction: UNKNOWN_JURISDICTION,
+					expiry: BlockNumberFor::<T>::max_value(),
+				})
+			});
+			WhitelistedCount::<T>::put(whitelisted_count);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(1, 2)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(translated))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(
+			_state: sp_std::vec::Vec<u8>,
+		) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"the storage version of the whitelist has not been updated"
+			);
+			ensure!(
+				WhitelistedCount::<T>::get() as usize ==
+					WhitelistedAccounts::<T>::iter_keys().count(),
+				"the number of whitelisted accounts is wrong"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,5 +1,6 @@
 use crate as pallet_whitelist;
 use frame_support::{parameter_types, traits::ConstU64};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
@@ -44,17 +45,29 @@ impl frame_system::Config for Test {
 }
 
 parameter_types! {
-	pub const MaxWhitelistUsers: u32 = 1000000;
+	pub static MaxWhitelistUsers: u32 = 1000000;
+	pub const InvestmentPeriod: u64 = 100;
+	pub const InvestmentDecimals: u8 = 0;
+	pub const MaxWhitelistBatch: u32 = 100;
 }
 
 impl pallet_whitelist::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Test>;
 	type WhitelistOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ComplianceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type MaxUsersInWhitelist = MaxWhitelistUsers;
+	type InvestmentBalance = u64;
+	type InvestmentPeriod = InvestmentPeriod;
+	type InvestmentDecimals = InvestmentDecimals;
+	type MaxWhitelistBatch = MaxWhitelistBatch;
+	type OnRemoval = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut ext: sp_io::TestExternalities =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext
 }
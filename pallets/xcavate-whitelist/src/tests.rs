@@ -1,13 +1,58 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
+use crate::{
+	mock::*, AnnualInvestments, Error, InvestmentLimits, KycAttestation, KycProviders, KycTier,
+	TierLimits, VouchedAccounts, VouchedBy, WhitelistedCount, BlockedAccounts,
+	RestrictedJurisdictions, WhitelistedAccounts, UsedAttestations, RemovedAccounts,
+};
+use crate::migrations::v1;
+use frame_support::BoundedVec;
+use codec::Encode;
+use sp_core::{sr25519, Pair};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
 
 #[test]
 fn add_to_whitelist_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100));
 		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_eq!(Whitelist::kyc_details(1).unwrap().jurisdiction, *b"GB");
+	});
+}
+
+#[test]
+fn add_to_whitelist_fails_with_invalid_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		assert_noop!(
+			Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 10),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn expired_account_is_not_whitelisted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 10));
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		System::set_block_number(10);
+		assert_eq!(Whitelist::whitelisted_accounts(1), false);
+		assert_noop!(Whitelist::record_investment(1, 100, 0), Error::<Test>::UserNotInWhitelist);
+		assert_ok!(Whitelist::add_to_whitelist(
+			RuntimeOrigin::root(),
+			1,
+			KycTier::Sophisticated,
+			*b"DE",
+			50
+		));
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_eq!(Whitelist::kyc_details(1).unwrap().tier, KycTier::Sophisticated);
 	});
 }
 
@@ -15,9 +60,9 @@ fn add_to_whitelist_works() {
 fn add_to_whitelist_fails_when_user_already_added() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100));
 		assert_noop!(
-			Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1),
+			Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100),
 			Error::<Test>::AccountAlreadyWhitelisted
 		);
 	});
@@ -27,7 +72,10 @@ fn add_to_whitelist_fails_when_user_already_added() {
 fn add_to_whitelist_fails_with_no_permission() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_noop!(Whitelist::add_to_whitelist(RuntimeOrigin::signed(2), 1), BadOrigin);
+		assert_noop!(
+			Whitelist::add_to_whitelist(RuntimeOrigin::signed(2), 1, KycTier::Retail, *b"GB", 100),
+			BadOrigin
+		);
 	});
 }
 
@@ -35,7 +83,7 @@ fn add_to_whitelist_fails_with_no_permission() {
 fn remove_from_whitelist_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100));
 		assert_ok!(Whitelist::remove_from_whitelist(RuntimeOrigin::root(), 1));
 		assert_eq!(Whitelist::whitelisted_accounts(1), false);
 	});
@@ -45,7 +93,7 @@ fn remove_from_whitelist_works() {
 fn remove_from_whitelist_fails_with_no_permission() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100));
 		assert_noop!(Whitelist::remove_from_whitelist(RuntimeOrigin::signed(2), 1), BadOrigin);
 	});
 }
@@ -60,3 +108,611 @@ fn remove_from_whitelist_fails() {
 		);
 	});
 }
+
+#[test]
+fn set_tier_limits_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let limits = InvestmentLimits { per_transaction: 100, annual: 250 };
+		assert_ok!(Whitelist::set_tier_limits(
+			RuntimeOrigin::root(),
+			KycTier::Retail,
+			Some(limits.clone())
+		));
+		assert_eq!(TierLimits::<Test>::get(KycTier::Retail), Some(limits));
+		assert_ok!(Whitelist::set_tier_limits(RuntimeOrigin::root(), KycTier::Retail, None));
+		assert_eq!(TierLimits::<Test>::get(KycTier::Retail), None);
+	});
+}
+
+#[test]
+fn set_tier_limits_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Whitelist::set_tier_limits(
+				RuntimeOrigin::signed(1),
+				KycTier::Retail,
+				Some(InvestmentLimits { per_transaction: 100, annual: 250 })
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Whitelist::set_tier_limits(
+				RuntimeOrigin::root(),
+				KycTier::Retail,
+				Some(InvestmentLimits { per_transaction: 300, annual: 250 })
+			),
+			Error::<Test>::InvalidLimits
+		);
+	});
+}
+
+#[test]
+fn record_investment_respects_tier_limits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 500));
+		assert_ok!(Whitelist::add_to_whitelist(
+			RuntimeOrigin::root(),
+			2,
+			KycTier::Institutional,
+			*b"GB",
+			500
+		));
+		assert_ok!(Whitelist::set_tier_limits(
+			RuntimeOrigin::root(),
+			KycTier::Retail,
+			Some(InvestmentLimits { per_transaction: 100, annual: 250 })
+		));
+		assert_noop!(Whitelist::record_investment(1, 101, 0), Error::<Test>::TransactionLimitExceeded);
+		assert_ok!(Whitelist::record_investment(1, 100, 0));
+		assert_ok!(Whitelist::record_investment(1, 100, 0));
+		assert_noop!(Whitelist::record_investment(1, 51, 0), Error::<Test>::AnnualLimitExceeded);
+		assert_ok!(Whitelist::record_investment(1, 50, 0));
+		assert_eq!(AnnualInvestments::<Test>::get(1).invested, 250);
+		assert_ok!(Whitelist::record_investment(2, 1_000, 0));
+		System::set_block_number(101);
+		assert_ok!(Whitelist::record_investment(1, 100, 0));
+		assert_eq!(AnnualInvestments::<Test>::get(1).invested, 100);
+		assert_eq!(AnnualInvestments::<Test>::get(1).period_start, 101);
+	});
+}
+
+#[test]
+fn record_investment_converts_decimals() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 500));
+		assert_ok!(Whitelist::set_tier_limits(
+			RuntimeOrigin::root(),
+			KycTier::Retail,
+			Some(InvestmentLimits { per_transaction: 100, annual: 250 })
+		));
+		assert_noop!(
+			Whitelist::record_investment(1, 1_000_001, 4),
+			Error::<Test>::TransactionLimitExceeded
+		);
+		assert_ok!(Whitelist::ensure_investment_allowed(1, 1_000_000, 4));
+		assert_eq!(AnnualInvestments::<Test>::get(1).invested, 0);
+		assert_ok!(Whitelist::record_investment(1, 1_000_000, 4));
+		assert_eq!(AnnualInvestments::<Test>::get(1).invested, 100);
+	});
+}
+
+#[test]
+fn removed_account_keeps_its_investments() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 500));
+		assert_ok!(Whitelist::set_tier_limits(
+			RuntimeOrigin::root(),
+			KycTier::Retail,
+			Some(InvestmentLimits { per_transaction: 200, annual: 250 })
+		));
+		assert_ok!(Whitelist::record_investment(1, 200, 0));
+		assert_ok!(Whitelist::remove_from_whitelist(RuntimeOrigin::root(), 1));
+		assert_eq!(AnnualInvestments::<Test>::get(1).invested, 200);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 500));
+		assert_noop!(Whitelist::record_investment(1, 100, 0), Error::<Test>::AnnualLimitExceeded);
+	});
+}
+
+#[test]
+fn restricted_jurisdiction_can_not_invest() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 500));
+		assert_noop!(
+			Whitelist::set_jurisdiction_restriction(RuntimeOrigin::signed(1), *b"GB", true),
+			BadOrigin
+		);
+		assert_ok!(Whitelist::set_jurisdiction_restriction(RuntimeOrigin::root(), *b"GB", true));
+		assert_eq!(RestrictedJurisdictions::<Test>::contains_key(*b"GB"), true);
+		assert_noop!(Whitelist::record_investment(1, 100, 0), Error::<Test>::JurisdictionRestricted);
+		assert_noop!(
+			Whitelist::add_to_whitelist(RuntimeOrigin::root(), 2, KycTier::Retail, *b"GB", 500),
+			Error::<Test>::JurisdictionRestricted
+		);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 3, KycTier::Retail, *b"DE", 500));
+		assert_ok!(Whitelist::set_jurisdiction_restriction(RuntimeOrigin::root(), *b"GB", false));
+		assert_ok!(Whitelist::record_investment(1, 100, 0));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 2, KycTier::Retail, *b"GB", 500));
+	});
+}
+
+fn attestation(provider: u64, user: u64, valid_until: u64) -> KycAttestation<u64, u64> {
+	KycAttestation {
+		provider,
+		user,
+		tier: KycTier::Sophisticated,
+		jurisdiction: *b"DE",
+		expiry: 100,
+		issued_at: 1,
+		valid_until,
+	}
+}
+
+#[test]
+fn register_kyc_provider_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let signing_key = sr25519::Pair::from_seed(&[1; 32]).public();
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, signing_key));
+		assert_eq!(KycProviders::<Test>::get(10).unwrap().signing_key, signing_key);
+		assert_eq!(KycProviders::<Test>::get(10).unwrap().vouched_accounts, 0);
+	});
+}
+
+#[test]
+fn register_kyc_provider_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let signing_key = sr25519::Pair::from_seed(&[1; 32]).public();
+		assert_noop!(
+			Whitelist::register_kyc_provider(RuntimeOrigin::signed(10), 10, signing_key),
+			BadOrigin
+		);
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, signing_key));
+		assert_noop!(
+			Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, signing_key),
+			Error::<Test>::ProviderAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn provider_add_to_whitelist_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let signing_key = sr25519::Pair::from_seed(&[1; 32]).public();
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, signing_key));
+		assert_ok!(Whitelist::provider_add_to_whitelist(
+			RuntimeOrigin::signed(10),
+			1,
+			KycTier::Retail,
+			*b"GB",
+			100
+		));
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_eq!(VouchedBy::<Test>::get(1), Some(10));
+		assert_eq!(VouchedAccounts::<Test>::get(10, 1), Some(()));
+		assert_eq!(KycProviders::<Test>::get(10).unwrap().vouched_accounts, 1);
+		assert_ok!(Whitelist::remove_from_whitelist(RuntimeOrigin::root(), 1));
+		assert_eq!(VouchedBy::<Test>::get(1), None);
+		assert_eq!(VouchedAccounts::<Test>::get(10, 1), None);
+		assert_eq!(KycProviders::<Test>::get(10).unwrap().vouched_accounts, 0);
+	});
+}
+
+#[test]
+fn provider_add_to_whitelist_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Whitelist::provider_add_to_whitelist(
+				RuntimeOrigin::signed(10),
+				1,
+				KycTier::Retail,
+				*b"GB",
+				100
+			),
+			Error::<Test>::ProviderNotRegistered
+		);
+	});
+}
+
+#[test]
+fn submit_kyc_attestation_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, pair.public()));
+		let attestation = attestation(10, 1, 10);
+		let signature = pair.sign(&Whitelist::attestation_payload(&attestation));
+		assert_ok!(Whitelist::submit_kyc_attestation(
+			RuntimeOrigin::signed(1),
+			attestation,
+			signature
+		));
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_eq!(Whitelist::kyc_details(1).unwrap().tier, KycTier::Sophisticated);
+		assert_eq!(VouchedBy::<Test>::get(1), Some(10));
+	});
+}
+
+#[test]
+fn submit_kyc_attestation_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		let other_pair = sr25519::Pair::from_seed(&[2; 32]);
+		let attestation = attestation(10, 1, 10);
+		let signature = pair.sign(&Whitelist::attestation_payload(&attestation));
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(1),
+				attestation.clone(),
+				signature.clone()
+			),
+			Error::<Test>::ProviderNotRegistered
+		);
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, pair.public()));
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(2),
+				attestation.clone(),
+				signature.clone()
+			),
+			Error::<Test>::NotAttestedUser
+		);
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(1),
+				attestation.clone(),
+				other_pair.sign(&Whitelist::attestation_payload(&attestation))
+			),
+			Error::<Test>::InvalidSignature
+		);
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(1),
+				attestation.clone(),
+				pair.sign(&attestation.encode())
+			),
+			Error::<Test>::InvalidSignature
+		);
+		let future_attestation = KycAttestation { issued_at: 2, ..attestation.clone() };
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(1),
+				future_attestation.clone(),
+				pair.sign(&Whitelist::attestation_payload(&future_attestation))
+			),
+			Error::<Test>::AttestationNotYetValid
+		);
+		assert_ok!(Whitelist::block_account(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(1),
+				attestation.clone(),
+				signature.clone()
+			),
+			Error::<Test>::AccountBlocked
+		);
+		assert_ok!(Whitelist::unblock_account(RuntimeOrigin::root(), 1));
+		System::set_block_number(11);
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(RuntimeOrigin::signed(1), attestation, signature),
+			Error::<Test>::AttestationExpired
+		);
+	});
+}
+
+#[test]
+fn submit_kyc_attestation_can_not_be_replayed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, pair.public()));
+		let attestation = KycAttestation { expiry: 5, ..attestation(10, 1, 10) };
+		let signature = pair.sign(&Whitelist::attestation_payload(&attestation));
+		assert_ok!(Whitelist::submit_kyc_attestation(
+			RuntimeOrigin::signed(1),
+			attestation.clone(),
+			signature.clone()
+		));
+		assert!(UsedAttestations::<Test>::contains_key(BlakeTwo256::hash(
+			&Whitelist::attestation_payload(&attestation)
+		)));
+		System::set_block_number(6);
+		assert_eq!(Whitelist::whitelisted_accounts(1), false);
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(RuntimeOrigin::signed(1), attestation, signature),
+			Error::<Test>::AttestationAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn submit_kyc_attestation_fails_after_removal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, pair.public()));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100));
+		let attestation = attestation(10, 1, 10);
+		let signature = pair.sign(&Whitelist::attestation_payload(&attestation));
+		System::set_block_number(2);
+		assert_ok!(Whitelist::remove_from_whitelist(RuntimeOrigin::root(), 1));
+		assert_eq!(RemovedAccounts::<Test>::get(1), Some(2));
+		assert_noop!(
+			Whitelist::submit_kyc_attestation(
+				RuntimeOrigin::signed(1),
+				attestation.clone(),
+				signature.clone()
+			),
+			Error::<Test>::AttestationRevoked
+		);
+		System::set_block_number(3);
+		let new_attestation = KycAttestation { issued_at: 3, ..attestation };
+		assert_ok!(Whitelist::submit_kyc_attestation(
+			RuntimeOrigin::signed(1),
+			new_attestation.clone(),
+			pair.sign(&Whitelist::attestation_payload(&new_attestation))
+		));
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+	});
+}
+
+#[test]
+fn revoke_kyc_provider_removes_vouched_users() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let signing_key = sr25519::Pair::from_seed(&[1; 32]).public();
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, signing_key));
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 11, signing_key));
+		for user in 1..4 {
+			assert_ok!(Whitelist::provider_add_to_whitelist(
+				RuntimeOrigin::signed(10),
+				user,
+				KycTier::Retail,
+				*b"GB",
+				100
+			));
+		}
+		assert_ok!(Whitelist::provider_add_to_whitelist(
+			RuntimeOrigin::signed(11),
+			4,
+			KycTier::Retail,
+			*b"GB",
+			100
+		));
+		assert_noop!(
+			Whitelist::revoke_kyc_provider(RuntimeOrigin::root(), 10, true, 2),
+			Error::<Test>::InvalidWitness
+		);
+		assert_ok!(Whitelist::revoke_kyc_provider(RuntimeOrigin::root(), 10, true, 3));
+		assert_eq!(KycProviders::<Test>::get(10), None);
+		for user in 1..4 {
+			assert_eq!(Whitelist::whitelisted_accounts(user), false);
+			assert_eq!(VouchedBy::<Test>::get(user), None);
+			assert_eq!(RemovedAccounts::<Test>::get(user), Some(1));
+		}
+		assert_eq!(Whitelist::whitelisted_accounts(4), true);
+		System::assert_last_event(
+			crate::Event::KycProviderRevoked { provider: 10, removed_users: 3 }.into(),
+		);
+	});
+}
+
+#[test]
+fn revoke_kyc_provider_keeps_users() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let signing_key = sr25519::Pair::from_seed(&[1; 32]).public();
+		assert_ok!(Whitelist::register_kyc_provider(RuntimeOrigin::root(), 10, signing_key));
+		assert_ok!(Whitelist::provider_add_to_whitelist(
+			RuntimeOrigin::signed(10),
+			1,
+			KycTier::Retail,
+			*b"GB",
+			100
+		));
+		assert_ok!(Whitelist::revoke_kyc_provider(RuntimeOrigin::root(), 10, false, 1));
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_eq!(VouchedBy::<Test>::get(1), None);
+		assert_eq!(VouchedAccounts::<Test>::get(10, 1), None);
+		assert_noop!(
+			Whitelist::revoke_kyc_provider(RuntimeOrigin::root(), 10, false, 0),
+			Error::<Test>::ProviderNotRegistered
+		);
+	});
+}
+
+#[test]
+fn add_many_to_whitelist_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let users: BoundedVec<_, MaxWhitelistBatch> = (1..4)
+			.map(|user| (user, KycTier::Retail, *b"GB", 100))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		assert_ok!(Whitelist::add_many_to_whitelist(RuntimeOrigin::root(), users));
+		assert_eq!(WhitelistedCount::<Test>::get(), 3);
+		for user in 1..4 {
+			assert_eq!(Whitelist::whitelisted_accounts(user), true);
+		}
+		let accounts = Whitelist::whitelisted_users(None, 2);
+		assert_eq!(accounts.len(), 2);
+		let remaining = Whitelist::whitelisted_users(Some(accounts[1].account), 2);
+		assert_eq!(remaining.len(), 1);
+		let mut all: Vec<u64> =
+			accounts.iter().chain(remaining.iter()).map(|info| info.account).collect();
+		all.sort();
+		assert_eq!(all, vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn add_many_to_whitelist_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 2, KycTier::Retail, *b"GB", 100));
+		let users: BoundedVec<_, MaxWhitelistBatch> =
+			vec![(1, KycTier::Retail, *b"GB", 100), (2, KycTier::Retail, *b"GB", 100)]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			Whitelist::add_many_to_whitelist(RuntimeOrigin::signed(1), users.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			Whitelist::add_many_to_whitelist(RuntimeOrigin::root(), users),
+			Error::<Test>::AccountAlreadyWhitelisted
+		);
+		assert_eq!(Whitelist::whitelisted_accounts(1), false);
+		assert_eq!(WhitelistedCount::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn whitelist_enforces_max_users() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxWhitelistUsers::set(&2);
+		let users: BoundedVec<_, MaxWhitelistBatch> =
+			vec![(1, KycTier::Retail, *b"GB", 100), (2, KycTier::Retail, *b"GB", 100)]
+				.try_into()
+				.unwrap();
+		assert_ok!(Whitelist::add_many_to_whitelist(RuntimeOrigin::root(), users));
+		assert_noop!(
+			Whitelist::add_to_whitelist(RuntimeOrigin::root(), 3, KycTier::Retail, *b"GB", 100),
+			Error::<Test>::TooManyUsers
+		);
+		assert_ok!(Whitelist::remove_from_whitelist(RuntimeOrigin::root(), 1));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 3, KycTier::Retail, *b"GB", 100));
+		assert_eq!(WhitelistedCount::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn remove_expired_accounts_frees_slots() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxWhitelistUsers::set(&2);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 10));
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 2, KycTier::Retail, *b"GB", 100));
+		assert_noop!(
+			Whitelist::remove_expired_accounts(RuntimeOrigin::signed(5), vec![1].try_into().unwrap()),
+			Error::<Test>::KycNotExpired
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			Whitelist::add_to_whitelist(RuntimeOrigin::root(), 3, KycTier::Retail, *b"GB", 100),
+			Error::<Test>::TooManyUsers
+		);
+		assert_noop!(
+			Whitelist::remove_expired_accounts(
+				RuntimeOrigin::signed(5),
+				vec![1, 2].try_into().unwrap()
+			),
+			Error::<Test>::KycNotExpired
+		);
+		assert_noop!(
+			Whitelist::remove_expired_accounts(RuntimeOrigin::signed(5), vec![3].try_into().unwrap()),
+			Error::<Test>::UserNotInWhitelist
+		);
+		assert_ok!(Whitelist::remove_expired_accounts(
+			RuntimeOrigin::signed(5),
+			vec![1].try_into().unwrap()
+		));
+		assert_eq!(Whitelist::kyc_details(1), None);
+		assert_eq!(WhitelistedCount::<Test>::get(), 1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 3, KycTier::Retail, *b"GB", 100));
+		assert_eq!(WhitelistedCount::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn remove_many_from_whitelist_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let users: BoundedVec<_, MaxWhitelistBatch> = (1..4)
+			.map(|user| (user, KycTier::Retail, *b"GB", 100))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		assert_ok!(Whitelist::add_many_to_whitelist(RuntimeOrigin::root(), users));
+		assert_ok!(Whitelist::remove_many_from_whitelist(
+			RuntimeOrigin::root(),
+			vec![1, 3].try_into().unwrap()
+		));
+		assert_eq!(WhitelistedCount::<Test>::get(), 1);
+		assert_eq!(Whitelist::whitelisted_accounts(1), false);
+		assert_eq!(Whitelist::whitelisted_accounts(2), true);
+		assert_noop!(
+			Whitelist::remove_many_from_whitelist(
+				RuntimeOrigin::root(),
+				vec![2, 3].try_into().unwrap()
+			),
+			Error::<Test>::UserNotInWhitelist
+		);
+	});
+}
+
+#[test]
+fn block_account_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Whitelist::add_to_whitelist(RuntimeOrigin::root(), 1, KycTier::Retail, *b"GB", 100));
+		assert_ok!(Whitelist::block_account(RuntimeOrigin::root(), 1));
+		assert_eq!(BlockedAccounts::<Test>::get(1), Some(1));
+		assert_eq!(Whitelist::is_blocked(&1), true);
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_noop!(Whitelist::ensure_not_blocked(&1), Error::<Test>::AccountBlocked);
+		assert_ok!(Whitelist::block_account(RuntimeOrigin::root(), 2));
+		assert_eq!(Whitelist::is_blocked(&2), true);
+		assert_ok!(Whitelist::unblock_account(RuntimeOrigin::root(), 1));
+		assert_eq!(Whitelist::is_blocked(&1), false);
+		assert_ok!(Whitelist::ensure_not_blocked(&1));
+	});
+}
+
+#[test]
+fn block_account_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(Whitelist::block_account(RuntimeOrigin::signed(1), 2), BadOrigin);
+		assert_ok!(Whitelist::block_account(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			Whitelist::block_account(RuntimeOrigin::root(), 2),
+			Error::<Test>::AccountAlreadyBlocked
+		);
+		assert_noop!(Whitelist::unblock_account(RuntimeOrigin::signed(1), 2), BadOrigin);
+		assert_noop!(
+			Whitelist::unblock_account(RuntimeOrigin::root(), 1),
+			Error::<Test>::AccountNotBlocked
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StorageVersion::new(0).put::<Whitelist>();
+		unhashed::put(&WhitelistedAccounts::<Test>::hashed_key_for(1), &true);
+		unhashed::put(&WhitelistedAccounts::<Test>::hashed_key_for(2), &false);
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		let details = Whitelist::kyc_details(1).unwrap();
+		assert_eq!(details.tier, KycTier::Retail);
+		assert_eq!(details.jurisdiction, v1::UNKNOWN_JURISDICTION);
+		assert_eq!(details.expiry, u64::MAX);
+		assert_eq!(Whitelist::whitelisted_accounts(1), true);
+		assert_eq!(Whitelist::kyc_details(2), None);
+		assert_eq!(WhitelistedCount::<Test>::get(), 1);
+		assert_eq!(Whitelist::on_chain_storage_version(), 1);
+	});
+}
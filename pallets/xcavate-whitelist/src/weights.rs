@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `LAPTOP-DFFNONK6`, CPU: `11th Gen Intel(R) Core(TM) i7-1165G7 @ 2.80GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The weights in the section marked as not benchmarked have not been generated by the
+//! benchmark CLI. They are estimates that get replaced by the next benchmark run.
 
 // Executed Command:
 // ./target/release/node-template
@@ -35,33 +38,188 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_to_whitelist() -> Weight;
 	fn remove_from_whitelist() -> Weight;
+	fn set_tier_limits() -> Weight;
+	fn register_kyc_provider() -> Weight;
+	fn revoke_kyc_provider(n: u32, ) -> Weight;
+	fn provider_add_to_whitelist() -> Weight;
+	fn submit_kyc_attestation() -> Weight;
+	fn add_many_to_whitelist(n: u32, ) -> Weight;
+	fn remove_many_from_whitelist(n: u32, ) -> Weight;
+	fn block_account() -> Weight;
+	fn unblock_account() -> Weight;
+	fn set_jurisdiction_restriction() -> Weight;
+	fn remove_expired_accounts(n: u32, ) -> Weight;
 }
 
 /// Weight functions for `pallet_xcavate_whitelist`.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// NOT BENCHMARKED: the weights below are estimated from the storage accesses of their
+	// benchmarks and the measured weights of calls doing the same work.
+
+	/// Estimated from the last measured `add_to_whitelist`.
 	/// Storage: `Whitelist::WhitelistedAccounts` (r:1 w:1)
-	/// Proof: `Whitelist::WhitelistedAccounts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::RestrictedJurisdictions` (r:1 w:0)
+	/// Storage: `Whitelist::VouchedBy` (r:1 w:1)
+	/// Storage: `Whitelist::KycProviders` (r:1 w:1)
+	/// Storage: `Whitelist::VouchedAccounts` (r:0 w:1)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
 	fn add_to_whitelist() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `3514`
-		// Minimum execution time: 11_842_000 picoseconds.
 		Weight::from_parts(12_936_000, 0)
-			.saturating_add(Weight::from_parts(0, 3514))
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Estimated from the last measured `remove_from_whitelist`.
+	/// Storage: `Whitelist::WhitelistedAccounts` (r:1 w:1)
+	/// Storage: `Whitelist::VouchedBy` (r:1 w:1)
+	/// Storage: `Whitelist::KycProviders` (r:1 w:1)
+	/// Storage: `Whitelist::VouchedAccounts` (r:0 w:1)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	/// Storage: `Whitelist::RemovedAccounts` (r:0 w:1)
+	/// Storage: `Whitelist::BlockedAccounts` (r:1 w:0)
+	fn remove_from_whitelist() -> Weight {
+		Weight::from_parts(14_661_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Estimated from the last measured `add_to_whitelist`.
+	/// Storage: `Whitelist::TierLimits` (r:0 w:1)
+	fn set_tier_limits() -> Weight {
+		Weight::from_parts(12_936_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated from the last measured `add_to_whitelist`.
+	/// Storage: `Whitelist::RestrictedJurisdictions` (r:0 w:1)
+	fn set_jurisdiction_restriction() -> Weight {
+		Weight::from_parts(12_936_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated from the last measured `register_kyc_provider`.
+	/// Storage: `Whitelist::KycProviders` (r:1 w:1)
+	fn register_kyc_provider() -> Weight {
+		Weight::from_parts(11_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Estimated from the last measured `revoke_kyc_provider`.
+	/// Storage: `Whitelist::KycProviders` (r:1 w:1)
+	/// Storage: `Whitelist::VouchedAccounts` (r:1001 w:1000)
+	/// Storage: `Whitelist::VouchedBy` (r:0 w:1000)
+	/// Storage: `Whitelist::WhitelistedAccounts` (r:1000 w:1000)
+	/// Storage: `Whitelist::RemovedAccounts` (r:0 w:1000)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	/// The range of component `n` is `[0, 1000]`.
+	fn revoke_kyc_provider(n: u32, ) -> Weight {
+		Weight::from_parts(15_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(n.into()))
+	}
+	/// Estimated from the last measured `provider_add_to_whitelist`.
+	/// Storage: `Whitelist::KycProviders` (r:1 w:1)
 	/// Storage: `Whitelist::WhitelistedAccounts` (r:1 w:1)
-	/// Proof: `Whitelist::WhitelistedAccounts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn remove_from_whitelist() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `151`
-		//  Estimated: `3514`
-		// Minimum execution time: 14_352_000 picoseconds.
+	/// Storage: `Whitelist::RestrictedJurisdictions` (r:1 w:0)
+	/// Storage: `Whitelist::VouchedBy` (r:1 w:1)
+	/// Storage: `Whitelist::VouchedAccounts` (r:0 w:1)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	fn provider_add_to_whitelist() -> Weight {
+		Weight::from_parts(18_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Estimated from the last measured `provider_add_to_whitelist` plus the
+	/// `sr25519_verification` of `frame_benchmarking`.
+	/// Storage: `Whitelist::BlockedAccounts` (r:1 w:0)
+	/// Storage: `Whitelist::RemovedAccounts` (r:1 w:0)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Storage: `Whitelist::UsedAttestations` (r:1 w:1)
+	/// Storage: `Whitelist::KycProviders` (r:1 w:1)
+	/// Storage: `Whitelist::WhitelistedAccounts` (r:1 w:1)
+	/// Storage: `Whitelist::RestrictedJurisdictions` (r:1 w:0)
+	/// Storage: `Whitelist::VouchedBy` (r:1 w:1)
+	/// Storage: `Whitelist::VouchedAccounts` (r:0 w:1)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	fn submit_kyc_attestation() -> Weight {
+		Weight::from_parts(66_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Estimated from the last measured `remove_many_from_whitelist`.
+	/// Storage: `Whitelist::WhitelistedAccounts` (r:100 w:100)
+	/// Storage: `Whitelist::VouchedBy` (r:100 w:100)
+	/// Storage: `Whitelist::RemovedAccounts` (r:0 w:100)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn remove_many_from_whitelist(n: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(13_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2555).saturating_mul(n.into()))
+	}
+	/// Estimated from the last measured `remove_from_whitelist`.
+	/// Storage: `Whitelist::BlockedAccounts` (r:1 w:1)
+	/// Storage: `Whitelist::RemovedAccounts` (r:0 w:1)
+	/// Storage: `PropertyManagement::StoredFunds` (r:1 w:1)
+	/// Storage: `PropertyManagement::QuarantinedFunds` (r:1 w:1)
+	fn block_account() -> Weight {
+		Weight::from_parts(14_661_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Estimated from the last measured `remove_from_whitelist`.
+	/// Storage: `Whitelist::BlockedAccounts` (r:1 w:1)
+	fn unblock_account() -> Weight {
 		Weight::from_parts(14_661_000, 0)
-			.saturating_add(Weight::from_parts(0, 3514))
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Estimated from the measured `add_to_whitelist` for every user.
+	/// Storage: `Whitelist::WhitelistedAccounts` (r:100 w:100)
+	/// Storage: `Whitelist::RestrictedJurisdictions` (r:100 w:0)
+	/// Storage: `Whitelist::VouchedBy` (r:100 w:0)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn add_many_to_whitelist(n: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(12_936_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2555).saturating_mul(n.into()))
+	}
+	/// Estimated from the measured `remove_from_whitelist` for every user.
+	/// Storage: `Whitelist::WhitelistedAccounts` (r:100 w:100)
+	/// Storage: `Whitelist::VouchedBy` (r:100 w:100)
+	/// Storage: `Whitelist::KycProviders` (r:100 w:100)
+	/// Storage: `Whitelist::VouchedAccounts` (r:0 w:100)
+	/// Storage: `Whitelist::RemovedAccounts` (r:0 w:100)
+	/// Storage: `Whitelist::WhitelistedCount` (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn remove_expired_accounts(n: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(14_661_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(n.into()))
 	}
 }
@@ -1,5 +1,8 @@
-use crate::{AccountId, Assets, Runtime};
-use frame_support::traits::fungibles::{Balanced, Credit};
+use crate::{AccountId, Assets, Runtime, RuntimeCall};
+use frame_support::traits::{
+	fungibles::{Balanced, Credit},
+	Contains,
+};
 use pallet_asset_tx_payment::HandleCredit;
 
 /// A `HandleCredit` implementation that naively transfers the fees to the block author.
@@ -15,6 +18,28 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
 	}
 }
 
+/// Call filter that keeps the property token bookkeeping of the marketplace in sync.
+/// Property token can only be moved with the marketplace, which updates `PropertyOwner` and
+/// `PropertyOwnerToken` and checks the whitelist. Direct transfers of property token with the
+/// assets pallet and the nft fractionalization calls, which are only used by the marketplace,
+/// are refused.
+pub struct PropertyTokenFilter;
+impl Contains<RuntimeCall> for PropertyTokenFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Assets(
+				pallet_assets::Call::transfer { id, .. } |
+				pallet_assets::Call::transfer_keep_alive { id, .. } |
+				pallet_assets::Call::approve_transfer { id, .. } |
+				pallet_assets::Call::transfer_approved { id, .. } |
+				pallet_assets::Call::refund { id, .. },
+			) => !pallet_nft_marketplace::AssetIdDetails::<Runtime>::contains_key(id.0),
+			RuntimeCall::NftFractionalization(_) => false,
+			_ => true,
+		}
+	}
+}
+
 // pub struct CreditToBlockAuthor<R, I = ()>(PhantomData<(R, I)>);
 // impl<R, I> HandleCredit<AccountIdOf<R>, pallet_assets::Pallet<R, I>> for CreditToBlockAuthor<R>
 // where
@@ -64,7 +64,7 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 /// Implementations of some helper traits passed into runtime modules as associated types.
 pub mod impls;
-use impls::CreditToBlockAuthor;
+use impls::{CreditToBlockAuthor, PropertyTokenFilter};
 
 // A few exports that help ease life for downstream crates.
 use frame_election_provider_support::bounds::{ElectionBounds, ElectionBoundsBuilder};
@@ -273,7 +273,7 @@ pub type SlowAdjustingFeeUpdate<R> =
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = PropertyTokenFilter;
 	/// The block type for the runtime.
 	type Block = Block;
 	/// Block & extrinsics weights: base values and limits.
@@ -433,6 +433,24 @@ parameter_types! {
 	pub const NftMarketplacePalletId: PalletId = PalletId(*b"py/nftxc");
 	pub const MaxNftTokens: u32 = 250;
 	pub const Postcode: u32 = 10;
+	pub const ListingDuration: BlockNumber = 30 * DAYS;
+	pub const OfferDuration: BlockNumber = 30 * DAYS;
+	pub const MaxOffersForBlock: u32 = 100;
+	pub const AuctionDuration: BlockNumber = 7 * DAYS;
+	pub const MaxAuctionsForBlock: u32 = 100;
+	pub const MaxBatchPurchases: u32 = 20;
+	pub const LawyerBond: Balance = 100 * DOLLARS;
+	pub const LawyerUnbondingPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxLawyerRegions: u32 = 10;
+	pub const LawyerReviewPeriod: BlockNumber = 14 * DAYS;
+	pub const MaxReviewTimeouts: u32 = 3;
+	pub const MaxReviewDeadlinesForBlock: u32 = 100;
+	pub const MaxDocumentHashes: u32 = 20;
+	pub const ArbitrationPeriod: BlockNumber = 14 * DAYS;
+	pub const MaxArbitrationsForBlock: u32 = 100;
+	pub const MaxSoftCapsForBlock: u32 = 100;
+	pub const MaxOwnershipShare: Percent = Percent::from_percent(25);
+	pub const MaxComplianceListings: u32 = 50;
 }
 
 /// Configure the pallet-nft-marketplace in pallets/nft-marketplace.
@@ -454,10 +472,44 @@ impl pallet_nft_marketplace::Config for Runtime {
 	type AssetId = <Self as pallet_assets::Config<Instance1>>::AssetId;
 	type AssetId2 = u32;
 	type PostcodeLimit = Postcode;
+	type FeeScheduleOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type PaymentAssetOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type ArbiterOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type ListingDuration = ListingDuration;
+	type OfferDuration = OfferDuration;
+	type MaxOffersForBlock = MaxOffersForBlock;
+	type AuctionDuration = AuctionDuration;
+	type MaxAuctionsForBlock = MaxAuctionsForBlock;
+	type MaxBatchPurchases = MaxBatchPurchases;
+	type LawyerBond = LawyerBond;
+	type LawyerUnbondingPeriod = LawyerUnbondingPeriod;
+	type MaxLawyerRegions = MaxLawyerRegions;
+	type LawyerReviewPeriod = LawyerReviewPeriod;
+	type MaxReviewTimeouts = MaxReviewTimeouts;
+	type MaxReviewDeadlinesForBlock = MaxReviewDeadlinesForBlock;
+	type MaxDocumentHashes = MaxDocumentHashes;
+	type ArbitrationPeriod = ArbitrationPeriod;
+	type MaxArbitrationsForBlock = MaxArbitrationsForBlock;
+	type MaxSoftCapsForBlock = MaxSoftCapsForBlock;
+	type MaxOwnershipShare = MaxOwnershipShare;
+	type MaxComplianceListings = MaxComplianceListings;
 }
 
 parameter_types! {
-	pub const MaxWhitelistUsers: u32 = 1000;
+	pub const MaxWhitelistUsers: u32 = 1_000_000;
+	pub const InvestmentPeriod: BlockNumber = 365 * DAYS;
+	// The investment limits are denominated with the 6 decimals of the stablecoins.
+	pub const InvestmentDecimals: u8 = 6;
+	pub const MaxWhitelistBatch: u32 = 100;
 }
 
 /// Configure the pallet-xcavate-whitelist in pallets/xcavate-whitelist.
@@ -468,7 +520,16 @@ impl pallet_xcavate_whitelist::Config for Runtime {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 	>;
+	type ComplianceOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type MaxUsersInWhitelist = MaxWhitelistUsers;
+	type InvestmentBalance = Balance;
+	type InvestmentPeriod = InvestmentPeriod;
+	type InvestmentDecimals = InvestmentDecimals;
+	type MaxWhitelistBatch = MaxWhitelistBatch;
+	type OnRemoval = (NftMarketplace, PropertyManagement);
 }
 
 parameter_types! {
@@ -1369,6 +1430,12 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_nft_marketplace::migrations::v1::MigrateToV1<Runtime>,
+	pallet_xcavate_whitelist::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1376,6 +1443,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1645,29 +1713,87 @@ impl_runtime_apis! {
 		}
 	}
 	
-	impl pallet_nft_marketplace::NftMarketplaceApi<Block, AccountId> for Runtime {
+	impl pallet_nft_marketplace::NftMarketplaceApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
 		fn get_marketplace_account_id() -> AccountId {
 			AccountIdConversion::<AccountId>::into_account_truncating(&NftMarketplacePalletId::get())
 		}
+
+		fn get_listings(
+			region: u32,
+			location: Option<Vec<u8>>,
+			start: u32,
+			limit: u32,
+		) -> pallet_nft_marketplace::ListingPage<AccountId, Balance, BlockNumber> {
+			NftMarketplace::listings(region, location, start, limit)
+		}
+
+		fn get_listing(
+			listing_id: u32,
+		) -> Option<pallet_nft_marketplace::ListingInfo<AccountId, Balance, BlockNumber>> {
+			NftMarketplace::listing(listing_id)
+		}
+
+		fn get_offers(
+			listing_id: u32,
+		) -> Vec<pallet_nft_marketplace::OfferInfo<AccountId, Balance, BlockNumber>> {
+			NftMarketplace::offers(listing_id)
+		}
+
+		fn get_holdings(account: AccountId) -> Vec<pallet_nft_marketplace::HoldingInfo<Balance>> {
+			NftMarketplace::holdings(account)
+		}
+
+		fn get_legal_documents(
+			listing_id: u32,
+		) -> Vec<pallet_nft_marketplace::LegalDocumentsInfo<AccountId, Hash, BlockNumber>> {
+			NftMarketplace::legal_documents(listing_id)
+		}
 	}
 
-	impl pallet_property_governance::PropertyGovernanceApi<Block, AccountId> for Runtime {
+	impl pallet_property_governance::PropertyGovernanceApi<Block, AccountId, Balance, BlockNumber> for Runtime {
 		fn get_governance_account_id() -> AccountId {
 			AccountIdConversion::<AccountId>::into_account_truncating(&PropertyGovernancePalletId::get())
 		}
+
+		fn get_open_proposals(
+			asset_id: u32,
+		) -> Vec<pallet_property_governance::ProposalInfo<AccountId, Balance, BlockNumber>> {
+			PropertyGovernance::open_proposals(asset_id)
+		}
 	}
 
-	impl pallet_property_management::PropertyManagementApi<Block, AccountId> for Runtime {
+	impl pallet_xcavate_whitelist::WhitelistApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_whitelisted_count() -> u32 {
+			pallet_xcavate_whitelist::WhitelistedCount::<Runtime>::get()
+		}
+
+		fn get_whitelisted_accounts(
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<pallet_xcavate_whitelist::WhitelistInfo<AccountId, BlockNumber>> {
+			XcavateWhitelist::whitelisted_users(start_after, limit)
+		}
+	}
+
+	impl pallet_property_management::PropertyManagementApi<Block, AccountId, Balance> for Runtime {
 		fn get_management_account_id() -> AccountId {
 			AccountIdConversion::<AccountId>::into_account_truncating(&PropertyManagementPalletId::get())
 		}
+
+		fn get_property_reserve(asset_id: u32) -> Balance {
+			pallet_property_management::PropertyReserve::<Runtime>::get(asset_id)
+		}
+
+		fn get_property_debts(asset_id: u32) -> Balance {
+			pallet_property_management::PropertyDebts::<Runtime>::get(asset_id)
+		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::traits::WhitelistedStorageKeys;
+	use frame_support::traits::{Contains, WhitelistedStorageKeys};
 	use sp_core::hexdisplay::HexDisplay;
 	use std::collections::HashSet;
 
@@ -1699,4 +1825,46 @@ mod tests {
 			whitelist.contains("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7")
 		);
 	}
+
+	#[test]
+	fn property_token_filter_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			pallet_nft_marketplace::AssetIdDetails::<Runtime>::insert(
+				0,
+				pallet_nft_marketplace::AssetDetails {
+					collection_id: 0,
+					item_id: 0,
+					region: 0,
+					location: Default::default(),
+					price: 1_000,
+					token_amount: 100,
+				},
+			);
+			let transfer = |id: u32| {
+				RuntimeCall::Assets(pallet_assets::Call::transfer {
+					id: id.into(),
+					target: AccountId::new([1; 32]).into(),
+					amount: 1,
+				})
+			};
+			assert!(!PropertyTokenFilter::contains(&transfer(0)));
+			assert!(PropertyTokenFilter::contains(&transfer(1)));
+			assert!(!PropertyTokenFilter::contains(&RuntimeCall::Assets(
+				pallet_assets::Call::transfer_approved {
+					id: 0.into(),
+					owner: AccountId::new([1; 32]).into(),
+					destination: AccountId::new([2; 32]).into(),
+					amount: 1,
+				}
+			)));
+			assert!(!PropertyTokenFilter::contains(&RuntimeCall::NftFractionalization(
+				pallet_nft_fractionalization::Call::unify {
+					nft_collection_id: 0,
+					nft_id: 0,
+					asset_id: 0,
+					beneficiary: AccountId::new([1; 32]).into(),
+				}
+			)));
+		});
+	}
 }